@@ -414,7 +414,61 @@ fileprivate final class UniffiHandleMap<T>: @unchecked Sendable {
 
 
 // Public interface members begin here.
+// Magic number for the Rust proxy to call using the same mechanism as every other method,
+// to free the callback once it's dropped by Rust.
+private let IDX_CALLBACK_FREE: Int32 = 0
+// Callback return codes
+private let UNIFFI_CALLBACK_SUCCESS: Int32 = 0
+private let UNIFFI_CALLBACK_ERROR: Int32 = 1
+private let UNIFFI_CALLBACK_UNEXPECTED_ERROR: Int32 = 2
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt16: FfiConverterPrimitive {
+    typealias FfiType = UInt16
+    typealias SwiftType = UInt16
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt16 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
+    typealias FfiType = UInt32
+    typealias SwiftType = UInt32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
+    typealias FfiType = UInt64
+    typealias SwiftType = UInt64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
@@ -432,6 +486,22 @@ fileprivate struct FfiConverterFloat: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
+    typealias FfiType = Double
+    typealias SwiftType = Double
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Double {
+        return try lift(readDouble(&buf))
+    }
+
+    public static func write(_ value: Double, into buf: inout [UInt8]) {
+        writeDouble(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -515,6 +585,34 @@ fileprivate struct FfiConverterData: FfiConverterRustBuffer {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDuration: FfiConverterRustBuffer {
+    typealias SwiftType = TimeInterval
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TimeInterval {
+        let seconds: UInt64 = try readInt(&buf)
+        let nanoseconds: UInt32 = try readInt(&buf)
+        return Double(seconds) + (Double(nanoseconds) / 1.0e9)
+    }
+
+    public static func write(_ value: TimeInterval, into buf: inout [UInt8]) {
+        if value.rounded(.down) > Double(Int64.max) {
+            fatalError("Duration overflow, exceeds max bounds supported by Uniffi")
+        }
+
+        if value < 0 {
+            fatalError("Invalid duration, must be non-negative")
+        }
+
+        let seconds = UInt64(value)
+        let nanoseconds = UInt32((value - Double(seconds)) * 1.0e9)
+        writeInt(&buf, seconds)
+        writeInt(&buf, nanoseconds)
+    }
+}
+
 
 
 
@@ -669,56 +767,183 @@ public func FfiConverterTypeCancellationToken_lower(_ value: CancellationToken)
 
 
 
+
+
 /**
- * Captured audio payload and metadata.
+ * Receives incremental text while an LLM response is streamed.
  */
-public struct AudioData: Equatable, Hashable {
-    /**
-     * Encoded audio bytes in FLAC format.
-     */
-    public var bytes: Data
+public protocol LlmStreamListener: AnyObject, Sendable {
+    
     /**
-     * Approximate capture duration in seconds before post-processing.
+     * Called on the calling thread with each new chunk of generated text, in order.
      */
-    public var durationSeconds: Float
+    func onDelta(delta: String) 
+    
+}
+/**
+ * Receives incremental text while an LLM response is streamed.
+ */
+open class LlmStreamListenerImpl: LlmStreamListener, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(
-        /**
-         * Encoded audio bytes in FLAC format.
-         */bytes: Data, 
-        /**
-         * Approximate capture duration in seconds before post-processing.
-         */durationSeconds: Float) {
-        self.bytes = bytes
-        self.durationSeconds = durationSeconds
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_diy_typeless_core_fn_clone_llmstreamlistener(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_diy_typeless_core_fn_free_llmstreamlistener(handle, $0) }
     }
 
     
 
     
+    /**
+     * Called on the calling thread with each new chunk of generated text, in order.
+     */
+open func onDelta(delta: String)  {try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_llmstreamlistener_on_delta(
+            self.uniffiCloneHandle(),
+        FfiConverterString.lower(delta),$0
+    )
+}
+}
+    
+
+    
 }
 
-#if compiler(>=6)
-extension AudioData: Sendable {}
-#endif
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceLlmStreamListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceLlmStreamListener] = [UniffiVTableCallbackInterfaceLlmStreamListener(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeLlmStreamListener.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface LlmStreamListener: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeLlmStreamListener.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface LlmStreamListener: handle missing in uniffiClone")
+            }
+        },
+        onDelta: { (
+            uniffiHandle: UInt64,
+            delta: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeLlmStreamListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onDelta(
+                     delta: try FfiConverterString.lift(delta)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitLlmStreamListener() {
+    uniffi_diy_typeless_core_fn_init_callback_vtable_llmstreamlistener(UniffiCallbackInterfaceLlmStreamListener.vtable)
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeAudioData: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AudioData {
-        return
-            try AudioData(
-                bytes: FfiConverterData.read(from: &buf), 
-                durationSeconds: FfiConverterFloat.read(from: &buf)
-        )
+public struct FfiConverterTypeLlmStreamListener: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<LlmStreamListener>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = LlmStreamListener
+
+    public static func lift(_ handle: UInt64) throws -> LlmStreamListener {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return LlmStreamListenerImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
     }
 
-    public static func write(_ value: AudioData, into buf: inout [UInt8]) {
-        FfiConverterData.write(value.bytes, into: &buf)
-        FfiConverterFloat.write(value.durationSeconds, into: &buf)
+    public static func lower(_ value: LlmStreamListener) -> UInt64 {
+         if let rustImpl = value as? LlmStreamListenerImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LlmStreamListener {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: LlmStreamListener, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
     }
 }
 
@@ -726,88 +951,2310 @@ public struct FfiConverterTypeAudioData: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeAudioData_lift(_ buf: RustBuffer) throws -> AudioData {
-    return try FfiConverterTypeAudioData.lift(buf)
+public func FfiConverterTypeLlmStreamListener_lift(_ handle: UInt64) throws -> LlmStreamListener {
+    return try FfiConverterTypeLlmStreamListener.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeAudioData_lower(_ value: AudioData) -> RustBuffer {
-    return FfiConverterTypeAudioData.lower(value)
+public func FfiConverterTypeLlmStreamListener_lower(_ value: LlmStreamListener) -> UInt64 {
+    return FfiConverterTypeLlmStreamListener.lower(value)
 }
 
 
-public struct PipelineResult: Equatable, Hashable {
-    public var rawText: String
-    public var polishedText: String
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(rawText: String, polishedText: String) {
-        self.rawText = rawText
-        self.polishedText = polishedText
-    }
 
-    
 
+
+/**
+ * Receives recording lifecycle events.
+ */
+public protocol RecordingEventListener: AnyObject, Sendable {
+    
+    /**
+     * Called on a background thread once a recording hits its [`RecordingLimits`].
+     *
+     * Capture has already stopped; call `stop` to get the truncated audio.
+     */
+    func onRecordingLimitReached() 
     
 }
+/**
+ * Receives recording lifecycle events.
+ */
+open class RecordingEventListenerImpl: RecordingEventListener, @unchecked Sendable {
+    fileprivate let handle: UInt64
 
-#if compiler(>=6)
-extension PipelineResult: Sendable {}
-#endif
-
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypePipelineResult: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PipelineResult {
-        return
-            try PipelineResult(
-                rawText: FfiConverterString.read(from: &buf), 
-                polishedText: FfiConverterString.read(from: &buf)
-        )
+    public struct NoHandle {
+        public init() {}
     }
 
-    public static func write(_ value: PipelineResult, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.rawText, into: &buf)
-        FfiConverterString.write(value.polishedText, into: &buf)
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
     }
-}
-
 
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePipelineResult_lift(_ buf: RustBuffer) throws -> PipelineResult {
-    return try FfiConverterTypePipelineResult.lift(buf)
-}
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
 
 #if swift(>=5.8)
-@_documentation(visibility: private)
+    @_documentation(visibility: private)
 #endif
-public func FfiConverterTypePipelineResult_lower(_ value: PipelineResult) -> RustBuffer {
-    return FfiConverterTypePipelineResult.lower(value)
-}
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_diy_typeless_core_fn_clone_recordingeventlistener(self.handle, $0) }
+    }
+    // No primary constructor declared for this class.
 
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
 
-/**
- * Unified error type returned by core operations.
- */
-public enum CoreError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+        try! rustCall { uniffi_diy_typeless_core_fn_free_recordingeventlistener(handle, $0) }
+    }
 
     
+
     
     /**
-     * Operation was cancelled by caller.
-     */
-    case Cancelled
-    /**
-     * No default input audio device is available.
+     * Called on a background thread once a recording hits its [`RecordingLimits`].
+     *
+     * Capture has already stopped; call `stop` to get the truncated audio.
      */
-    case AudioDeviceUnavailable
-    /**
+open func onRecordingLimitReached()  {try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_recordingeventlistener_on_recording_limit_reached(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+
+    
+}
+
+
+
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceRecordingEventListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceRecordingEventListener] = [UniffiVTableCallbackInterfaceRecordingEventListener(
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            do {
+                try FfiConverterTypeRecordingEventListener.handleMap.remove(handle: uniffiHandle)
+            } catch {
+                print("Uniffi callback interface RecordingEventListener: handle missing in uniffiFree")
+            }
+        },
+        uniffiClone: { (uniffiHandle: UInt64) -> UInt64 in
+            do {
+                return try FfiConverterTypeRecordingEventListener.handleMap.clone(handle: uniffiHandle)
+            } catch {
+                fatalError("Uniffi callback interface RecordingEventListener: handle missing in uniffiClone")
+            }
+        },
+        onRecordingLimitReached: { (
+            uniffiHandle: UInt64,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterTypeRecordingEventListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onRecordingLimitReached(
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        }
+    )]
+}
+
+private func uniffiCallbackInitRecordingEventListener() {
+    uniffi_diy_typeless_core_fn_init_callback_vtable_recordingeventlistener(UniffiCallbackInterfaceRecordingEventListener.vtable)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRecordingEventListener: FfiConverter {
+    fileprivate static let handleMap = UniffiHandleMap<RecordingEventListener>()
+
+    typealias FfiType = UInt64
+    typealias SwiftType = RecordingEventListener
+
+    public static func lift(_ handle: UInt64) throws -> RecordingEventListener {
+        if ((handle & 1) == 0) {
+            // Rust-generated handle, construct a new class that uses the handle to implement the
+            // interface
+            return RecordingEventListenerImpl(unsafeFromHandle: handle)
+        } else {
+            // Swift-generated handle, get the object from the handle map
+            return try handleMap.remove(handle: handle)
+        }
+    }
+
+    public static func lower(_ value: RecordingEventListener) -> UInt64 {
+         if let rustImpl = value as? RecordingEventListenerImpl {
+             // Rust-implemented object.  Clone the handle and return it
+            return rustImpl.uniffiCloneHandle()
+         } else {
+            // Swift object, generate a new vtable handle and return that.
+            return handleMap.insert(obj: value)
+         }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecordingEventListener {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: RecordingEventListener, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingEventListener_lift(_ handle: UInt64) throws -> RecordingEventListener {
+    return try FfiConverterTypeRecordingEventListener.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingEventListener_lower(_ value: RecordingEventListener) -> UInt64 {
+    return FfiConverterTypeRecordingEventListener.lower(value)
+}
+
+
+
+
+
+
+/**
+ * A microphone capture with its own stream and sample buffer.
+ *
+ * Sessions share no state, so several can exist in one process and tests can
+ * exercise them in parallel. The `start_recording`/`stop_recording` exports drive
+ * a process-wide default session, see [`default_session`].
+ */
+public protocol RecordingSessionProtocol: AnyObject, Sendable {
+    
+    /**
+     * Keep an input stream open on `device_id` (or the default device) and buffer the
+     * last 500 ms of audio, so a recording started later includes speech that began
+     * just before `start` was called.
+     *
+     * The microphone stays in use until [`RecordingSession::disarm`]. Arming a session
+     * that is recording keeps its current input open once the recording stops.
+     */
+    func arm(deviceId: String?) throws 
+    
+    /**
+     * Stop buffering pre-roll and release the microphone when not recording.
+     */
+    func disarm() throws 
+    
+    /**
+     * Time spent recording, excluding paused intervals and any time after the
+     * recording limit stopped capture. Zero when idle.
+     */
+    func elapsed()  -> TimeInterval
+    
+    /**
+     * Whether the session keeps the microphone open between recordings.
+     */
+    func isArmed()  -> Bool
+    
+    /**
+     * Whether the current recording stopped capturing at its [`RecordingLimits`].
+     */
+    func isLimitReached()  -> Bool
+    
+    /**
+     * Whether the session is capturing but paused.
+     */
+    func isPaused()  -> Bool
+    
+    /**
+     * Whether the session is capturing, including while paused.
+     */
+    func isRecording()  -> Bool
+    
+    /**
+     * Live levels of this session's input, including while armed; silent when idle.
+     */
+    func levels()  -> AudioLevels
+    
+    /**
+     * Limits applied to new recordings.
+     */
+    func limits()  -> RecordingLimits
+    
+    /**
+     * Stop buffering audio while keeping the input stream open.
+     *
+     * Paused audio is left out of the recording, so `AudioData::duration_seconds`
+     * only counts time spent recording. Fails with [`CoreError::RecordingAlreadyPaused`]
+     * when already paused.
+     */
+    func pause() throws 
+    
+    /**
+     * Resume buffering audio after [`RecordingSession::pause`].
+     *
+     * Fails with [`CoreError::RecordingNotPaused`] when the session is not paused.
+     */
+    func resume() throws 
+    
+    /**
+     * Register the listener notified of recording events, replacing any previous one.
+     */
+    func setEventListener(listener: RecordingEventListener?) throws 
+    
+    /**
+     * Limits applied to recordings started after this call.
+     */
+    func setLimits(limits: RecordingLimits) throws 
+    
+    /**
+     * Start capturing from `device_id`, or the system default input device when `None`.
+     *
+     * `device_id` is an identifier (or exact name) reported by `list_input_devices`.
+     * When the session is armed on the same device, the buffered pre-roll becomes the
+     * start of the recording.
+     */
+    func start(deviceId: String?) throws 
+    
+    /**
+     * Stop capturing and return FLAC-encoded audio with default post-processing.
+     */
+    func stop() throws  -> AudioData
+    
+    /**
+     * Stop capturing and return FLAC-encoded audio using custom post-processing options.
+     */
+    func stopWithOptions(options: AudioProcessingOptions) throws  -> AudioData
+    
+}
+/**
+ * A microphone capture with its own stream and sample buffer.
+ *
+ * Sessions share no state, so several can exist in one process and tests can
+ * exercise them in parallel. The `start_recording`/`stop_recording` exports drive
+ * a process-wide default session, see [`default_session`].
+ */
+open class RecordingSession: RecordingSessionProtocol, @unchecked Sendable {
+    fileprivate let handle: UInt64
+
+    /// Used to instantiate a [FFIObject] without an actual handle, for fakes in tests, mostly.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public struct NoHandle {
+        public init() {}
+    }
+
+    // TODO: We'd like this to be `private` but for Swifty reasons,
+    // we can't implement `FfiConverter` without making this `required` and we can't
+    // make it `required` without making it `public`.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    required public init(unsafeFromHandle handle: UInt64) {
+        self.handle = handle
+    }
+
+    // This constructor can be used to instantiate a fake object.
+    // - Parameter noHandle: Placeholder value so we can have a constructor separate from the default empty one that may be implemented for classes extending [FFIObject].
+    //
+    // - Warning:
+    //     Any object instantiated with this constructor cannot be passed to an actual Rust-backed object. Since there isn't a backing handle the FFI lower functions will crash.
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public init(noHandle: NoHandle) {
+        self.handle = 0
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public func uniffiCloneHandle() -> UInt64 {
+        return try! rustCall { uniffi_diy_typeless_core_fn_clone_recordingsession(self.handle, $0) }
+    }
+    /**
+     * Create an idle session.
+     */
+public convenience init() {
+    let handle =
+        try! rustCall() {
+    uniffi_diy_typeless_core_fn_constructor_recordingsession_new($0
+    )
+}
+    self.init(unsafeFromHandle: handle)
+}
+
+    deinit {
+        if handle == 0 {
+            // Mock objects have handle=0 don't try to free them
+            return
+        }
+
+        try! rustCall { uniffi_diy_typeless_core_fn_free_recordingsession(handle, $0) }
+    }
+
+    
+
+    
+    /**
+     * Keep an input stream open on `device_id` (or the default device) and buffer the
+     * last 500 ms of audio, so a recording started later includes speech that began
+     * just before `start` was called.
+     *
+     * The microphone stays in use until [`RecordingSession::disarm`]. Arming a session
+     * that is recording keeps its current input open once the recording stops.
+     */
+open func arm(deviceId: String?)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_arm(
+            self.uniffiCloneHandle(),
+        FfiConverterOptionString.lower(deviceId),$0
+    )
+}
+}
+    
+    /**
+     * Stop buffering pre-roll and release the microphone when not recording.
+     */
+open func disarm()throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_disarm(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+    /**
+     * Time spent recording, excluding paused intervals and any time after the
+     * recording limit stopped capture. Zero when idle.
+     */
+open func elapsed() -> TimeInterval  {
+    return try!  FfiConverterDuration.lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_recordingsession_elapsed(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Whether the session keeps the microphone open between recordings.
+     */
+open func isArmed() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_recordingsession_is_armed(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Whether the current recording stopped capturing at its [`RecordingLimits`].
+     */
+open func isLimitReached() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_recordingsession_is_limit_reached(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Whether the session is capturing but paused.
+     */
+open func isPaused() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_recordingsession_is_paused(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Whether the session is capturing, including while paused.
+     */
+open func isRecording() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_recordingsession_is_recording(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Live levels of this session's input, including while armed; silent when idle.
+     */
+open func levels() -> AudioLevels  {
+    return try!  FfiConverterTypeAudioLevels_lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_recordingsession_levels(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Limits applied to new recordings.
+     */
+open func limits() -> RecordingLimits  {
+    return try!  FfiConverterTypeRecordingLimits_lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_recordingsession_limits(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Stop buffering audio while keeping the input stream open.
+     *
+     * Paused audio is left out of the recording, so `AudioData::duration_seconds`
+     * only counts time spent recording. Fails with [`CoreError::RecordingAlreadyPaused`]
+     * when already paused.
+     */
+open func pause()throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_pause(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+    /**
+     * Resume buffering audio after [`RecordingSession::pause`].
+     *
+     * Fails with [`CoreError::RecordingNotPaused`] when the session is not paused.
+     */
+open func resume()throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_resume(
+            self.uniffiCloneHandle(),$0
+    )
+}
+}
+    
+    /**
+     * Register the listener notified of recording events, replacing any previous one.
+     */
+open func setEventListener(listener: RecordingEventListener?)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_set_event_listener(
+            self.uniffiCloneHandle(),
+        FfiConverterOptionTypeRecordingEventListener.lower(listener),$0
+    )
+}
+}
+    
+    /**
+     * Limits applied to recordings started after this call.
+     */
+open func setLimits(limits: RecordingLimits)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_set_limits(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeRecordingLimits_lower(limits),$0
+    )
+}
+}
+    
+    /**
+     * Start capturing from `device_id`, or the system default input device when `None`.
+     *
+     * `device_id` is an identifier (or exact name) reported by `list_input_devices`.
+     * When the session is armed on the same device, the buffered pre-roll becomes the
+     * start of the recording.
+     */
+open func start(deviceId: String?)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_start(
+            self.uniffiCloneHandle(),
+        FfiConverterOptionString.lower(deviceId),$0
+    )
+}
+}
+    
+    /**
+     * Stop capturing and return FLAC-encoded audio with default post-processing.
+     */
+open func stop()throws  -> AudioData  {
+    return try  FfiConverterTypeAudioData_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_stop(
+            self.uniffiCloneHandle(),$0
+    )
+})
+}
+    
+    /**
+     * Stop capturing and return FLAC-encoded audio using custom post-processing options.
+     */
+open func stopWithOptions(options: AudioProcessingOptions)throws  -> AudioData  {
+    return try  FfiConverterTypeAudioData_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_method_recordingsession_stop_with_options(
+            self.uniffiCloneHandle(),
+        FfiConverterTypeAudioProcessingOptions_lower(options),$0
+    )
+})
+}
+    
+
+    
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRecordingSession: FfiConverter {
+    typealias FfiType = UInt64
+    typealias SwiftType = RecordingSession
+
+    public static func lift(_ handle: UInt64) throws -> RecordingSession {
+        return RecordingSession(unsafeFromHandle: handle)
+    }
+
+    public static func lower(_ value: RecordingSession) -> UInt64 {
+        return value.uniffiCloneHandle()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecordingSession {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+    public static func write(_ value: RecordingSession, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingSession_lift(_ handle: UInt64) throws -> RecordingSession {
+    return try FfiConverterTypeRecordingSession.lift(handle)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingSession_lower(_ value: RecordingSession) -> UInt64 {
+    return FfiConverterTypeRecordingSession.lower(value)
+}
+
+
+
+
+/**
+ * Captured audio payload and metadata.
+ */
+public struct AudioData: Equatable, Hashable {
+    /**
+     * Encoded audio bytes in FLAC format.
+     */
+    public var bytes: Data
+    /**
+     * Approximate capture duration in seconds before post-processing, excluding
+     * paused intervals.
+     */
+    public var durationSeconds: Float
+    /**
+     * Duration in seconds of the encoded audio after silence trimming.
+     */
+    public var trimmedDurationSeconds: Float
+    /**
+     * Seconds of audio captured before recording started, from an armed microphone.
+     */
+    public var preRollSeconds: Float
+    /**
+     * Whether capture stopped early because the recording reached its duration or
+     * memory limit; audio after that point is missing.
+     */
+    public var truncated: Bool
+    /**
+     * Clipping in the captured audio and during post-processing.
+     */
+    public var clipStats: ClipStats
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Encoded audio bytes in FLAC format.
+         */bytes: Data, 
+        /**
+         * Approximate capture duration in seconds before post-processing, excluding
+         * paused intervals.
+         */durationSeconds: Float, 
+        /**
+         * Duration in seconds of the encoded audio after silence trimming.
+         */trimmedDurationSeconds: Float, 
+        /**
+         * Seconds of audio captured before recording started, from an armed microphone.
+         */preRollSeconds: Float, 
+        /**
+         * Whether capture stopped early because the recording reached its duration or
+         * memory limit; audio after that point is missing.
+         */truncated: Bool, 
+        /**
+         * Clipping in the captured audio and during post-processing.
+         */clipStats: ClipStats) {
+        self.bytes = bytes
+        self.durationSeconds = durationSeconds
+        self.trimmedDurationSeconds = trimmedDurationSeconds
+        self.preRollSeconds = preRollSeconds
+        self.truncated = truncated
+        self.clipStats = clipStats
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension AudioData: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAudioData: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AudioData {
+        return
+            try AudioData(
+                bytes: FfiConverterData.read(from: &buf), 
+                durationSeconds: FfiConverterFloat.read(from: &buf), 
+                trimmedDurationSeconds: FfiConverterFloat.read(from: &buf), 
+                preRollSeconds: FfiConverterFloat.read(from: &buf), 
+                truncated: FfiConverterBool.read(from: &buf), 
+                clipStats: FfiConverterTypeClipStats.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AudioData, into buf: inout [UInt8]) {
+        FfiConverterData.write(value.bytes, into: &buf)
+        FfiConverterFloat.write(value.durationSeconds, into: &buf)
+        FfiConverterFloat.write(value.trimmedDurationSeconds, into: &buf)
+        FfiConverterFloat.write(value.preRollSeconds, into: &buf)
+        FfiConverterBool.write(value.truncated, into: &buf)
+        FfiConverterTypeClipStats.write(value.clipStats, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioData_lift(_ buf: RustBuffer) throws -> AudioData {
+    return try FfiConverterTypeAudioData.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioData_lower(_ value: AudioData) -> RustBuffer {
+    return FfiConverterTypeAudioData.lower(value)
+}
+
+
+/**
+ * Live signal levels of the active recording.
+ */
+public struct AudioLevels: Equatable, Hashable {
+    /**
+     * Whether a recording is currently capturing audio.
+     */
+    public var isRecording: Bool
+    /**
+     * RMS amplitude of the latest capture buffer, from 0.0 to 1.0.
+     */
+    public var rms: Float
+    /**
+     * Peak amplitude of the latest capture buffer, from 0.0 to 1.0.
+     */
+    public var peak: Float
+    /**
+     * RMS level in dBFS, floored at -100 dB for silence.
+     */
+    public var rmsDb: Float
+    /**
+     * Peak level in dBFS, floored at -100 dB for silence.
+     */
+    public var peakDb: Float
+    /**
+     * Whether a sample reached full scale within the last half second.
+     */
+    public var isClipping: Bool
+    /**
+     * Peak amplitude per 50 ms bin over the last 1.6 seconds, oldest first.
+     */
+    public var waveform: [Float]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Whether a recording is currently capturing audio.
+         */isRecording: Bool, 
+        /**
+         * RMS amplitude of the latest capture buffer, from 0.0 to 1.0.
+         */rms: Float, 
+        /**
+         * Peak amplitude of the latest capture buffer, from 0.0 to 1.0.
+         */peak: Float, 
+        /**
+         * RMS level in dBFS, floored at -100 dB for silence.
+         */rmsDb: Float, 
+        /**
+         * Peak level in dBFS, floored at -100 dB for silence.
+         */peakDb: Float, 
+        /**
+         * Whether a sample reached full scale within the last half second.
+         */isClipping: Bool, 
+        /**
+         * Peak amplitude per 50 ms bin over the last 1.6 seconds, oldest first.
+         */waveform: [Float]) {
+        self.isRecording = isRecording
+        self.rms = rms
+        self.peak = peak
+        self.rmsDb = rmsDb
+        self.peakDb = peakDb
+        self.isClipping = isClipping
+        self.waveform = waveform
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension AudioLevels: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAudioLevels: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AudioLevels {
+        return
+            try AudioLevels(
+                isRecording: FfiConverterBool.read(from: &buf), 
+                rms: FfiConverterFloat.read(from: &buf), 
+                peak: FfiConverterFloat.read(from: &buf), 
+                rmsDb: FfiConverterFloat.read(from: &buf), 
+                peakDb: FfiConverterFloat.read(from: &buf), 
+                isClipping: FfiConverterBool.read(from: &buf), 
+                waveform: FfiConverterSequenceFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AudioLevels, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.isRecording, into: &buf)
+        FfiConverterFloat.write(value.rms, into: &buf)
+        FfiConverterFloat.write(value.peak, into: &buf)
+        FfiConverterFloat.write(value.rmsDb, into: &buf)
+        FfiConverterFloat.write(value.peakDb, into: &buf)
+        FfiConverterBool.write(value.isClipping, into: &buf)
+        FfiConverterSequenceFloat.write(value.waveform, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioLevels_lift(_ buf: RustBuffer) throws -> AudioLevels {
+    return try FfiConverterTypeAudioLevels.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioLevels_lower(_ value: AudioLevels) -> RustBuffer {
+    return FfiConverterTypeAudioLevels.lower(value)
+}
+
+
+/**
+ * Post-processing options applied when a recording is stopped.
+ */
+public struct AudioProcessingOptions: Equatable, Hashable {
+    /**
+     * Trim leading and trailing silence before encoding.
+     */
+    public var trimSilence: Bool
+    /**
+     * Silence kept around detected speech, in milliseconds.
+     */
+    public var silencePaddingMs: UInt32
+    /**
+     * Resample with linear interpolation instead of the band-limited sinc filter.
+     *
+     * Faster, but lets noise above 8 kHz alias into the speech band.
+     */
+    public var fastResampling: Bool
+    /**
+     * Reduce steady background noise such as fans or café chatter before gain is
+     * applied, using a noise profile taken from the quietest parts of the recording.
+     */
+    public var noiseSuppression: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Trim leading and trailing silence before encoding.
+         */trimSilence: Bool = true, 
+        /**
+         * Silence kept around detected speech, in milliseconds.
+         */silencePaddingMs: UInt32 = UInt32(250), 
+        /**
+         * Resample with linear interpolation instead of the band-limited sinc filter.
+         *
+         * Faster, but lets noise above 8 kHz alias into the speech band.
+         */fastResampling: Bool = false, 
+        /**
+         * Reduce steady background noise such as fans or café chatter before gain is
+         * applied, using a noise profile taken from the quietest parts of the recording.
+         */noiseSuppression: Bool = false) {
+        self.trimSilence = trimSilence
+        self.silencePaddingMs = silencePaddingMs
+        self.fastResampling = fastResampling
+        self.noiseSuppression = noiseSuppression
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension AudioProcessingOptions: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAudioProcessingOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AudioProcessingOptions {
+        return
+            try AudioProcessingOptions(
+                trimSilence: FfiConverterBool.read(from: &buf), 
+                silencePaddingMs: FfiConverterUInt32.read(from: &buf), 
+                fastResampling: FfiConverterBool.read(from: &buf), 
+                noiseSuppression: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AudioProcessingOptions, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.trimSilence, into: &buf)
+        FfiConverterUInt32.write(value.silencePaddingMs, into: &buf)
+        FfiConverterBool.write(value.fastResampling, into: &buf)
+        FfiConverterBool.write(value.noiseSuppression, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioProcessingOptions_lift(_ buf: RustBuffer) throws -> AudioProcessingOptions {
+    return try FfiConverterTypeAudioProcessingOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioProcessingOptions_lower(_ value: AudioProcessingOptions) -> RustBuffer {
+    return FfiConverterTypeAudioProcessingOptions.lower(value)
+}
+
+
+/**
+ * Clipping measured while preparing audio for transcription.
+ *
+ * Input clipping happened at the microphone or its gain stage and cannot be undone;
+ * a noticeable `input_clipped_ratio` usually means the speaker is too close to the
+ * microphone or the input gain is too high.
+ */
+public struct ClipStats: Equatable, Hashable {
+    /**
+     * Captured samples at or near full scale, before any processing.
+     */
+    public var inputClippedSamples: UInt64
+    /**
+     * Share of captured samples that clipped, from 0.0 to 1.0.
+     */
+    public var inputClippedRatio: Float
+    /**
+     * Highest captured level in dBFS, floored at -100 dB for silence.
+     */
+    public var inputPeakDb: Float
+    /**
+     * Samples that normalization gain pushed past full scale; the limiter kept them
+     * from clipping.
+     */
+    public var processingClippedSamples: UInt64
+    /**
+     * Samples turned down by the limiter.
+     */
+    public var limitedSamples: UInt64
+    /**
+     * Largest gain reduction applied by the limiter in dB; 0 when it stayed idle.
+     */
+    public var maxGainReductionDb: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Captured samples at or near full scale, before any processing.
+         */inputClippedSamples: UInt64, 
+        /**
+         * Share of captured samples that clipped, from 0.0 to 1.0.
+         */inputClippedRatio: Float, 
+        /**
+         * Highest captured level in dBFS, floored at -100 dB for silence.
+         */inputPeakDb: Float, 
+        /**
+         * Samples that normalization gain pushed past full scale; the limiter kept them
+         * from clipping.
+         */processingClippedSamples: UInt64, 
+        /**
+         * Samples turned down by the limiter.
+         */limitedSamples: UInt64, 
+        /**
+         * Largest gain reduction applied by the limiter in dB; 0 when it stayed idle.
+         */maxGainReductionDb: Float) {
+        self.inputClippedSamples = inputClippedSamples
+        self.inputClippedRatio = inputClippedRatio
+        self.inputPeakDb = inputPeakDb
+        self.processingClippedSamples = processingClippedSamples
+        self.limitedSamples = limitedSamples
+        self.maxGainReductionDb = maxGainReductionDb
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension ClipStats: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeClipStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ClipStats {
+        return
+            try ClipStats(
+                inputClippedSamples: FfiConverterUInt64.read(from: &buf), 
+                inputClippedRatio: FfiConverterFloat.read(from: &buf), 
+                inputPeakDb: FfiConverterFloat.read(from: &buf), 
+                processingClippedSamples: FfiConverterUInt64.read(from: &buf), 
+                limitedSamples: FfiConverterUInt64.read(from: &buf), 
+                maxGainReductionDb: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ClipStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.inputClippedSamples, into: &buf)
+        FfiConverterFloat.write(value.inputClippedRatio, into: &buf)
+        FfiConverterFloat.write(value.inputPeakDb, into: &buf)
+        FfiConverterUInt64.write(value.processingClippedSamples, into: &buf)
+        FfiConverterUInt64.write(value.limitedSamples, into: &buf)
+        FfiConverterFloat.write(value.maxGainReductionDb, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClipStats_lift(_ buf: RustBuffer) throws -> ClipStats {
+    return try FfiConverterTypeClipStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeClipStats_lower(_ value: ClipStats) -> RustBuffer {
+    return FfiConverterTypeClipStats.lower(value)
+}
+
+
+/**
+ * Runtime endpoint and model configuration for remote providers.
+ *
+ * URLs are API base URLs without a trailing endpoint path; the core appends
+ * `/audio/transcriptions`, `/chat/completions`, `/messages`,
+ * `/{model}:generateContent` and `/models` as needed.
+ */
+public struct CoreConfig: Equatable, Hashable {
+    /**
+     * Groq OpenAI-compatible API base URL.
+     */
+    public var groqApiUrl: String
+    /**
+     * Whisper model used for Groq transcription.
+     */
+    public var groqWhisperModel: String
+    /**
+     * OpenAI API base URL.
+     */
+    public var openaiApiUrl: String
+    /**
+     * Chat model used for OpenAI polishing and prompt processing.
+     */
+    public var openaiModel: String
+    /**
+     * Transcription model used for OpenAI transcription.
+     */
+    public var openaiWhisperModel: String
+    /**
+     * Gemini `models` collection URL.
+     */
+    public var geminiApiUrl: String
+    /**
+     * Gemini model used for polishing and prompt processing.
+     */
+    public var geminiModel: String
+    /**
+     * Anthropic API base URL.
+     */
+    public var anthropicApiUrl: String
+    /**
+     * Claude model used for Anthropic polishing and prompt processing.
+     */
+    public var anthropicModel: String
+    /**
+     * Retry schedule shared by transcription, polish and prompt processing.
+     */
+    public var retryPolicy: RetryPolicy
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Groq OpenAI-compatible API base URL.
+         */groqApiUrl: String, 
+        /**
+         * Whisper model used for Groq transcription.
+         */groqWhisperModel: String, 
+        /**
+         * OpenAI API base URL.
+         */openaiApiUrl: String, 
+        /**
+         * Chat model used for OpenAI polishing and prompt processing.
+         */openaiModel: String, 
+        /**
+         * Transcription model used for OpenAI transcription.
+         */openaiWhisperModel: String, 
+        /**
+         * Gemini `models` collection URL.
+         */geminiApiUrl: String, 
+        /**
+         * Gemini model used for polishing and prompt processing.
+         */geminiModel: String, 
+        /**
+         * Anthropic API base URL.
+         */anthropicApiUrl: String, 
+        /**
+         * Claude model used for Anthropic polishing and prompt processing.
+         */anthropicModel: String, 
+        /**
+         * Retry schedule shared by transcription, polish and prompt processing.
+         */retryPolicy: RetryPolicy) {
+        self.groqApiUrl = groqApiUrl
+        self.groqWhisperModel = groqWhisperModel
+        self.openaiApiUrl = openaiApiUrl
+        self.openaiModel = openaiModel
+        self.openaiWhisperModel = openaiWhisperModel
+        self.geminiApiUrl = geminiApiUrl
+        self.geminiModel = geminiModel
+        self.anthropicApiUrl = anthropicApiUrl
+        self.anthropicModel = anthropicModel
+        self.retryPolicy = retryPolicy
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension CoreConfig: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCoreConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CoreConfig {
+        return
+            try CoreConfig(
+                groqApiUrl: FfiConverterString.read(from: &buf), 
+                groqWhisperModel: FfiConverterString.read(from: &buf), 
+                openaiApiUrl: FfiConverterString.read(from: &buf), 
+                openaiModel: FfiConverterString.read(from: &buf), 
+                openaiWhisperModel: FfiConverterString.read(from: &buf), 
+                geminiApiUrl: FfiConverterString.read(from: &buf), 
+                geminiModel: FfiConverterString.read(from: &buf), 
+                anthropicApiUrl: FfiConverterString.read(from: &buf), 
+                anthropicModel: FfiConverterString.read(from: &buf), 
+                retryPolicy: FfiConverterTypeRetryPolicy.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CoreConfig, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.groqApiUrl, into: &buf)
+        FfiConverterString.write(value.groqWhisperModel, into: &buf)
+        FfiConverterString.write(value.openaiApiUrl, into: &buf)
+        FfiConverterString.write(value.openaiModel, into: &buf)
+        FfiConverterString.write(value.openaiWhisperModel, into: &buf)
+        FfiConverterString.write(value.geminiApiUrl, into: &buf)
+        FfiConverterString.write(value.geminiModel, into: &buf)
+        FfiConverterString.write(value.anthropicApiUrl, into: &buf)
+        FfiConverterString.write(value.anthropicModel, into: &buf)
+        FfiConverterTypeRetryPolicy.write(value.retryPolicy, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCoreConfig_lift(_ buf: RustBuffer) throws -> CoreConfig {
+    return try FfiConverterTypeCoreConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCoreConfig_lower(_ value: CoreConfig) -> RustBuffer {
+    return FfiConverterTypeCoreConfig.lower(value)
+}
+
+
+/**
+ * User vocabulary and transcript replacements.
+ */
+public struct Dictionary: Equatable, Hashable {
+    /**
+     * Names and terms sent to the transcription model as a spelling hint.
+     */
+    public var vocabulary: [String]
+    /**
+     * Replacements applied in order to each transcript.
+     */
+    public var entries: [DictionaryEntry]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Names and terms sent to the transcription model as a spelling hint.
+         */vocabulary: [String], 
+        /**
+         * Replacements applied in order to each transcript.
+         */entries: [DictionaryEntry]) {
+        self.vocabulary = vocabulary
+        self.entries = entries
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Dictionary: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDictionary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Dictionary {
+        return
+            try Dictionary(
+                vocabulary: FfiConverterSequenceString.read(from: &buf), 
+                entries: FfiConverterSequenceTypeDictionaryEntry.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Dictionary, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.vocabulary, into: &buf)
+        FfiConverterSequenceTypeDictionaryEntry.write(value.entries, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDictionary_lift(_ buf: RustBuffer) throws -> Dictionary {
+    return try FfiConverterTypeDictionary.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDictionary_lower(_ value: Dictionary) -> RustBuffer {
+    return FfiConverterTypeDictionary.lower(value)
+}
+
+
+/**
+ * One replacement applied to every transcript before polish.
+ */
+public struct DictionaryEntry: Equatable, Hashable {
+    /**
+     * Word or phrase to find, or a regular expression when `is_regex` is set.
+     */
+    public var pattern: String
+    /**
+     * Text substituted for each match; regex entries may refer to groups as `$1`.
+     */
+    public var replacement: String
+    /**
+     * Match letter case exactly. Otherwise matching ignores case, and a match
+     * that starts with a capital letter gets a capitalized replacement.
+     */
+    public var caseSensitive: Bool
+    /**
+     * Treat `pattern` as a regular expression instead of a whole word or phrase.
+     */
+    public var isRegex: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Word or phrase to find, or a regular expression when `is_regex` is set.
+         */pattern: String, 
+        /**
+         * Text substituted for each match; regex entries may refer to groups as `$1`.
+         */replacement: String, 
+        /**
+         * Match letter case exactly. Otherwise matching ignores case, and a match
+         * that starts with a capital letter gets a capitalized replacement.
+         */caseSensitive: Bool = false, 
+        /**
+         * Treat `pattern` as a regular expression instead of a whole word or phrase.
+         */isRegex: Bool = false) {
+        self.pattern = pattern
+        self.replacement = replacement
+        self.caseSensitive = caseSensitive
+        self.isRegex = isRegex
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension DictionaryEntry: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDictionaryEntry: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DictionaryEntry {
+        return
+            try DictionaryEntry(
+                pattern: FfiConverterString.read(from: &buf), 
+                replacement: FfiConverterString.read(from: &buf), 
+                caseSensitive: FfiConverterBool.read(from: &buf), 
+                isRegex: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DictionaryEntry, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.pattern, into: &buf)
+        FfiConverterString.write(value.replacement, into: &buf)
+        FfiConverterBool.write(value.caseSensitive, into: &buf)
+        FfiConverterBool.write(value.isRegex, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDictionaryEntry_lift(_ buf: RustBuffer) throws -> DictionaryEntry {
+    return try FfiConverterTypeDictionaryEntry.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDictionaryEntry_lower(_ value: DictionaryEntry) -> RustBuffer {
+    return FfiConverterTypeDictionaryEntry.lower(value)
+}
+
+
+/**
+ * Input device description used for device selection.
+ */
+public struct InputDeviceInfo: Equatable, Hashable {
+    /**
+     * Stable identifier accepted by `start_recording_with_device`.
+     */
+    public var id: String
+    /**
+     * Human-readable device name.
+     */
+    public var name: String
+    /**
+     * Whether this is the current system default input device.
+     */
+    public var isDefault: Bool
+    /**
+     * Sample rate of the default input configuration in Hz.
+     */
+    public var defaultSampleRate: UInt32
+    /**
+     * Channel count of the default input configuration.
+     */
+    public var defaultChannels: UInt16
+    /**
+     * Sample format of the default input configuration (e.g. `f32`).
+     */
+    public var defaultSampleFormat: String
+    /**
+     * Sample formats supported by any input configuration.
+     */
+    public var supportedSampleFormats: [String]
+    /**
+     * Common sample rates (Hz) covered by the supported input configurations.
+     */
+    public var supportedSampleRates: [UInt32]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Stable identifier accepted by `start_recording_with_device`.
+         */id: String, 
+        /**
+         * Human-readable device name.
+         */name: String, 
+        /**
+         * Whether this is the current system default input device.
+         */isDefault: Bool, 
+        /**
+         * Sample rate of the default input configuration in Hz.
+         */defaultSampleRate: UInt32, 
+        /**
+         * Channel count of the default input configuration.
+         */defaultChannels: UInt16, 
+        /**
+         * Sample format of the default input configuration (e.g. `f32`).
+         */defaultSampleFormat: String, 
+        /**
+         * Sample formats supported by any input configuration.
+         */supportedSampleFormats: [String], 
+        /**
+         * Common sample rates (Hz) covered by the supported input configurations.
+         */supportedSampleRates: [UInt32]) {
+        self.id = id
+        self.name = name
+        self.isDefault = isDefault
+        self.defaultSampleRate = defaultSampleRate
+        self.defaultChannels = defaultChannels
+        self.defaultSampleFormat = defaultSampleFormat
+        self.supportedSampleFormats = supportedSampleFormats
+        self.supportedSampleRates = supportedSampleRates
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension InputDeviceInfo: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeInputDeviceInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InputDeviceInfo {
+        return
+            try InputDeviceInfo(
+                id: FfiConverterString.read(from: &buf), 
+                name: FfiConverterString.read(from: &buf), 
+                isDefault: FfiConverterBool.read(from: &buf), 
+                defaultSampleRate: FfiConverterUInt32.read(from: &buf), 
+                defaultChannels: FfiConverterUInt16.read(from: &buf), 
+                defaultSampleFormat: FfiConverterString.read(from: &buf), 
+                supportedSampleFormats: FfiConverterSequenceString.read(from: &buf), 
+                supportedSampleRates: FfiConverterSequenceUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: InputDeviceInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.name, into: &buf)
+        FfiConverterBool.write(value.isDefault, into: &buf)
+        FfiConverterUInt32.write(value.defaultSampleRate, into: &buf)
+        FfiConverterUInt16.write(value.defaultChannels, into: &buf)
+        FfiConverterString.write(value.defaultSampleFormat, into: &buf)
+        FfiConverterSequenceString.write(value.supportedSampleFormats, into: &buf)
+        FfiConverterSequenceUInt32.write(value.supportedSampleRates, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInputDeviceInfo_lift(_ buf: RustBuffer) throws -> InputDeviceInfo {
+    return try FfiConverterTypeInputDeviceInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInputDeviceInfo_lower(_ value: InputDeviceInfo) -> RustBuffer {
+    return FfiConverterTypeInputDeviceInfo.lower(value)
+}
+
+
+/**
+ * Chunking options for [`crate::transcribe_long_audio`].
+ */
+public struct LongAudioOptions: Equatable, Hashable {
+    /**
+     * Target chunk length in seconds. Keep it well under the provider's upload
+     * limit; five minutes of 16 kHz FLAC is roughly 6 MB.
+     */
+    public var chunkSeconds: UInt32
+    /**
+     * How far before each target boundary to look for the quietest cut point, in seconds.
+     */
+    public var splitSearchSeconds: UInt32
+    /**
+     * Audio repeated at the start of each chunk so a word cut at the boundary is
+     * heard whole at least once, in milliseconds.
+     */
+    public var overlapMs: UInt32
+    /**
+     * Maximum number of chunks transcribed at the same time; 1 transcribes in order.
+     */
+    public var maxParallelRequests: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Target chunk length in seconds. Keep it well under the provider's upload
+         * limit; five minutes of 16 kHz FLAC is roughly 6 MB.
+         */chunkSeconds: UInt32 = UInt32(300), 
+        /**
+         * How far before each target boundary to look for the quietest cut point, in seconds.
+         */splitSearchSeconds: UInt32 = UInt32(20), 
+        /**
+         * Audio repeated at the start of each chunk so a word cut at the boundary is
+         * heard whole at least once, in milliseconds.
+         */overlapMs: UInt32 = UInt32(1000), 
+        /**
+         * Maximum number of chunks transcribed at the same time; 1 transcribes in order.
+         */maxParallelRequests: UInt32 = UInt32(3)) {
+        self.chunkSeconds = chunkSeconds
+        self.splitSearchSeconds = splitSearchSeconds
+        self.overlapMs = overlapMs
+        self.maxParallelRequests = maxParallelRequests
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension LongAudioOptions: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLongAudioOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LongAudioOptions {
+        return
+            try LongAudioOptions(
+                chunkSeconds: FfiConverterUInt32.read(from: &buf), 
+                splitSearchSeconds: FfiConverterUInt32.read(from: &buf), 
+                overlapMs: FfiConverterUInt32.read(from: &buf), 
+                maxParallelRequests: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LongAudioOptions, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.chunkSeconds, into: &buf)
+        FfiConverterUInt32.write(value.splitSearchSeconds, into: &buf)
+        FfiConverterUInt32.write(value.overlapMs, into: &buf)
+        FfiConverterUInt32.write(value.maxParallelRequests, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLongAudioOptions_lift(_ buf: RustBuffer) throws -> LongAudioOptions {
+    return try FfiConverterTypeLongAudioOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLongAudioOptions_lower(_ value: LongAudioOptions) -> RustBuffer {
+    return FfiConverterTypeLongAudioOptions.lower(value)
+}
+
+
+/**
+ * Providers, credentials and prompt inputs for [`crate::run_pipeline`].
+ */
+public struct PipelineOptions: Equatable, Hashable {
+    /**
+     * Speech-to-text provider.
+     */
+    public var transcriptionProvider: TranscriptionProvider
+    /**
+     * API key for the transcription provider; may be empty for unauthenticated servers.
+     */
+    public var transcriptionApiKey: String
+    /**
+     * Optional transcription language hint (ISO-639-1).
+     */
+    public var language: String?
+    /**
+     * LLM provider used for polishing.
+     */
+    public var llmProvider: LlmProvider
+    /**
+     * API key for the LLM provider; ignored when `skip_polish` is set.
+     */
+    public var llmApiKey: String
+    /**
+     * Optional description of where the text will be used.
+     */
+    public var context: String?
+    /**
+     * Return the raw transcript without calling the LLM.
+     */
+    public var skipPolish: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Speech-to-text provider.
+         */transcriptionProvider: TranscriptionProvider, 
+        /**
+         * API key for the transcription provider; may be empty for unauthenticated servers.
+         */transcriptionApiKey: String, 
+        /**
+         * Optional transcription language hint (ISO-639-1).
+         */language: String?, 
+        /**
+         * LLM provider used for polishing.
+         */llmProvider: LlmProvider, 
+        /**
+         * API key for the LLM provider; ignored when `skip_polish` is set.
+         */llmApiKey: String, 
+        /**
+         * Optional description of where the text will be used.
+         */context: String?, 
+        /**
+         * Return the raw transcript without calling the LLM.
+         */skipPolish: Bool = false) {
+        self.transcriptionProvider = transcriptionProvider
+        self.transcriptionApiKey = transcriptionApiKey
+        self.language = language
+        self.llmProvider = llmProvider
+        self.llmApiKey = llmApiKey
+        self.context = context
+        self.skipPolish = skipPolish
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension PipelineOptions: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePipelineOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PipelineOptions {
+        return
+            try PipelineOptions(
+                transcriptionProvider: FfiConverterTypeTranscriptionProvider.read(from: &buf), 
+                transcriptionApiKey: FfiConverterString.read(from: &buf), 
+                language: FfiConverterOptionString.read(from: &buf), 
+                llmProvider: FfiConverterTypeLlmProvider.read(from: &buf), 
+                llmApiKey: FfiConverterString.read(from: &buf), 
+                context: FfiConverterOptionString.read(from: &buf), 
+                skipPolish: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PipelineOptions, into buf: inout [UInt8]) {
+        FfiConverterTypeTranscriptionProvider.write(value.transcriptionProvider, into: &buf)
+        FfiConverterString.write(value.transcriptionApiKey, into: &buf)
+        FfiConverterOptionString.write(value.language, into: &buf)
+        FfiConverterTypeLlmProvider.write(value.llmProvider, into: &buf)
+        FfiConverterString.write(value.llmApiKey, into: &buf)
+        FfiConverterOptionString.write(value.context, into: &buf)
+        FfiConverterBool.write(value.skipPolish, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePipelineOptions_lift(_ buf: RustBuffer) throws -> PipelineOptions {
+    return try FfiConverterTypePipelineOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePipelineOptions_lower(_ value: PipelineOptions) -> RustBuffer {
+    return FfiConverterTypePipelineOptions.lower(value)
+}
+
+
+/**
+ * Output of a transcription + polish pipeline run.
+ */
+public struct PipelineResult: Equatable, Hashable {
+    /**
+     * Transcript returned by the transcription provider.
+     */
+    public var rawText: String
+    /**
+     * Segment timings and metadata for `raw_text`.
+     */
+    public var transcript: Transcript
+    /**
+     * Polished text, or the raw transcript when polish was skipped or failed.
+     */
+    public var polishedText: String
+    /**
+     * Wall time spent in transcription, in milliseconds.
+     */
+    public var transcribeMs: UInt64
+    /**
+     * Wall time spent in polish, in milliseconds (0 when skipped).
+     */
+    public var polishMs: UInt64
+    /**
+     * Polish failure that caused the fallback to raw text, if any.
+     */
+    public var polishError: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Transcript returned by the transcription provider.
+         */rawText: String, 
+        /**
+         * Segment timings and metadata for `raw_text`.
+         */transcript: Transcript, 
+        /**
+         * Polished text, or the raw transcript when polish was skipped or failed.
+         */polishedText: String, 
+        /**
+         * Wall time spent in transcription, in milliseconds.
+         */transcribeMs: UInt64, 
+        /**
+         * Wall time spent in polish, in milliseconds (0 when skipped).
+         */polishMs: UInt64, 
+        /**
+         * Polish failure that caused the fallback to raw text, if any.
+         */polishError: String?) {
+        self.rawText = rawText
+        self.transcript = transcript
+        self.polishedText = polishedText
+        self.transcribeMs = transcribeMs
+        self.polishMs = polishMs
+        self.polishError = polishError
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension PipelineResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePipelineResult: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PipelineResult {
+        return
+            try PipelineResult(
+                rawText: FfiConverterString.read(from: &buf), 
+                transcript: FfiConverterTypeTranscript.read(from: &buf), 
+                polishedText: FfiConverterString.read(from: &buf), 
+                transcribeMs: FfiConverterUInt64.read(from: &buf), 
+                polishMs: FfiConverterUInt64.read(from: &buf), 
+                polishError: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PipelineResult, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.rawText, into: &buf)
+        FfiConverterTypeTranscript.write(value.transcript, into: &buf)
+        FfiConverterString.write(value.polishedText, into: &buf)
+        FfiConverterUInt64.write(value.transcribeMs, into: &buf)
+        FfiConverterUInt64.write(value.polishMs, into: &buf)
+        FfiConverterOptionString.write(value.polishError, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePipelineResult_lift(_ buf: RustBuffer) throws -> PipelineResult {
+    return try FfiConverterTypePipelineResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePipelineResult_lower(_ value: PipelineResult) -> RustBuffer {
+    return FfiConverterTypePipelineResult.lower(value)
+}
+
+
+/**
+ * Upper bounds on a single recording. A value of 0 disables that limit.
+ *
+ * When either limit is reached the capture callback stops buffering, the microphone
+ * is released (unless the session is armed), and
+ * [`RecordingEventListener::on_recording_limit_reached`] fires. The host still calls
+ * `stop` to collect the audio, which is marked as `truncated`. Pre-roll audio kept
+ * by an armed session does not count toward either limit.
+ */
+public struct RecordingLimits: Equatable, Hashable {
+    /**
+     * Longest recording kept, in seconds of captured audio.
+     */
+    public var maxDurationSeconds: UInt32
+    /**
+     * Most memory the capture buffer may use, in bytes.
+     */
+    public var maxBufferBytes: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Longest recording kept, in seconds of captured audio.
+         */maxDurationSeconds: UInt32 = UInt32(1800), 
+        /**
+         * Most memory the capture buffer may use, in bytes.
+         */maxBufferBytes: UInt64 = UInt64(536870912)) {
+        self.maxDurationSeconds = maxDurationSeconds
+        self.maxBufferBytes = maxBufferBytes
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RecordingLimits: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRecordingLimits: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecordingLimits {
+        return
+            try RecordingLimits(
+                maxDurationSeconds: FfiConverterUInt32.read(from: &buf), 
+                maxBufferBytes: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RecordingLimits, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.maxDurationSeconds, into: &buf)
+        FfiConverterUInt64.write(value.maxBufferBytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingLimits_lift(_ buf: RustBuffer) throws -> RecordingLimits {
+    return try FfiConverterTypeRecordingLimits.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingLimits_lower(_ value: RecordingLimits) -> RustBuffer {
+    return FfiConverterTypeRecordingLimits.lower(value)
+}
+
+
+/**
+ * Retry schedule for remote API calls.
+ *
+ * Backoff uses "full jitter": before retry `n` (0-based) the delay is drawn
+ * uniformly from `0..=min(max_delay_ms, base_delay_ms * 2^n)`. A server
+ * `Retry-After` delay replaces the computed backoff; when it is longer than
+ * `max_delay_ms` the call fails right away with the provider error instead of
+ * blocking.
+ */
+public struct RetryPolicy: Equatable, Hashable {
+    /**
+     * Maximum number of attempts, including the first one (must be >= 1).
+     */
+    public var maxAttempts: UInt32
+    /**
+     * Backoff ceiling before the first retry, in milliseconds.
+     */
+    public var baseDelayMs: UInt64
+    /**
+     * Upper bound for any wait between attempts, in milliseconds.
+     */
+    public var maxDelayMs: UInt64
+    /**
+     * Give up instead of retrying when the next attempt would start after
+     * this many milliseconds (0 disables the deadline).
+     */
+    public var totalDeadlineMs: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Maximum number of attempts, including the first one (must be >= 1).
+         */maxAttempts: UInt32, 
+        /**
+         * Backoff ceiling before the first retry, in milliseconds.
+         */baseDelayMs: UInt64, 
+        /**
+         * Upper bound for any wait between attempts, in milliseconds.
+         */maxDelayMs: UInt64, 
+        /**
+         * Give up instead of retrying when the next attempt would start after
+         * this many milliseconds (0 disables the deadline).
+         */totalDeadlineMs: UInt64) {
+        self.maxAttempts = maxAttempts
+        self.baseDelayMs = baseDelayMs
+        self.maxDelayMs = maxDelayMs
+        self.totalDeadlineMs = totalDeadlineMs
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension RetryPolicy: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRetryPolicy: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RetryPolicy {
+        return
+            try RetryPolicy(
+                maxAttempts: FfiConverterUInt32.read(from: &buf), 
+                baseDelayMs: FfiConverterUInt64.read(from: &buf), 
+                maxDelayMs: FfiConverterUInt64.read(from: &buf), 
+                totalDeadlineMs: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RetryPolicy, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.maxAttempts, into: &buf)
+        FfiConverterUInt64.write(value.baseDelayMs, into: &buf)
+        FfiConverterUInt64.write(value.maxDelayMs, into: &buf)
+        FfiConverterUInt64.write(value.totalDeadlineMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryPolicy_lift(_ buf: RustBuffer) throws -> RetryPolicy {
+    return try FfiConverterTypeRetryPolicy.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRetryPolicy_lower(_ value: RetryPolicy) -> RustBuffer {
+    return FfiConverterTypeRetryPolicy.lower(value)
+}
+
+
+/**
+ * Transcription with timing and confidence details.
+ */
+public struct Transcript: Equatable, Hashable {
+    /**
+     * Full transcript text.
+     */
+    public var text: String
+    /**
+     * Language reported by the provider, if any.
+     */
+    public var language: String?
+    /**
+     * Audio duration reported by the provider, in seconds.
+     */
+    public var durationSeconds: Double?
+    /**
+     * Segment timings in order.
+     */
+    public var segments: [TranscriptSegment]
+    /**
+     * Word timings in order; empty unless word timestamps were requested.
+     */
+    public var words: [TranscriptWord]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Full transcript text.
+         */text: String, 
+        /**
+         * Language reported by the provider, if any.
+         */language: String?, 
+        /**
+         * Audio duration reported by the provider, in seconds.
+         */durationSeconds: Double?, 
+        /**
+         * Segment timings in order.
+         */segments: [TranscriptSegment], 
+        /**
+         * Word timings in order; empty unless word timestamps were requested.
+         */words: [TranscriptWord]) {
+        self.text = text
+        self.language = language
+        self.durationSeconds = durationSeconds
+        self.segments = segments
+        self.words = words
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension Transcript: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTranscript: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Transcript {
+        return
+            try Transcript(
+                text: FfiConverterString.read(from: &buf), 
+                language: FfiConverterOptionString.read(from: &buf), 
+                durationSeconds: FfiConverterOptionDouble.read(from: &buf), 
+                segments: FfiConverterSequenceTypeTranscriptSegment.read(from: &buf), 
+                words: FfiConverterSequenceTypeTranscriptWord.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Transcript, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionString.write(value.language, into: &buf)
+        FfiConverterOptionDouble.write(value.durationSeconds, into: &buf)
+        FfiConverterSequenceTypeTranscriptSegment.write(value.segments, into: &buf)
+        FfiConverterSequenceTypeTranscriptWord.write(value.words, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscript_lift(_ buf: RustBuffer) throws -> Transcript {
+    return try FfiConverterTypeTranscript.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscript_lower(_ value: Transcript) -> RustBuffer {
+    return FfiConverterTypeTranscript.lower(value)
+}
+
+
+/**
+ * One transcribed segment, usually a sentence or phrase.
+ */
+public struct TranscriptSegment: Equatable, Hashable {
+    /**
+     * Segment start, in seconds from the beginning of the audio.
+     */
+    public var startSeconds: Double
+    /**
+     * Segment end, in seconds from the beginning of the audio.
+     */
+    public var endSeconds: Double
+    /**
+     * Segment text.
+     */
+    public var text: String
+    /**
+     * Average token log probability; values far below zero suggest low confidence.
+     */
+    public var avgLogprob: Double?
+    /**
+     * Probability that the segment contains no speech.
+     */
+    public var noSpeechProb: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * Segment start, in seconds from the beginning of the audio.
+         */startSeconds: Double, 
+        /**
+         * Segment end, in seconds from the beginning of the audio.
+         */endSeconds: Double, 
+        /**
+         * Segment text.
+         */text: String, 
+        /**
+         * Average token log probability; values far below zero suggest low confidence.
+         */avgLogprob: Double?, 
+        /**
+         * Probability that the segment contains no speech.
+         */noSpeechProb: Double?) {
+        self.startSeconds = startSeconds
+        self.endSeconds = endSeconds
+        self.text = text
+        self.avgLogprob = avgLogprob
+        self.noSpeechProb = noSpeechProb
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension TranscriptSegment: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTranscriptSegment: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TranscriptSegment {
+        return
+            try TranscriptSegment(
+                startSeconds: FfiConverterDouble.read(from: &buf), 
+                endSeconds: FfiConverterDouble.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                avgLogprob: FfiConverterOptionDouble.read(from: &buf), 
+                noSpeechProb: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TranscriptSegment, into buf: inout [UInt8]) {
+        FfiConverterDouble.write(value.startSeconds, into: &buf)
+        FfiConverterDouble.write(value.endSeconds, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionDouble.write(value.avgLogprob, into: &buf)
+        FfiConverterOptionDouble.write(value.noSpeechProb, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscriptSegment_lift(_ buf: RustBuffer) throws -> TranscriptSegment {
+    return try FfiConverterTypeTranscriptSegment.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscriptSegment_lower(_ value: TranscriptSegment) -> RustBuffer {
+    return FfiConverterTypeTranscriptSegment.lower(value)
+}
+
+
+/**
+ * One transcribed word with its timing.
+ */
+public struct TranscriptWord: Equatable, Hashable {
+    /**
+     * The word as transcribed.
+     */
+    public var word: String
+    /**
+     * Word start, in seconds from the beginning of the audio.
+     */
+    public var startSeconds: Double
+    /**
+     * Word end, in seconds from the beginning of the audio.
+     */
+    public var endSeconds: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(
+        /**
+         * The word as transcribed.
+         */word: String, 
+        /**
+         * Word start, in seconds from the beginning of the audio.
+         */startSeconds: Double, 
+        /**
+         * Word end, in seconds from the beginning of the audio.
+         */endSeconds: Double) {
+        self.word = word
+        self.startSeconds = startSeconds
+        self.endSeconds = endSeconds
+    }
+
+    
+
+    
+}
+
+#if compiler(>=6)
+extension TranscriptWord: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTranscriptWord: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TranscriptWord {
+        return
+            try TranscriptWord(
+                word: FfiConverterString.read(from: &buf), 
+                startSeconds: FfiConverterDouble.read(from: &buf), 
+                endSeconds: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TranscriptWord, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.word, into: &buf)
+        FfiConverterDouble.write(value.startSeconds, into: &buf)
+        FfiConverterDouble.write(value.endSeconds, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscriptWord_lift(_ buf: RustBuffer) throws -> TranscriptWord {
+    return try FfiConverterTypeTranscriptWord.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscriptWord_lower(_ value: TranscriptWord) -> RustBuffer {
+    return FfiConverterTypeTranscriptWord.lower(value)
+}
+
+
+/**
+ * Unified error type returned by core operations.
+ */
+public enum CoreError: Swift.Error, Equatable, Hashable, Foundation.LocalizedError {
+
+    
+    
+    /**
+     * Operation was cancelled by caller.
+     */
+    case Cancelled
+    /**
+     * No default input audio device is available.
+     */
+    case AudioDeviceUnavailable
+    /**
+     * Requested input audio device does not exist.
+     */
+    case AudioDeviceNotFound(String
+    )
+    /**
      * Recording was started while another capture session is active.
      */
     case RecordingAlreadyActive
@@ -815,11 +3262,23 @@ public enum CoreError: Swift.Error, Equatable, Hashable, Foundation.LocalizedErr
      * Recording was stopped without an active capture session.
      */
     case RecordingNotActive
+    /**
+     * Recording was paused while already paused.
+     */
+    case RecordingAlreadyPaused
+    /**
+     * Recording was resumed while not paused.
+     */
+    case RecordingNotPaused
     /**
      * Audio capture failed.
      */
     case AudioCapture(String
     )
+    /**
+     * Recording contained no detectable speech.
+     */
+    case NoSpeechDetected
     /**
      * Audio processing failed.
      */
@@ -831,321 +3290,1105 @@ public enum CoreError: Swift.Error, Equatable, Hashable, Foundation.LocalizedErr
     case Http(String
     )
     /**
-     * Remote API returned a non-success response.
+     * Remote API returned a response that could not be used.
      */
     case Api(String
     )
+    /**
+     * Provider rejected the API key (HTTP 401/403, or a key error reported as 400).
+     */
+    case Unauthorized(
+        /**
+         * Provider display name, e.g. `Gemini`.
+         */provider: String, 
+        /**
+         * HTTP status code.
+         */status: UInt16, 
+        /**
+         * Provider error body, truncated.
+         */body: String
+    )
+    /**
+     * Provider is throttling requests (HTTP 429).
+     */
+    case RateLimited(
+        /**
+         * Provider display name.
+         */provider: String, 
+        /**
+         * Server-suggested wait before retrying, in whole seconds.
+         */retryAfterSecs: UInt64?, 
+        /**
+         * Provider error body, truncated.
+         */body: String
+    )
+    /**
+     * Account has run out of quota or credit.
+     */
+    case QuotaExceeded(
+        /**
+         * Provider display name.
+         */provider: String, 
+        /**
+         * HTTP status code.
+         */status: UInt16, 
+        /**
+         * Provider error body, truncated.
+         */body: String
+    )
+    /**
+     * Provider refused to generate content for safety or policy reasons.
+     */
+    case ContentBlocked(
+        /**
+         * Provider display name.
+         */provider: String, 
+        /**
+         * Provider-reported block or finish reason.
+         */reason: String
+    )
+    /**
+     * Request timed out, either locally or at a gateway (HTTP 408/504).
+     */
+    case Timeout(
+        /**
+         * Provider display name.
+         */provider: String, 
+        /**
+         * HTTP status code, or `None` when no response arrived in time.
+         */status: UInt16?, 
+        /**
+         * Provider error body, truncated; empty for local timeouts.
+         */body: String
+    )
+    /**
+     * Provider returned any other non-success HTTP status.
+     */
+    case ApiStatus(
+        /**
+         * Provider display name.
+         */provider: String, 
+        /**
+         * HTTP status code.
+         */status: UInt16, 
+        /**
+         * Provider error body, truncated.
+         */body: String
+    )
     /**
      * Serialization or deserialization failed.
      */
     case Serialization(String
     )
     /**
-     * API returned no usable content.
+     * API returned no usable content.
+     */
+    case EmptyResponse
+    /**
+     * Transcription operation failed.
+     */
+    case Transcription(String
+    )
+    /**
+     * Configuration is invalid or missing.
+     */
+    case Config(String
+    )
+
+    
+    /**
+     * Whether repeating the same request later may succeed.
+     */
+public func isRetryable() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_coreerror_is_retryable(
+            FfiConverterTypeCoreError_lower(self),$0
+    )
+})
+}
+    
+    /**
+     * HTTP status code returned by the provider, if the error came from one.
+     */
+public func statusCode() -> UInt16?  {
+    return try!  FfiConverterOptionUInt16.lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_method_coreerror_status_code(
+            FfiConverterTypeCoreError_lower(self),$0
+    )
+})
+}
+    
+
+    
+
+    
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+    
+}
+
+#if compiler(>=6)
+extension CoreError: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCoreError: FfiConverterRustBuffer {
+    typealias SwiftType = CoreError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CoreError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Cancelled
+        case 2: return .AudioDeviceUnavailable
+        case 3: return .AudioDeviceNotFound(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 4: return .RecordingAlreadyActive
+        case 5: return .RecordingNotActive
+        case 6: return .RecordingAlreadyPaused
+        case 7: return .RecordingNotPaused
+        case 8: return .AudioCapture(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 9: return .NoSpeechDetected
+        case 10: return .AudioProcessing(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 11: return .Http(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 12: return .Api(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 13: return .Unauthorized(
+            provider: try FfiConverterString.read(from: &buf), 
+            status: try FfiConverterUInt16.read(from: &buf), 
+            body: try FfiConverterString.read(from: &buf)
+            )
+        case 14: return .RateLimited(
+            provider: try FfiConverterString.read(from: &buf), 
+            retryAfterSecs: try FfiConverterOptionUInt64.read(from: &buf), 
+            body: try FfiConverterString.read(from: &buf)
+            )
+        case 15: return .QuotaExceeded(
+            provider: try FfiConverterString.read(from: &buf), 
+            status: try FfiConverterUInt16.read(from: &buf), 
+            body: try FfiConverterString.read(from: &buf)
+            )
+        case 16: return .ContentBlocked(
+            provider: try FfiConverterString.read(from: &buf), 
+            reason: try FfiConverterString.read(from: &buf)
+            )
+        case 17: return .Timeout(
+            provider: try FfiConverterString.read(from: &buf), 
+            status: try FfiConverterOptionUInt16.read(from: &buf), 
+            body: try FfiConverterString.read(from: &buf)
+            )
+        case 18: return .ApiStatus(
+            provider: try FfiConverterString.read(from: &buf), 
+            status: try FfiConverterUInt16.read(from: &buf), 
+            body: try FfiConverterString.read(from: &buf)
+            )
+        case 19: return .Serialization(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 20: return .EmptyResponse
+        case 21: return .Transcription(
+            try FfiConverterString.read(from: &buf)
+            )
+        case 22: return .Config(
+            try FfiConverterString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CoreError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case .Cancelled:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .AudioDeviceUnavailable:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .AudioDeviceNotFound(v1):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case .RecordingAlreadyActive:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .RecordingNotActive:
+            writeInt(&buf, Int32(5))
+        
+        
+        case .RecordingAlreadyPaused:
+            writeInt(&buf, Int32(6))
+        
+        
+        case .RecordingNotPaused:
+            writeInt(&buf, Int32(7))
+        
+        
+        case let .AudioCapture(v1):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case .NoSpeechDetected:
+            writeInt(&buf, Int32(9))
+        
+        
+        case let .AudioProcessing(v1):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Http(v1):
+            writeInt(&buf, Int32(11))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Api(v1):
+            writeInt(&buf, Int32(12))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Unauthorized(provider,status,body):
+            writeInt(&buf, Int32(13))
+            FfiConverterString.write(provider, into: &buf)
+            FfiConverterUInt16.write(status, into: &buf)
+            FfiConverterString.write(body, into: &buf)
+            
+        
+        case let .RateLimited(provider,retryAfterSecs,body):
+            writeInt(&buf, Int32(14))
+            FfiConverterString.write(provider, into: &buf)
+            FfiConverterOptionUInt64.write(retryAfterSecs, into: &buf)
+            FfiConverterString.write(body, into: &buf)
+            
+        
+        case let .QuotaExceeded(provider,status,body):
+            writeInt(&buf, Int32(15))
+            FfiConverterString.write(provider, into: &buf)
+            FfiConverterUInt16.write(status, into: &buf)
+            FfiConverterString.write(body, into: &buf)
+            
+        
+        case let .ContentBlocked(provider,reason):
+            writeInt(&buf, Int32(16))
+            FfiConverterString.write(provider, into: &buf)
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case let .Timeout(provider,status,body):
+            writeInt(&buf, Int32(17))
+            FfiConverterString.write(provider, into: &buf)
+            FfiConverterOptionUInt16.write(status, into: &buf)
+            FfiConverterString.write(body, into: &buf)
+            
+        
+        case let .ApiStatus(provider,status,body):
+            writeInt(&buf, Int32(18))
+            FfiConverterString.write(provider, into: &buf)
+            FfiConverterUInt16.write(status, into: &buf)
+            FfiConverterString.write(body, into: &buf)
+            
+        
+        case let .Serialization(v1):
+            writeInt(&buf, Int32(19))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case .EmptyResponse:
+            writeInt(&buf, Int32(20))
+        
+        
+        case let .Transcription(v1):
+            writeInt(&buf, Int32(21))
+            FfiConverterString.write(v1, into: &buf)
+            
+        
+        case let .Config(v1):
+            writeInt(&buf, Int32(22))
+            FfiConverterString.write(v1, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCoreError_lift(_ buf: RustBuffer) throws -> CoreError {
+    return try FfiConverterTypeCoreError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCoreError_lower(_ value: CoreError) -> RustBuffer {
+    return FfiConverterTypeCoreError.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Supported LLM providers for polish and generic text-processing flows.
+ */
+
+public enum LlmProvider: Equatable, Hashable {
+    
+    /**
+     * Google AI Studio Gemini API.
+     */
+    case googleAiStudio
+    /**
+     * OpenAI Chat Completions API.
      */
-    case EmptyResponse
+    case openai
     /**
-     * Transcription operation failed.
+     * Anthropic Messages API.
      */
-    case Transcription(String
-    )
+    case anthropic
     /**
-     * Configuration is invalid or missing.
+     * Any server implementing the OpenAI `chat/completions` endpoint, such as
+     * Ollama or the llama.cpp server. The API key may be empty.
      */
-    case Config(String
+    case openAiCompatible(
+        /**
+         * API base URL including the version prefix, e.g. `http://localhost:11434/v1`.
+         */baseUrl: String, 
+        /**
+         * Model name sent with each request.
+         */model: String
     )
 
-    
 
-    
 
-    
-    public var errorDescription: String? {
-        String(reflecting: self)
-    }
-    
+
+
 }
 
 #if compiler(>=6)
-extension CoreError: Sendable {}
+extension LlmProvider: Sendable {}
 #endif
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeCoreError: FfiConverterRustBuffer {
-    typealias SwiftType = CoreError
+public struct FfiConverterTypeLlmProvider: FfiConverterRustBuffer {
+    typealias SwiftType = LlmProvider
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CoreError {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LlmProvider {
         let variant: Int32 = try readInt(&buf)
         switch variant {
-
         
-
+        case 1: return .googleAiStudio
         
-        case 1: return .Cancelled
-        case 2: return .AudioDeviceUnavailable
-        case 3: return .RecordingAlreadyActive
-        case 4: return .RecordingNotActive
-        case 5: return .AudioCapture(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 6: return .AudioProcessing(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 7: return .Http(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 8: return .Api(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 9: return .Serialization(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 10: return .EmptyResponse
-        case 11: return .Transcription(
-            try FfiConverterString.read(from: &buf)
-            )
-        case 12: return .Config(
-            try FfiConverterString.read(from: &buf)
-            )
-
-         default: throw UniffiInternalError.unexpectedEnumCase
+        case 2: return .openai
+        
+        case 3: return .anthropic
+        
+        case 4: return .openAiCompatible(baseUrl: try FfiConverterString.read(from: &buf), model: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
         }
     }
 
-    public static func write(_ value: CoreError, into buf: inout [UInt8]) {
+    public static func write(_ value: LlmProvider, into buf: inout [UInt8]) {
         switch value {
-
-        
-
         
         
-        case .Cancelled:
+        case .googleAiStudio:
             writeInt(&buf, Int32(1))
         
         
-        case .AudioDeviceUnavailable:
+        case .openai:
             writeInt(&buf, Int32(2))
         
         
-        case .RecordingAlreadyActive:
+        case .anthropic:
             writeInt(&buf, Int32(3))
         
         
-        case .RecordingNotActive:
+        case let .openAiCompatible(baseUrl,model):
             writeInt(&buf, Int32(4))
+            FfiConverterString.write(baseUrl, into: &buf)
+            FfiConverterString.write(model, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLlmProvider_lift(_ buf: RustBuffer) throws -> LlmProvider {
+    return try FfiConverterTypeLlmProvider.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLlmProvider_lower(_ value: LlmProvider) -> RustBuffer {
+    return FfiConverterTypeLlmProvider.lower(value)
+}
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+/**
+ * Supported speech-to-text providers.
+ */
+
+public enum TranscriptionProvider: Equatable, Hashable {
+    
+    /**
+     * Groq Whisper API.
+     */
+    case groq
+    /**
+     * OpenAI audio transcriptions API.
+     */
+    case openai
+    /**
+     * Any server implementing the OpenAI `audio/transcriptions` endpoint.
+     */
+    case openAiCompatible(
+        /**
+         * API base URL including the version prefix, e.g. `http://localhost:8000/v1`.
+         */baseUrl: String, 
+        /**
+         * Model name sent with each transcription request.
+         */model: String
+    )
+
+
+
+
+
+}
+
+#if compiler(>=6)
+extension TranscriptionProvider: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTranscriptionProvider: FfiConverterRustBuffer {
+    typealias SwiftType = TranscriptionProvider
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TranscriptionProvider {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
         
+        case 1: return .groq
         
-        case let .AudioCapture(v1):
-            writeInt(&buf, Int32(5))
-            FfiConverterString.write(v1, into: &buf)
-            
+        case 2: return .openai
         
-        case let .AudioProcessing(v1):
-            writeInt(&buf, Int32(6))
-            FfiConverterString.write(v1, into: &buf)
-            
+        case 3: return .openAiCompatible(baseUrl: try FfiConverterString.read(from: &buf), model: try FfiConverterString.read(from: &buf)
+        )
         
-        case let .Http(v1):
-            writeInt(&buf, Int32(7))
-            FfiConverterString.write(v1, into: &buf)
-            
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TranscriptionProvider, into buf: inout [UInt8]) {
+        switch value {
         
-        case let .Api(v1):
-            writeInt(&buf, Int32(8))
-            FfiConverterString.write(v1, into: &buf)
-            
         
-        case let .Serialization(v1):
-            writeInt(&buf, Int32(9))
-            FfiConverterString.write(v1, into: &buf)
-            
+        case .groq:
+            writeInt(&buf, Int32(1))
         
-        case .EmptyResponse:
-            writeInt(&buf, Int32(10))
         
+        case .openai:
+            writeInt(&buf, Int32(2))
         
-        case let .Transcription(v1):
-            writeInt(&buf, Int32(11))
-            FfiConverterString.write(v1, into: &buf)
-            
         
-        case let .Config(v1):
-            writeInt(&buf, Int32(12))
-            FfiConverterString.write(v1, into: &buf)
+        case let .openAiCompatible(baseUrl,model):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(baseUrl, into: &buf)
+            FfiConverterString.write(model, into: &buf)
             
         }
     }
 }
 
-
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscriptionProvider_lift(_ buf: RustBuffer) throws -> TranscriptionProvider {
+    return try FfiConverterTypeTranscriptionProvider.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTranscriptionProvider_lower(_ value: TranscriptionProvider) -> RustBuffer {
+    return FfiConverterTypeTranscriptionProvider.lower(value)
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt16: FfiConverterRustBuffer {
+    typealias SwiftType = UInt16?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt16.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt16.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionFloat: FfiConverterRustBuffer {
+    typealias SwiftType = Float?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterFloat.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterFloat.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCoreError_lift(_ buf: RustBuffer) throws -> CoreError {
-    return try FfiConverterTypeCoreError.lift(buf)
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeCoreError_lower(_ value: CoreError) -> RustBuffer {
-    return FfiConverterTypeCoreError.lower(value)
-}
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-/**
- * Supported LLM providers for polish and generic text-processing flows.
- */
-
-public enum LlmProvider: Equatable, Hashable {
-    
-    /**
-     * Google AI Studio Gemini API.
-     */
-    case googleAiStudio
-    /**
-     * OpenAI Chat Completions API.
-     */
-    case openai
+fileprivate struct FfiConverterOptionTypeRecordingEventListener: FfiConverterRustBuffer {
+    typealias SwiftType = RecordingEventListener?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRecordingEventListener.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRecordingEventListener.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
 
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
+        }
+        return seq
+    }
 }
 
-#if compiler(>=6)
-extension LlmProvider: Sendable {}
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+fileprivate struct FfiConverterSequenceFloat: FfiConverterRustBuffer {
+    typealias SwiftType = [Float]
+
+    public static func write(_ value: [Float], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterFloat.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Float] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Float]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterFloat.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeLlmProvider: FfiConverterRustBuffer {
-    typealias SwiftType = LlmProvider
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LlmProvider {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .googleAiStudio
-        
-        case 2: return .openai
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: LlmProvider, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .googleAiStudio:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .openai:
-            writeInt(&buf, Int32(2))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
         }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLlmProvider_lift(_ buf: RustBuffer) throws -> LlmProvider {
-    return try FfiConverterTypeLlmProvider.lift(buf)
+fileprivate struct FfiConverterSequenceTypeDictionaryEntry: FfiConverterRustBuffer {
+    typealias SwiftType = [DictionaryEntry]
+
+    public static func write(_ value: [DictionaryEntry], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeDictionaryEntry.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [DictionaryEntry] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [DictionaryEntry]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeDictionaryEntry.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeLlmProvider_lower(_ value: LlmProvider) -> RustBuffer {
-    return FfiConverterTypeLlmProvider.lower(value)
-}
+fileprivate struct FfiConverterSequenceTypeInputDeviceInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [InputDeviceInfo]
 
+    public static func write(_ value: [InputDeviceInfo], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeInputDeviceInfo.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [InputDeviceInfo] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [InputDeviceInfo]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeInputDeviceInfo.read(from: &buf))
+        }
+        return seq
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionFloat: FfiConverterRustBuffer {
-    typealias SwiftType = Float?
+fileprivate struct FfiConverterSequenceTypeTranscriptSegment: FfiConverterRustBuffer {
+    typealias SwiftType = [TranscriptSegment]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [TranscriptSegment], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTranscriptSegment.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterFloat.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterFloat.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TranscriptSegment] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TranscriptSegment]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTranscriptSegment.read(from: &buf))
         }
+        return seq
     }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
+fileprivate struct FfiConverterSequenceTypeTranscriptWord: FfiConverterRustBuffer {
+    typealias SwiftType = [TranscriptWord]
 
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
+    public static func write(_ value: [TranscriptWord], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTranscriptWord.write(item, into: &buf)
         }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TranscriptWord] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TranscriptWord]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTranscriptWord.read(from: &buf))
         }
+        return seq
     }
 }
+/**
+ * Add a transcript replacement, or update the entry with the same pattern.
+ *
+ * Returns `CoreError::Config` if the pattern is empty or an invalid regular expression.
+ */
+public func addDictionaryEntry(entry: DictionaryEntry)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_add_dictionary_entry(
+        FfiConverterTypeDictionaryEntry_lower(entry),$0
+    )
+}
+}
+/**
+ * Add a term to the vocabulary sent to the transcription model as a spelling hint.
+ */
+public func addVocabularyTerm(term: String)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_add_vocabulary_term(
+        FfiConverterString.lower(term),$0
+    )
+}
+}
+/**
+ * Apply the dictionary replacements to arbitrary text.
+ */
+public func applyDictionary(text: String) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_func_apply_dictionary(
+        FfiConverterString.lower(text),$0
+    )
+})
+}
+/**
+ * Keep the default input device open and buffer the last 500 ms of audio, so the
+ * next [`start_recording`] includes speech that began just before it was called.
+ *
+ * The microphone stays in use until [`disarm_microphone`].
+ */
+public func armMicrophone()throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_arm_microphone($0
+    )
+}
+}
+/**
+ * Arm a specific input device for pre-roll; pair with [`start_recording_with_device`].
+ */
+public func armMicrophoneWithDevice(deviceId: String)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_arm_microphone_with_device(
+        FfiConverterString.lower(deviceId),$0
+    )
+}
+}
+/**
+ * Remove the listener registered with [`set_recording_event_listener`].
+ */
+public func clearRecordingEventListener()throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_clear_recording_event_listener($0
+    )
+}
+}
+/**
+ * Return the built-in provider URLs and model names.
+ */
+public func defaultCoreConfig() -> CoreConfig  {
+    return try!  FfiConverterTypeCoreConfig_lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_func_default_core_config($0
+    )
+})
+}
+/**
+ * Process-wide recording session used by the free recording functions.
+ *
+ * Hosts that need more than one capture, or want to own the session lifetime, can
+ * create their own with [`RecordingSession::new`] instead.
+ */
+public func defaultRecordingSession() -> RecordingSession  {
+    return try!  FfiConverterTypeRecordingSession_lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_func_default_recording_session($0
+    )
+})
+}
+/**
+ * Stop buffering pre-roll and release the microphone when not recording.
+ */
+public func disarmMicrophone()throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_disarm_microphone($0
+    )
+}
+}
+/**
+ * Return the provider URLs and model names currently used by the core.
+ */
+public func getCoreConfig() -> CoreConfig  {
+    return try!  FfiConverterTypeCoreConfig_lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_func_get_core_config($0
+    )
+})
+}
+/**
+ * Live RMS, peak, clipping and waveform levels of the active recording.
+ *
+ * Cheap enough to poll from a UI timer: levels are computed in the capture callback
+ * and read without locking. Returns silent levels when no recording is active.
+ */
+public func getCurrentLevels() -> AudioLevels  {
+    return try!  FfiConverterTypeAudioLevels_lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_func_get_current_levels($0
+    )
+})
+}
+/**
+ * Return the vocabulary and replacements currently applied to transcripts.
+ */
+public func getDictionary() -> Dictionary  {
+    return try!  FfiConverterTypeDictionary_lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_func_get_dictionary($0
+    )
+})
+}
+/**
+ * Limits applied to new recordings; 30 minutes and 512 MiB unless changed.
+ */
+public func getRecordingLimits() -> RecordingLimits  {
+    return try!  FfiConverterTypeRecordingLimits_lift(try! rustCall() {
+    uniffi_diy_typeless_core_fn_func_get_recording_limits($0
+    )
+})
+}
+/**
+ * List available input audio devices with their default and supported configurations.
+ */
+public func listInputDevices()throws  -> [InputDeviceInfo]  {
+    return try  FfiConverterSequenceTypeInputDeviceInfo.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_list_input_devices($0
+    )
+})
+}
+/**
+ * Pause the active recording without closing the input stream.
+ *
+ * Audio captured while paused is discarded. Fails with
+ * [`CoreError::RecordingNotActive`] when idle and [`CoreError::RecordingAlreadyPaused`]
+ * when already paused.
+ */
+public func pauseRecording()throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_pause_recording($0
+    )
+}
+}
+/**
+ * Polish raw transcript text with the selected LLM provider.
+ */
+public func polishText(provider: LlmProvider, apiKey: String, rawText: String, context: String?)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_polish_text(
+        FfiConverterTypeLlmProvider_lower(provider),
+        FfiConverterString.lower(apiKey),
+        FfiConverterString.lower(rawText),
+        FfiConverterOptionString.lower(context),$0
+    )
+})
+}
 /**
  * Polish raw transcript text with the selected LLM provider.
+ *
+ * Supports cooperative cancellation using a shared cancellation token.
  */
-public func polishText(provider: LlmProvider, apiKey: String, rawText: String, context: String?)throws  -> String  {
+public func polishTextCancellable(provider: LlmProvider, apiKey: String, rawText: String, context: String?, cancellationToken: CancellationToken)throws  -> String  {
     return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
-    uniffi_diy_typeless_core_fn_func_polish_text(
+    uniffi_diy_typeless_core_fn_func_polish_text_cancellable(
         FfiConverterTypeLlmProvider_lower(provider),
         FfiConverterString.lower(apiKey),
         FfiConverterString.lower(rawText),
-        FfiConverterOptionString.lower(context),$0
+        FfiConverterOptionString.lower(context),
+        FfiConverterTypeCancellationToken_lower(cancellationToken),$0
     )
 })
 }
 /**
- * Polish raw transcript text with the selected LLM provider.
+ * Polish raw transcript text, delivering generated text to `listener` as it streams in.
  *
- * Supports cooperative cancellation using a shared cancellation token.
+ * Returns the complete polished text. Cancelling the token stops the stream mid-response.
  */
-public func polishTextCancellable(provider: LlmProvider, apiKey: String, rawText: String, context: String?, cancellationToken: CancellationToken)throws  -> String  {
+public func polishTextStreaming(provider: LlmProvider, apiKey: String, rawText: String, context: String?, listener: LlmStreamListener, cancellationToken: CancellationToken)throws  -> String  {
     return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
-    uniffi_diy_typeless_core_fn_func_polish_text_cancellable(
+    uniffi_diy_typeless_core_fn_func_polish_text_streaming(
         FfiConverterTypeLlmProvider_lower(provider),
         FfiConverterString.lower(apiKey),
         FfiConverterString.lower(rawText),
         FfiConverterOptionString.lower(context),
+        FfiConverterTypeLlmStreamListener_lower(listener),
         FfiConverterTypeCancellationToken_lower(cancellationToken),$0
     )
 })
 }
+/**
+ * Decode an audio file (WAV, MP3, OGG Vorbis, FLAC or M4A/AAC/ALAC) and return
+ * FLAC-encoded audio ready for transcription.
+ *
+ * The file is downmixed to mono, resampled to 16 kHz and processed exactly like a
+ * recording returned by [`stop_recording`]. Unsupported or corrupt files fail with
+ * [`CoreError::AudioProcessing`].
+ */
+public func prepareAudioFile(audioBytes: Data)throws  -> AudioData  {
+    return try  FfiConverterTypeAudioData_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_prepare_audio_file(
+        FfiConverterData.lower(audioBytes),$0
+    )
+})
+}
+/**
+ * Decode an audio file and return FLAC-encoded audio using custom post-processing options.
+ */
+public func prepareAudioFileWithOptions(audioBytes: Data, options: AudioProcessingOptions)throws  -> AudioData  {
+    return try  FfiConverterTypeAudioData_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_prepare_audio_file_with_options(
+        FfiConverterData.lower(audioBytes),
+        FfiConverterTypeAudioProcessingOptions_lower(options),$0
+    )
+})
+}
 /**
  * Process text with the selected LLM provider.
  * Generic function for processing text with custom prompts
@@ -1179,6 +4422,115 @@ public func processTextWithLlmCancellable(provider: LlmProvider, apiKey: String,
     )
 })
 }
+/**
+ * Process arbitrary text with the selected LLM provider, delivering generated text to
+ * `listener` as it streams in.
+ *
+ * Returns the complete response. Cancelling the token stops the stream mid-response.
+ */
+public func processTextWithLlmStreaming(provider: LlmProvider, apiKey: String, prompt: String, systemInstruction: String?, temperature: Float?, listener: LlmStreamListener, cancellationToken: CancellationToken)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_process_text_with_llm_streaming(
+        FfiConverterTypeLlmProvider_lower(provider),
+        FfiConverterString.lower(apiKey),
+        FfiConverterString.lower(prompt),
+        FfiConverterOptionString.lower(systemInstruction),
+        FfiConverterOptionFloat.lower(temperature),
+        FfiConverterTypeLlmStreamListener_lower(listener),
+        FfiConverterTypeCancellationToken_lower(cancellationToken),$0
+    )
+})
+}
+/**
+ * Remove the transcript replacement with this pattern. Returns whether it was present.
+ */
+public func removeDictionaryEntry(pattern: String)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_remove_dictionary_entry(
+        FfiConverterString.lower(pattern),$0
+    )
+})
+}
+/**
+ * Remove a vocabulary term, ignoring case. Returns whether the term was present.
+ */
+public func removeVocabularyTerm(term: String)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_remove_vocabulary_term(
+        FfiConverterString.lower(term),$0
+    )
+})
+}
+/**
+ * Resume a recording paused with [`pause_recording`].
+ *
+ * Fails with [`CoreError::RecordingNotActive`] when idle and
+ * [`CoreError::RecordingNotPaused`] when not paused.
+ */
+public func resumeRecording()throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_resume_recording($0
+    )
+}
+}
+/**
+ * Transcribe and polish encoded audio in one call.
+ *
+ * Both stages share `cancellation_token`. If polish fails for any reason other than
+ * cancellation, the result falls back to the raw transcript and records the failure in
+ * `polish_error`.
+ */
+public func runPipeline(audioBytes: Data, options: PipelineOptions, cancellationToken: CancellationToken)throws  -> PipelineResult  {
+    return try  FfiConverterTypePipelineResult_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_run_pipeline(
+        FfiConverterData.lower(audioBytes),
+        FfiConverterTypePipelineOptions_lower(options),
+        FfiConverterTypeCancellationToken_lower(cancellationToken),$0
+    )
+})
+}
+/**
+ * Replace the provider URLs and model names used by subsequent requests.
+ *
+ * Returns `CoreError::Config` if a URL is not http(s) or a model name is empty.
+ */
+public func setCoreConfig(config: CoreConfig)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_set_core_config(
+        FfiConverterTypeCoreConfig_lower(config),$0
+    )
+}
+}
+/**
+ * Replace the whole dictionary, e.g. after loading it from storage.
+ *
+ * Returns `CoreError::Config` if a pattern is empty or an invalid regular expression.
+ */
+public func setDictionary(dictionary: Dictionary)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_set_dictionary(
+        FfiConverterTypeDictionary_lower(dictionary),$0
+    )
+}
+}
+/**
+ * Register the listener notified when a recording reaches its limits.
+ */
+public func setRecordingEventListener(listener: RecordingEventListener)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_set_recording_event_listener(
+        FfiConverterTypeRecordingEventListener_lower(listener),$0
+    )
+}
+}
+/**
+ * Cap the duration and buffer size of recordings started after this call.
+ *
+ * A recording that reaches either limit stops capturing and releases the microphone;
+ * [`stop_recording`] still returns the audio, with `truncated` set.
+ */
+public func setRecordingLimits(limits: RecordingLimits)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_set_recording_limits(
+        FfiConverterTypeRecordingLimits_lower(limits),$0
+    )
+}
+}
 /**
  * Start microphone capture.
  *
@@ -1189,10 +4541,23 @@ public func startRecording()throws   {try rustCallWithError(FfiConverterTypeCore
     )
 }
 }
+/**
+ * Start microphone capture on a specific input device.
+ *
+ * `device_id` is an identifier (or exact name) reported by [`list_input_devices`].
+ */
+public func startRecordingWithDevice(deviceId: String)throws   {try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_start_recording_with_device(
+        FfiConverterString.lower(deviceId),$0
+    )
+}
+}
 /**
  * Stop microphone capture and return FLAC-encoded audio.
  *
- * The returned payload is optimized for transcription upload.
+ * The returned payload is optimized for transcription upload: leading and trailing
+ * silence is trimmed, and recordings without speech fail with
+ * [`CoreError::NoSpeechDetected`].
  */
 public func stopRecording()throws  -> AudioData  {
     return try  FfiConverterTypeAudioData_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
@@ -1200,6 +4565,16 @@ public func stopRecording()throws  -> AudioData  {
     )
 })
 }
+/**
+ * Stop microphone capture and return FLAC-encoded audio using custom post-processing options.
+ */
+public func stopRecordingWithOptions(options: AudioProcessingOptions)throws  -> AudioData  {
+    return try  FfiConverterTypeAudioData_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_stop_recording_with_options(
+        FfiConverterTypeAudioProcessingOptions_lower(options),$0
+    )
+})
+}
 /**
  * Transcribe encoded audio bytes with Groq Whisper API.
  */
@@ -1227,6 +4602,94 @@ public func transcribeAudioBytesCancellable(apiKey: String, audioBytes: Data, la
     )
 })
 }
+/**
+ * Transcribe encoded audio bytes with the selected transcription provider.
+ *
+ * `api_key` may be empty for OpenAI-compatible servers that do not require authentication.
+ */
+public func transcribeAudioBytesWithProvider(provider: TranscriptionProvider, apiKey: String, audioBytes: Data, language: String?)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_transcribe_audio_bytes_with_provider(
+        FfiConverterTypeTranscriptionProvider_lower(provider),
+        FfiConverterString.lower(apiKey),
+        FfiConverterData.lower(audioBytes),
+        FfiConverterOptionString.lower(language),$0
+    )
+})
+}
+/**
+ * Transcribe encoded audio bytes with the selected transcription provider.
+ *
+ * Supports cooperative cancellation using a shared cancellation token.
+ */
+public func transcribeAudioBytesWithProviderCancellable(provider: TranscriptionProvider, apiKey: String, audioBytes: Data, language: String?, cancellationToken: CancellationToken)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_transcribe_audio_bytes_with_provider_cancellable(
+        FfiConverterTypeTranscriptionProvider_lower(provider),
+        FfiConverterString.lower(apiKey),
+        FfiConverterData.lower(audioBytes),
+        FfiConverterOptionString.lower(language),
+        FfiConverterTypeCancellationToken_lower(cancellationToken),$0
+    )
+})
+}
+/**
+ * Transcribe encoded audio bytes and return segment timings, confidence and the
+ * detected language.
+ *
+ * Set `word_timestamps` to also request per-word timings; not every provider or
+ * model supports them.
+ */
+public func transcribeAudioDetailed(provider: TranscriptionProvider, apiKey: String, audioBytes: Data, language: String?, wordTimestamps: Bool)throws  -> Transcript  {
+    return try  FfiConverterTypeTranscript_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_transcribe_audio_detailed(
+        FfiConverterTypeTranscriptionProvider_lower(provider),
+        FfiConverterString.lower(apiKey),
+        FfiConverterData.lower(audioBytes),
+        FfiConverterOptionString.lower(language),
+        FfiConverterBool.lower(wordTimestamps),$0
+    )
+})
+}
+/**
+ * Transcribe encoded audio bytes with segment timings.
+ *
+ * Supports cooperative cancellation using a shared cancellation token.
+ */
+public func transcribeAudioDetailedCancellable(provider: TranscriptionProvider, apiKey: String, audioBytes: Data, language: String?, wordTimestamps: Bool, cancellationToken: CancellationToken)throws  -> Transcript  {
+    return try  FfiConverterTypeTranscript_lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_transcribe_audio_detailed_cancellable(
+        FfiConverterTypeTranscriptionProvider_lower(provider),
+        FfiConverterString.lower(apiKey),
+        FfiConverterData.lower(audioBytes),
+        FfiConverterOptionString.lower(language),
+        FfiConverterBool.lower(wordTimestamps),
+        FfiConverterTypeCancellationToken_lower(cancellationToken),$0
+    )
+})
+}
+/**
+ * Transcribe a recording of any length by splitting it into chunks that
+ * stay under provider upload limits.
+ *
+ * Each cut is placed at the quietest point shortly before the target chunk
+ * length, chunks are transcribed with up to `options.max_parallel_requests`
+ * concurrent requests, and words repeated across chunk overlaps are removed
+ * when the text is joined. Accepts any format supported by [`prepare_audio_file`];
+ * FLAC recordings shorter than one chunk are uploaded unchanged.
+ */
+public func transcribeLongAudio(provider: TranscriptionProvider, apiKey: String, audioBytes: Data, language: String?, options: LongAudioOptions, cancellationToken: CancellationToken)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeCoreError_lift) {
+    uniffi_diy_typeless_core_fn_func_transcribe_long_audio(
+        FfiConverterTypeTranscriptionProvider_lower(provider),
+        FfiConverterString.lower(apiKey),
+        FfiConverterData.lower(audioBytes),
+        FfiConverterOptionString.lower(language),
+        FfiConverterTypeLongAudioOptions_lower(options),
+        FfiConverterTypeCancellationToken_lower(cancellationToken),$0
+    )
+})
+}
 /**
  * Warm up TLS connection to Groq API
  *
@@ -1277,22 +4740,109 @@ private let initializationResult: InitializationResult = {
     if bindings_contract_version != scaffolding_contract_version {
         return InitializationResult.contractVersionMismatch
     }
+    if (uniffi_diy_typeless_core_checksum_func_add_dictionary_entry() != 9870) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_add_vocabulary_term() != 62759) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_apply_dictionary() != 44819) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_arm_microphone() != 26558) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_arm_microphone_with_device() != 19079) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_clear_recording_event_listener() != 5178) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_default_core_config() != 7868) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_default_recording_session() != 17288) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_disarm_microphone() != 59696) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_get_core_config() != 64498) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_get_current_levels() != 45795) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_get_dictionary() != 41537) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_get_recording_limits() != 21500) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_list_input_devices() != 34141) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_pause_recording() != 21252) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_diy_typeless_core_checksum_func_polish_text() != 41834) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_diy_typeless_core_checksum_func_polish_text_cancellable() != 21561) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_diy_typeless_core_checksum_func_polish_text_streaming() != 40277) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_prepare_audio_file() != 16848) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_prepare_audio_file_with_options() != 46894) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_diy_typeless_core_checksum_func_process_text_with_llm() != 35189) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_diy_typeless_core_checksum_func_process_text_with_llm_cancellable() != 57067) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_diy_typeless_core_checksum_func_process_text_with_llm_streaming() != 56591) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_remove_dictionary_entry() != 18908) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_remove_vocabulary_term() != 65396) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_resume_recording() != 635) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_run_pipeline() != 58716) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_set_core_config() != 59715) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_set_dictionary() != 16392) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_set_recording_event_listener() != 24437) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_set_recording_limits() != 12841) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_diy_typeless_core_checksum_func_start_recording() != 20492) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_diy_typeless_core_checksum_func_stop_recording() != 22176) {
+    if (uniffi_diy_typeless_core_checksum_func_start_recording_with_device() != 44074) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_stop_recording() != 4781) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_stop_recording_with_options() != 13789) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_diy_typeless_core_checksum_func_transcribe_audio_bytes() != 3876) {
@@ -1301,6 +4851,21 @@ private let initializationResult: InitializationResult = {
     if (uniffi_diy_typeless_core_checksum_func_transcribe_audio_bytes_cancellable() != 47248) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_diy_typeless_core_checksum_func_transcribe_audio_bytes_with_provider() != 26764) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_transcribe_audio_bytes_with_provider_cancellable() != 42962) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_transcribe_audio_detailed() != 7456) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_transcribe_audio_detailed_cancellable() != 12925) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_func_transcribe_long_audio() != 20966) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_diy_typeless_core_checksum_func_warmup_groq_connection() != 35656) {
         return InitializationResult.apiChecksumMismatch
     }
@@ -1313,10 +4878,69 @@ private let initializationResult: InitializationResult = {
     if (uniffi_diy_typeless_core_checksum_method_cancellationtoken_is_cancelled() != 51790) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_diy_typeless_core_checksum_method_recordingeventlistener_on_recording_limit_reached() != 18446) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_arm() != 60502) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_disarm() != 36371) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_elapsed() != 34055) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_is_armed() != 21467) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_is_limit_reached() != 24185) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_is_paused() != 18619) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_is_recording() != 36206) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_levels() != 8795) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_limits() != 30269) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_pause() != 12289) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_resume() != 32061) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_set_event_listener() != 58398) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_set_limits() != 64329) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_start() != 8653) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_stop() != 47133) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_recordingsession_stop_with_options() != 30893) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_diy_typeless_core_checksum_method_llmstreamlistener_on_delta() != 58715) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_diy_typeless_core_checksum_constructor_cancellationtoken_new() != 61982) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_diy_typeless_core_checksum_constructor_recordingsession_new() != 6531) {
+        return InitializationResult.apiChecksumMismatch
+    }
 
+    uniffiCallbackInitLlmStreamListener()
+    uniffiCallbackInitRecordingEventListener()
     return InitializationResult.ok
 }()
 
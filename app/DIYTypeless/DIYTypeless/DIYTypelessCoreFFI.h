@@ -242,6 +242,38 @@ typedef struct UniffiForeignFutureResultVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureResultVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_RECORDING_EVENT_LISTENER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_RECORDING_EVENT_LISTENER_METHOD0
+typedef void (*UniffiCallbackInterfaceRecordingEventListenerMethod0)(uint64_t, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_LLM_STREAM_LISTENER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_LLM_STREAM_LISTENER_METHOD0
+typedef void (*UniffiCallbackInterfaceLlmStreamListenerMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_RECORDING_EVENT_LISTENER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_RECORDING_EVENT_LISTENER
+typedef struct UniffiVTableCallbackInterfaceRecordingEventListener {
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+    UniffiCallbackInterfaceClone _Nonnull uniffiClone;
+    UniffiCallbackInterfaceRecordingEventListenerMethod0 _Nonnull onRecordingLimitReached;
+} UniffiVTableCallbackInterfaceRecordingEventListener;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_LLM_STREAM_LISTENER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_LLM_STREAM_LISTENER
+typedef struct UniffiVTableCallbackInterfaceLlmStreamListener {
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+    UniffiCallbackInterfaceClone _Nonnull uniffiClone;
+    UniffiCallbackInterfaceLlmStreamListenerMethod0 _Nonnull onDelta;
+} UniffiVTableCallbackInterfaceLlmStreamListener;
+
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CLONE_CANCELLATIONTOKEN
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CLONE_CANCELLATIONTOKEN
@@ -267,6 +299,238 @@ void uniffi_diy_typeless_core_fn_method_cancellationtoken_cancel(uint64_t ptr, R
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
 int8_t uniffi_diy_typeless_core_fn_method_cancellationtoken_is_cancelled(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CLONE_RECORDINGEVENTLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CLONE_RECORDINGEVENTLISTENER
+uint64_t uniffi_diy_typeless_core_fn_clone_recordingeventlistener(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FREE_RECORDINGEVENTLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FREE_RECORDINGEVENTLISTENER
+void uniffi_diy_typeless_core_fn_free_recordingeventlistener(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_INIT_CALLBACK_VTABLE_RECORDINGEVENTLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_INIT_CALLBACK_VTABLE_RECORDINGEVENTLISTENER
+void uniffi_diy_typeless_core_fn_init_callback_vtable_recordingeventlistener(const UniffiVTableCallbackInterfaceRecordingEventListener* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGEVENTLISTENER_ON_RECORDING_LIMIT_REACHED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGEVENTLISTENER_ON_RECORDING_LIMIT_REACHED
+void uniffi_diy_typeless_core_fn_method_recordingeventlistener_on_recording_limit_reached(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CLONE_RECORDINGSESSION
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CLONE_RECORDINGSESSION
+uint64_t uniffi_diy_typeless_core_fn_clone_recordingsession(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FREE_RECORDINGSESSION
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FREE_RECORDINGSESSION
+void uniffi_diy_typeless_core_fn_free_recordingsession(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CONSTRUCTOR_RECORDINGSESSION_NEW
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CONSTRUCTOR_RECORDINGSESSION_NEW
+uint64_t uniffi_diy_typeless_core_fn_constructor_recordingsession_new(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_ARM
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_ARM
+void uniffi_diy_typeless_core_fn_method_recordingsession_arm(uint64_t ptr, RustBuffer device_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_DISARM
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_DISARM
+void uniffi_diy_typeless_core_fn_method_recordingsession_disarm(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_ELAPSED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_ELAPSED
+RustBuffer uniffi_diy_typeless_core_fn_method_recordingsession_elapsed(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_IS_ARMED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_IS_ARMED
+int8_t uniffi_diy_typeless_core_fn_method_recordingsession_is_armed(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_IS_LIMIT_REACHED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_IS_LIMIT_REACHED
+int8_t uniffi_diy_typeless_core_fn_method_recordingsession_is_limit_reached(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_IS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_IS_PAUSED
+int8_t uniffi_diy_typeless_core_fn_method_recordingsession_is_paused(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_IS_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_IS_RECORDING
+int8_t uniffi_diy_typeless_core_fn_method_recordingsession_is_recording(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_LEVELS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_LEVELS
+RustBuffer uniffi_diy_typeless_core_fn_method_recordingsession_levels(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_LIMITS
+RustBuffer uniffi_diy_typeless_core_fn_method_recordingsession_limits(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_PAUSE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_PAUSE
+void uniffi_diy_typeless_core_fn_method_recordingsession_pause(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_RESUME
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_RESUME
+void uniffi_diy_typeless_core_fn_method_recordingsession_resume(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_SET_EVENT_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_SET_EVENT_LISTENER
+void uniffi_diy_typeless_core_fn_method_recordingsession_set_event_listener(uint64_t ptr, RustBuffer listener, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_SET_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_SET_LIMITS
+void uniffi_diy_typeless_core_fn_method_recordingsession_set_limits(uint64_t ptr, RustBuffer limits, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_START
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_START
+void uniffi_diy_typeless_core_fn_method_recordingsession_start(uint64_t ptr, RustBuffer device_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_STOP
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_STOP
+RustBuffer uniffi_diy_typeless_core_fn_method_recordingsession_stop(uint64_t ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_STOP_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_RECORDINGSESSION_STOP_WITH_OPTIONS
+RustBuffer uniffi_diy_typeless_core_fn_method_recordingsession_stop_with_options(uint64_t ptr, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CLONE_LLMSTREAMLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_CLONE_LLMSTREAMLISTENER
+uint64_t uniffi_diy_typeless_core_fn_clone_llmstreamlistener(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FREE_LLMSTREAMLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FREE_LLMSTREAMLISTENER
+void uniffi_diy_typeless_core_fn_free_llmstreamlistener(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_INIT_CALLBACK_VTABLE_LLMSTREAMLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_INIT_CALLBACK_VTABLE_LLMSTREAMLISTENER
+void uniffi_diy_typeless_core_fn_init_callback_vtable_llmstreamlistener(const UniffiVTableCallbackInterfaceLlmStreamListener* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_LLMSTREAMLISTENER_ON_DELTA
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_LLMSTREAMLISTENER_ON_DELTA
+void uniffi_diy_typeless_core_fn_method_llmstreamlistener_on_delta(uint64_t ptr, RustBuffer delta, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_COREERROR_IS_RETRYABLE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_COREERROR_IS_RETRYABLE
+int8_t uniffi_diy_typeless_core_fn_method_coreerror_is_retryable(RustBuffer ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_COREERROR_STATUS_CODE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_METHOD_COREERROR_STATUS_CODE
+RustBuffer uniffi_diy_typeless_core_fn_method_coreerror_status_code(RustBuffer ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_ADD_DICTIONARY_ENTRY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_ADD_DICTIONARY_ENTRY
+void uniffi_diy_typeless_core_fn_func_add_dictionary_entry(RustBuffer entry, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_ADD_VOCABULARY_TERM
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_ADD_VOCABULARY_TERM
+void uniffi_diy_typeless_core_fn_func_add_vocabulary_term(RustBuffer term, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_APPLY_DICTIONARY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_APPLY_DICTIONARY
+RustBuffer uniffi_diy_typeless_core_fn_func_apply_dictionary(RustBuffer text, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_ARM_MICROPHONE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_ARM_MICROPHONE
+void uniffi_diy_typeless_core_fn_func_arm_microphone(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_ARM_MICROPHONE_WITH_DEVICE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_ARM_MICROPHONE_WITH_DEVICE
+void uniffi_diy_typeless_core_fn_func_arm_microphone_with_device(RustBuffer device_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_CLEAR_RECORDING_EVENT_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_CLEAR_RECORDING_EVENT_LISTENER
+void uniffi_diy_typeless_core_fn_func_clear_recording_event_listener(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_DEFAULT_CORE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_DEFAULT_CORE_CONFIG
+RustBuffer uniffi_diy_typeless_core_fn_func_default_core_config(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_DEFAULT_RECORDING_SESSION
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_DEFAULT_RECORDING_SESSION
+uint64_t uniffi_diy_typeless_core_fn_func_default_recording_session(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_DISARM_MICROPHONE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_DISARM_MICROPHONE
+void uniffi_diy_typeless_core_fn_func_disarm_microphone(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_GET_CORE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_GET_CORE_CONFIG
+RustBuffer uniffi_diy_typeless_core_fn_func_get_core_config(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_GET_CURRENT_LEVELS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_GET_CURRENT_LEVELS
+RustBuffer uniffi_diy_typeless_core_fn_func_get_current_levels(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_GET_DICTIONARY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_GET_DICTIONARY
+RustBuffer uniffi_diy_typeless_core_fn_func_get_dictionary(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_GET_RECORDING_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_GET_RECORDING_LIMITS
+RustBuffer uniffi_diy_typeless_core_fn_func_get_recording_limits(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_LIST_INPUT_DEVICES
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_LIST_INPUT_DEVICES
+RustBuffer uniffi_diy_typeless_core_fn_func_list_input_devices(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PAUSE_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PAUSE_RECORDING
+void uniffi_diy_typeless_core_fn_func_pause_recording(RustCallStatus *_Nonnull out_status
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_POLISH_TEXT
@@ -279,6 +543,21 @@ RustBuffer uniffi_diy_typeless_core_fn_func_polish_text(RustBuffer provider, Rus
 RustBuffer uniffi_diy_typeless_core_fn_func_polish_text_cancellable(RustBuffer provider, RustBuffer api_key, RustBuffer raw_text, RustBuffer context, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_POLISH_TEXT_STREAMING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_POLISH_TEXT_STREAMING
+RustBuffer uniffi_diy_typeless_core_fn_func_polish_text_streaming(RustBuffer provider, RustBuffer api_key, RustBuffer raw_text, RustBuffer context, uint64_t listener, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PREPARE_AUDIO_FILE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PREPARE_AUDIO_FILE
+RustBuffer uniffi_diy_typeless_core_fn_func_prepare_audio_file(RustBuffer audio_bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PREPARE_AUDIO_FILE_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PREPARE_AUDIO_FILE_WITH_OPTIONS
+RustBuffer uniffi_diy_typeless_core_fn_func_prepare_audio_file_with_options(RustBuffer audio_bytes, RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PROCESS_TEXT_WITH_LLM
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PROCESS_TEXT_WITH_LLM
 RustBuffer uniffi_diy_typeless_core_fn_func_process_text_with_llm(RustBuffer provider, RustBuffer api_key, RustBuffer prompt, RustBuffer system_instruction, RustBuffer temperature, RustCallStatus *_Nonnull out_status
@@ -289,16 +568,72 @@ RustBuffer uniffi_diy_typeless_core_fn_func_process_text_with_llm(RustBuffer pro
 RustBuffer uniffi_diy_typeless_core_fn_func_process_text_with_llm_cancellable(RustBuffer provider, RustBuffer api_key, RustBuffer prompt, RustBuffer system_instruction, RustBuffer temperature, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PROCESS_TEXT_WITH_LLM_STREAMING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_PROCESS_TEXT_WITH_LLM_STREAMING
+RustBuffer uniffi_diy_typeless_core_fn_func_process_text_with_llm_streaming(RustBuffer provider, RustBuffer api_key, RustBuffer prompt, RustBuffer system_instruction, RustBuffer temperature, uint64_t listener, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_REMOVE_DICTIONARY_ENTRY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_REMOVE_DICTIONARY_ENTRY
+int8_t uniffi_diy_typeless_core_fn_func_remove_dictionary_entry(RustBuffer pattern, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_REMOVE_VOCABULARY_TERM
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_REMOVE_VOCABULARY_TERM
+int8_t uniffi_diy_typeless_core_fn_func_remove_vocabulary_term(RustBuffer term, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_RESUME_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_RESUME_RECORDING
+void uniffi_diy_typeless_core_fn_func_resume_recording(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_RUN_PIPELINE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_RUN_PIPELINE
+RustBuffer uniffi_diy_typeless_core_fn_func_run_pipeline(RustBuffer audio_bytes, RustBuffer options, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_SET_CORE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_SET_CORE_CONFIG
+void uniffi_diy_typeless_core_fn_func_set_core_config(RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_SET_DICTIONARY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_SET_DICTIONARY
+void uniffi_diy_typeless_core_fn_func_set_dictionary(RustBuffer dictionary, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_SET_RECORDING_EVENT_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_SET_RECORDING_EVENT_LISTENER
+void uniffi_diy_typeless_core_fn_func_set_recording_event_listener(uint64_t listener, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_SET_RECORDING_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_SET_RECORDING_LIMITS
+void uniffi_diy_typeless_core_fn_func_set_recording_limits(RustBuffer limits, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_START_RECORDING
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_START_RECORDING
 void uniffi_diy_typeless_core_fn_func_start_recording(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_START_RECORDING_WITH_DEVICE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_START_RECORDING_WITH_DEVICE
+void uniffi_diy_typeless_core_fn_func_start_recording_with_device(RustBuffer device_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_STOP_RECORDING
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_STOP_RECORDING
 RustBuffer uniffi_diy_typeless_core_fn_func_stop_recording(RustCallStatus *_Nonnull out_status
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_STOP_RECORDING_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_STOP_RECORDING_WITH_OPTIONS
+RustBuffer uniffi_diy_typeless_core_fn_func_stop_recording_with_options(RustBuffer options, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_BYTES
@@ -311,6 +646,31 @@ RustBuffer uniffi_diy_typeless_core_fn_func_transcribe_audio_bytes(RustBuffer ap
 RustBuffer uniffi_diy_typeless_core_fn_func_transcribe_audio_bytes_cancellable(RustBuffer api_key, RustBuffer audio_bytes, RustBuffer language, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_BYTES_WITH_PROVIDER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_BYTES_WITH_PROVIDER
+RustBuffer uniffi_diy_typeless_core_fn_func_transcribe_audio_bytes_with_provider(RustBuffer provider, RustBuffer api_key, RustBuffer audio_bytes, RustBuffer language, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_BYTES_WITH_PROVIDER_CANCELLABLE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_BYTES_WITH_PROVIDER_CANCELLABLE
+RustBuffer uniffi_diy_typeless_core_fn_func_transcribe_audio_bytes_with_provider_cancellable(RustBuffer provider, RustBuffer api_key, RustBuffer audio_bytes, RustBuffer language, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_DETAILED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_DETAILED
+RustBuffer uniffi_diy_typeless_core_fn_func_transcribe_audio_detailed(RustBuffer provider, RustBuffer api_key, RustBuffer audio_bytes, RustBuffer language, int8_t word_timestamps, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_DETAILED_CANCELLABLE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_AUDIO_DETAILED_CANCELLABLE
+RustBuffer uniffi_diy_typeless_core_fn_func_transcribe_audio_detailed_cancellable(RustBuffer provider, RustBuffer api_key, RustBuffer audio_bytes, RustBuffer language, int8_t word_timestamps, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_LONG_AUDIO
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_TRANSCRIBE_LONG_AUDIO
+RustBuffer uniffi_diy_typeless_core_fn_func_transcribe_long_audio(RustBuffer provider, RustBuffer api_key, RustBuffer audio_bytes, RustBuffer language, RustBuffer options, uint64_t cancellation_token, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_WARMUP_GROQ_CONNECTION
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_FN_FUNC_WARMUP_GROQ_CONNECTION
 void uniffi_diy_typeless_core_fn_func_warmup_groq_connection(RustCallStatus *_Nonnull out_status
@@ -580,6 +940,96 @@ void ffi_diy_typeless_core_rust_future_free_void(uint64_t handle
 #ifndef UNIFFI_FFIDEF_FFI_DIY_TYPELESS_CORE_RUST_FUTURE_COMPLETE_VOID
 #define UNIFFI_FFIDEF_FFI_DIY_TYPELESS_CORE_RUST_FUTURE_COMPLETE_VOID
 void ffi_diy_typeless_core_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_ADD_DICTIONARY_ENTRY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_ADD_DICTIONARY_ENTRY
+uint16_t uniffi_diy_typeless_core_checksum_func_add_dictionary_entry(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_ADD_VOCABULARY_TERM
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_ADD_VOCABULARY_TERM
+uint16_t uniffi_diy_typeless_core_checksum_func_add_vocabulary_term(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_APPLY_DICTIONARY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_APPLY_DICTIONARY
+uint16_t uniffi_diy_typeless_core_checksum_func_apply_dictionary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_ARM_MICROPHONE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_ARM_MICROPHONE
+uint16_t uniffi_diy_typeless_core_checksum_func_arm_microphone(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_ARM_MICROPHONE_WITH_DEVICE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_ARM_MICROPHONE_WITH_DEVICE
+uint16_t uniffi_diy_typeless_core_checksum_func_arm_microphone_with_device(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_CLEAR_RECORDING_EVENT_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_CLEAR_RECORDING_EVENT_LISTENER
+uint16_t uniffi_diy_typeless_core_checksum_func_clear_recording_event_listener(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_DEFAULT_CORE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_DEFAULT_CORE_CONFIG
+uint16_t uniffi_diy_typeless_core_checksum_func_default_core_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_DEFAULT_RECORDING_SESSION
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_DEFAULT_RECORDING_SESSION
+uint16_t uniffi_diy_typeless_core_checksum_func_default_recording_session(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_DISARM_MICROPHONE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_DISARM_MICROPHONE
+uint16_t uniffi_diy_typeless_core_checksum_func_disarm_microphone(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_GET_CORE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_GET_CORE_CONFIG
+uint16_t uniffi_diy_typeless_core_checksum_func_get_core_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_GET_CURRENT_LEVELS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_GET_CURRENT_LEVELS
+uint16_t uniffi_diy_typeless_core_checksum_func_get_current_levels(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_GET_DICTIONARY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_GET_DICTIONARY
+uint16_t uniffi_diy_typeless_core_checksum_func_get_dictionary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_GET_RECORDING_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_GET_RECORDING_LIMITS
+uint16_t uniffi_diy_typeless_core_checksum_func_get_recording_limits(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_LIST_INPUT_DEVICES
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_LIST_INPUT_DEVICES
+uint16_t uniffi_diy_typeless_core_checksum_func_list_input_devices(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PAUSE_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PAUSE_RECORDING
+uint16_t uniffi_diy_typeless_core_checksum_func_pause_recording(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_POLISH_TEXT
@@ -592,6 +1042,24 @@ uint16_t uniffi_diy_typeless_core_checksum_func_polish_text(void
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_POLISH_TEXT_CANCELLABLE
 uint16_t uniffi_diy_typeless_core_checksum_func_polish_text_cancellable(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_POLISH_TEXT_STREAMING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_POLISH_TEXT_STREAMING
+uint16_t uniffi_diy_typeless_core_checksum_func_polish_text_streaming(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PREPARE_AUDIO_FILE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PREPARE_AUDIO_FILE
+uint16_t uniffi_diy_typeless_core_checksum_func_prepare_audio_file(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PREPARE_AUDIO_FILE_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PREPARE_AUDIO_FILE_WITH_OPTIONS
+uint16_t uniffi_diy_typeless_core_checksum_func_prepare_audio_file_with_options(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PROCESS_TEXT_WITH_LLM
@@ -604,18 +1072,84 @@ uint16_t uniffi_diy_typeless_core_checksum_func_process_text_with_llm(void
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PROCESS_TEXT_WITH_LLM_CANCELLABLE
 uint16_t uniffi_diy_typeless_core_checksum_func_process_text_with_llm_cancellable(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PROCESS_TEXT_WITH_LLM_STREAMING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_PROCESS_TEXT_WITH_LLM_STREAMING
+uint16_t uniffi_diy_typeless_core_checksum_func_process_text_with_llm_streaming(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_REMOVE_DICTIONARY_ENTRY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_REMOVE_DICTIONARY_ENTRY
+uint16_t uniffi_diy_typeless_core_checksum_func_remove_dictionary_entry(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_REMOVE_VOCABULARY_TERM
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_REMOVE_VOCABULARY_TERM
+uint16_t uniffi_diy_typeless_core_checksum_func_remove_vocabulary_term(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_RESUME_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_RESUME_RECORDING
+uint16_t uniffi_diy_typeless_core_checksum_func_resume_recording(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_RUN_PIPELINE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_RUN_PIPELINE
+uint16_t uniffi_diy_typeless_core_checksum_func_run_pipeline(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_SET_CORE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_SET_CORE_CONFIG
+uint16_t uniffi_diy_typeless_core_checksum_func_set_core_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_SET_DICTIONARY
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_SET_DICTIONARY
+uint16_t uniffi_diy_typeless_core_checksum_func_set_dictionary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_SET_RECORDING_EVENT_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_SET_RECORDING_EVENT_LISTENER
+uint16_t uniffi_diy_typeless_core_checksum_func_set_recording_event_listener(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_SET_RECORDING_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_SET_RECORDING_LIMITS
+uint16_t uniffi_diy_typeless_core_checksum_func_set_recording_limits(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_START_RECORDING
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_START_RECORDING
 uint16_t uniffi_diy_typeless_core_checksum_func_start_recording(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_START_RECORDING_WITH_DEVICE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_START_RECORDING_WITH_DEVICE
+uint16_t uniffi_diy_typeless_core_checksum_func_start_recording_with_device(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_STOP_RECORDING
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_STOP_RECORDING
 uint16_t uniffi_diy_typeless_core_checksum_func_stop_recording(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_STOP_RECORDING_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_STOP_RECORDING_WITH_OPTIONS
+uint16_t uniffi_diy_typeless_core_checksum_func_stop_recording_with_options(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_BYTES
@@ -628,6 +1162,36 @@ uint16_t uniffi_diy_typeless_core_checksum_func_transcribe_audio_bytes(void
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_BYTES_CANCELLABLE
 uint16_t uniffi_diy_typeless_core_checksum_func_transcribe_audio_bytes_cancellable(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_BYTES_WITH_PROVIDER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_BYTES_WITH_PROVIDER
+uint16_t uniffi_diy_typeless_core_checksum_func_transcribe_audio_bytes_with_provider(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_BYTES_WITH_PROVIDER_CANCELLABLE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_BYTES_WITH_PROVIDER_CANCELLABLE
+uint16_t uniffi_diy_typeless_core_checksum_func_transcribe_audio_bytes_with_provider_cancellable(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_DETAILED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_DETAILED
+uint16_t uniffi_diy_typeless_core_checksum_func_transcribe_audio_detailed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_DETAILED_CANCELLABLE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_AUDIO_DETAILED_CANCELLABLE
+uint16_t uniffi_diy_typeless_core_checksum_func_transcribe_audio_detailed_cancellable(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_LONG_AUDIO
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_TRANSCRIBE_LONG_AUDIO
+uint16_t uniffi_diy_typeless_core_checksum_func_transcribe_long_audio(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_FUNC_WARMUP_GROQ_CONNECTION
@@ -652,12 +1216,126 @@ uint16_t uniffi_diy_typeless_core_checksum_method_cancellationtoken_cancel(void
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_CANCELLATIONTOKEN_IS_CANCELLED
 uint16_t uniffi_diy_typeless_core_checksum_method_cancellationtoken_is_cancelled(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGEVENTLISTENER_ON_RECORDING_LIMIT_REACHED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGEVENTLISTENER_ON_RECORDING_LIMIT_REACHED
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingeventlistener_on_recording_limit_reached(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_ARM
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_ARM
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_arm(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_DISARM
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_DISARM
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_disarm(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_ELAPSED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_ELAPSED
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_elapsed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_IS_ARMED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_IS_ARMED
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_is_armed(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_IS_LIMIT_REACHED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_IS_LIMIT_REACHED
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_is_limit_reached(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_IS_PAUSED
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_IS_PAUSED
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_is_paused(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_IS_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_IS_RECORDING
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_is_recording(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_LEVELS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_LEVELS
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_levels(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_LIMITS
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_limits(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_PAUSE
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_PAUSE
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_pause(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_RESUME
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_RESUME
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_resume(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_SET_EVENT_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_SET_EVENT_LISTENER
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_set_event_listener(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_SET_LIMITS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_SET_LIMITS
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_set_limits(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_START
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_START
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_start(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_STOP
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_STOP
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_stop(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_STOP_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_RECORDINGSESSION_STOP_WITH_OPTIONS
+uint16_t uniffi_diy_typeless_core_checksum_method_recordingsession_stop_with_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_LLMSTREAMLISTENER_ON_DELTA
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_METHOD_LLMSTREAMLISTENER_ON_DELTA
+uint16_t uniffi_diy_typeless_core_checksum_method_llmstreamlistener_on_delta(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
 #define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_CONSTRUCTOR_CANCELLATIONTOKEN_NEW
 uint16_t uniffi_diy_typeless_core_checksum_constructor_cancellationtoken_new(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_CONSTRUCTOR_RECORDINGSESSION_NEW
+#define UNIFFI_FFIDEF_UNIFFI_DIY_TYPELESS_CORE_CHECKSUM_CONSTRUCTOR_RECORDINGSESSION_NEW
+uint16_t uniffi_diy_typeless_core_checksum_constructor_recordingsession_new(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_DIY_TYPELESS_CORE_UNIFFI_CONTRACT_VERSION
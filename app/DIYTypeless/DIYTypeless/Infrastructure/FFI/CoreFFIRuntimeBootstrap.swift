@@ -15,11 +15,15 @@ func configureCoreFFIRuntimeIfNeeded() {
                 try DIYTypeless.startRecording()
             },
             stopRecording: {
-                let ffiAudioData = try stopRecording()
-                return DomainAudioData(
-                    bytes: ffiAudioData.bytes,
-                    durationSeconds: ffiAudioData.durationSeconds
-                )
+                do {
+                    let ffiAudioData = try stopRecording()
+                    return DomainAudioData(
+                        bytes: ffiAudioData.bytes,
+                        durationSeconds: ffiAudioData.durationSeconds
+                    )
+                } catch let error as CoreError {
+                    throw mapCoreError(error)
+                }
             },
             warmupGroqConnection: {
                 try warmupGroqConnection()
@@ -121,18 +125,38 @@ private func mapCoreError(_ error: CoreError) -> DIYTypelessCore.CoreError {
         return .Cancelled
     case .AudioDeviceUnavailable:
         return .AudioDeviceUnavailable
+    case .AudioDeviceNotFound(let deviceId):
+        return .AudioDeviceNotFound(deviceId)
     case .RecordingAlreadyActive:
         return .RecordingAlreadyActive
     case .RecordingNotActive:
         return .RecordingNotActive
+    case .RecordingAlreadyPaused:
+        return .RecordingAlreadyPaused
+    case .RecordingNotPaused:
+        return .RecordingNotPaused
     case .AudioCapture(let message):
         return .AudioCapture(message)
+    case .NoSpeechDetected:
+        return .NoSpeechDetected
     case .AudioProcessing(let message):
         return .AudioProcessing(message)
     case .Http(let message):
         return .Http(message)
     case .Api(let message):
         return .Api(message)
+    case .Unauthorized(let provider, let status, let body):
+        return .Unauthorized(provider: provider, status: status, body: body)
+    case .RateLimited(let provider, let retryAfterSecs, let body):
+        return .RateLimited(provider: provider, retryAfterSecs: retryAfterSecs, body: body)
+    case .QuotaExceeded(let provider, let status, let body):
+        return .QuotaExceeded(provider: provider, status: status, body: body)
+    case .ContentBlocked(let provider, let reason):
+        return .ContentBlocked(provider: provider, reason: reason)
+    case .Timeout(let provider, let status, let body):
+        return .Timeout(provider: provider, status: status, body: body)
+    case .ApiStatus(let provider, let status, let body):
+        return .ApiStatus(provider: provider, status: status, body: body)
     case .Serialization(let message):
         return .Serialization(message)
     case .EmptyResponse:
@@ -0,0 +1,39 @@
+import Foundation
+
+extension CoreErrorMapper {
+    /// Maps a core failure to a user-facing error using the provider, status and
+    /// retry hint the core reports. Callers handle `Cancelled` before mapping.
+    public static func toUserFacingError(_ coreError: CoreError) -> UserFacingError {
+        let description = coreError.localizedDescription
+        switch coreError {
+        case .Api(let message):
+            return toUserFacingError(category: .api, message: message)
+        case .Http(let message):
+            return toUserFacingError(category: .network, message: message)
+        case .Unauthorized(let provider, _, _):
+            return toUserFacingError(category: .unauthorized(provider: provider), message: description)
+        case .RateLimited(_, let retryAfterSecs, _):
+            return toUserFacingError(
+                category: .rateLimited(retryAfterSeconds: retryAfterSecs),
+                message: description
+            )
+        case .QuotaExceeded(let provider, _, _):
+            return toUserFacingError(category: .quotaExceeded(provider: provider), message: description)
+        case .ContentBlocked(let provider, _):
+            return toUserFacingError(category: .contentBlocked(provider: provider), message: description)
+        case .Timeout:
+            return toUserFacingError(category: .timeout, message: description)
+        case .ApiStatus(let provider, _, let body) where isRegionBlocked(provider: provider, body: body):
+            return toUserFacingError(category: .regionBlocked, message: description)
+        case .ApiStatus(_, let status, _):
+            return toUserFacingError(category: .httpStatus(status), message: description)
+        default:
+            return toUserFacingError(category: .unknown, message: description)
+        }
+    }
+
+    /// Gemini rejects requests from unsupported countries with a location error.
+    private static func isRegionBlocked(provider: String, body: String) -> Bool {
+        provider == "Gemini" && body.localizedCaseInsensitiveContains("location is not supported")
+    }
+}
@@ -55,18 +55,7 @@ public final class PolishTextUseCaseImpl: PolishTextUseCaseProtocol {
                             return
                         }
 
-                        let userError: UserFacingError
-                        switch coreError {
-                        case .Api(let message):
-                            userError = CoreErrorMapper.toUserFacingError(category: .api, message: message)
-                        case .Http(let message):
-                            userError = CoreErrorMapper.toUserFacingError(category: .network, message: message)
-                        default:
-                            userError = CoreErrorMapper.toUserFacingError(
-                                category: .unknown,
-                                message: coreError.localizedDescription
-                            )
-                        }
+                        let userError = CoreErrorMapper.toUserFacingError(coreError)
                         continuation.resume(throwing: PolishingError.apiError(userError))
                     } catch {
                         let userError = UserFacingError.unknown(error.localizedDescription)
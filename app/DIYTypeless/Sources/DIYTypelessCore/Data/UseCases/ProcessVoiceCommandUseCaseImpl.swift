@@ -43,19 +43,10 @@ public final class ProcessVoiceCommandUseCaseImpl: ProcessVoiceCommandUseCasePro
         } catch is CancellationError {
             throw CancellationError()
         } catch let coreError as CoreError {
-            switch coreError {
-            case .Cancelled:
+            if case .Cancelled = coreError {
                 throw CancellationError()
-            case .Api(let message):
-                throw CoreErrorMapper.toUserFacingError(category: .api, message: message)
-            case .Http(let message):
-                throw CoreErrorMapper.toUserFacingError(category: .network, message: message)
-            default:
-                throw CoreErrorMapper.toUserFacingError(
-                    category: .unknown,
-                    message: coreError.localizedDescription
-                )
             }
+            throw CoreErrorMapper.toUserFacingError(coreError)
         } catch {
             throw UserFacingError.unknown(error.localizedDescription)
         }
@@ -9,6 +9,8 @@ public final class StopRecordingUseCaseImpl: StopRecordingUseCaseProtocol {
                 do {
                     let audioData = try CoreFFIRuntime.stopRecording()
                     continuation.resume(returning: audioData)
+                } catch CoreError.NoSpeechDetected {
+                    continuation.resume(throwing: RecordingError.noSpeechDetected)
                 } catch {
                     continuation.resume(throwing: RecordingError.stopFailed(error.localizedDescription))
                 }
@@ -53,18 +53,7 @@ public final class TranscribeAudioUseCaseImpl: TranscribeAudioUseCaseProtocol {
                             return
                         }
 
-                        let userError: UserFacingError
-                        switch coreError {
-                        case .Api(let message):
-                            userError = CoreErrorMapper.toUserFacingError(category: .api, message: message)
-                        case .Http(let message):
-                            userError = CoreErrorMapper.toUserFacingError(category: .network, message: message)
-                        default:
-                            userError = CoreErrorMapper.toUserFacingError(
-                                category: .unknown,
-                                message: coreError.localizedDescription
-                            )
-                        }
+                        let userError = CoreErrorMapper.toUserFacingError(coreError)
                         continuation.resume(throwing: TranscriptionError.apiError(userError))
                     } catch {
                         let userError = UserFacingError.unknown(error.localizedDescription)
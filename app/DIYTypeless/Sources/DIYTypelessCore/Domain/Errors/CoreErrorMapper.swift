@@ -4,6 +4,16 @@ import Foundation
 public enum TechnicalErrorCategory: Sendable {
     case api
     case network
+    /// Provider rejected the API key.
+    case unauthorized(provider: String)
+    case rateLimited(retryAfterSeconds: UInt64?)
+    case quotaExceeded(provider: String)
+    case contentBlocked(provider: String)
+    case timeout
+    /// Provider does not serve the user's region.
+    case regionBlocked
+    /// Provider answered with another non-success HTTP status.
+    case httpStatus(UInt16)
     case unknown
 }
 
@@ -15,13 +25,13 @@ public enum CoreErrorMapper {
         case .api:
             let lowercased = message.lowercased()
             if lowercased.contains("401") {
-                return .invalidAPIKey
+                return .invalidAPIKey(provider: nil)
             }
             if lowercased.contains("400") || lowercased.contains("403") {
                 return .regionBlocked
             }
             if lowercased.contains("429") {
-                return .rateLimited
+                return .rateLimited(retryAfterSeconds: nil)
             }
             if lowercased.contains("500") ||
                 lowercased.contains("502") ||
@@ -32,6 +42,25 @@ public enum CoreErrorMapper {
             return .unknown(message)
         case .network:
             return .networkError
+        case .unauthorized(let provider):
+            return .invalidAPIKey(provider: provider)
+        case .rateLimited(let retryAfterSeconds):
+            return .rateLimited(retryAfterSeconds: retryAfterSeconds)
+        case .quotaExceeded(let provider):
+            return .quotaExceeded(provider: provider)
+        case .contentBlocked(let provider):
+            return .contentBlocked(provider: provider)
+        case .timeout:
+            return .timeout
+        case .regionBlocked:
+            return .regionBlocked
+        case .httpStatus(let status):
+            switch status {
+            case 500...599:
+                return .serviceUnavailable
+            default:
+                return .unknown(message)
+            }
         case .unknown:
             return .unknown(message)
         }
@@ -4,9 +4,13 @@ import Foundation
 /// These errors are mapped from technical failures and provide
 /// user-friendly messages suitable for display in the capsule UI.
 public enum UserFacingError: Error, Equatable {
-    case invalidAPIKey
+    /// `provider` is the display name the core reports, when known.
+    case invalidAPIKey(provider: String?)
     case regionBlocked
-    case rateLimited
+    case rateLimited(retryAfterSeconds: UInt64?)
+    case quotaExceeded(provider: String)
+    case contentBlocked(provider: String)
+    case timeout
     case serviceUnavailable
     case networkError
     case unknown(String)
@@ -17,12 +21,24 @@ extension UserFacingError {
     /// Messages are kept concise to fit in the capsule UI (~160px width).
     public var message: String {
         switch self {
-        case .invalidAPIKey:
+        case .invalidAPIKey(let provider):
+            if let provider {
+                return "Invalid \(provider) API key"
+            }
             return "Invalid API key"
         case .regionBlocked:
             return "Service unavailable in your region"
-        case .rateLimited:
+        case .rateLimited(let retryAfterSeconds):
+            if let retryAfterSeconds {
+                return "Rate limited, retry in \(retryAfterSeconds)s"
+            }
             return "Rate limited, try again soon"
+        case .quotaExceeded(let provider):
+            return "\(provider) quota exceeded"
+        case .contentBlocked(let provider):
+            return "Blocked by \(provider)"
+        case .timeout:
+            return "Request timed out"
         case .serviceUnavailable:
             return "Service temporarily unavailable"
         case .networkError:
@@ -39,9 +55,9 @@ extension UserFacingError {
     /// The severity level of the error, used to determine display color.
     public var severity: ErrorSeverity {
         switch self {
-        case .invalidAPIKey, .rateLimited:
+        case .invalidAPIKey, .rateLimited, .quotaExceeded, .contentBlocked:
             return .warning
-        case .regionBlocked, .serviceUnavailable, .networkError, .unknown:
+        case .regionBlocked, .timeout, .serviceUnavailable, .networkError, .unknown:
             return .critical
         }
     }
@@ -4,12 +4,15 @@ import Foundation
 public protocol StopRecordingUseCaseProtocol: Sendable {
     /// Stops the current recording and returns the audio data (FLAC format)
     /// - Returns: Audio data
-    /// - Throws: RecordingError if no recording is in progress or stop fails
+    /// - Throws: RecordingError if no recording is in progress, the recording holds
+    ///   no speech, or stop fails
     func execute() async throws -> DomainAudioData
 }
 
 public enum RecordingError: Error {
     case notRecording
+    /// The recording was silent or too short; there is nothing to transcribe.
+    case noSpeechDetected
     case stopFailed(String)
     case invalidAudioData
 }
@@ -43,12 +43,22 @@ open class CancellationToken: CancellationTokenProtocol, @unchecked Sendable {
 public enum CoreError: Error, Equatable, Hashable, LocalizedError, Sendable {
     case Cancelled
     case AudioDeviceUnavailable
+    case AudioDeviceNotFound(String)
     case RecordingAlreadyActive
     case RecordingNotActive
+    case RecordingAlreadyPaused
+    case RecordingNotPaused
     case AudioCapture(String)
+    case NoSpeechDetected
     case AudioProcessing(String)
     case Http(String)
     case Api(String)
+    case Unauthorized(provider: String, status: UInt16, body: String)
+    case RateLimited(provider: String, retryAfterSecs: UInt64?, body: String)
+    case QuotaExceeded(provider: String, status: UInt16, body: String)
+    case ContentBlocked(provider: String, reason: String)
+    case Timeout(provider: String, status: UInt16?, body: String)
+    case ApiStatus(provider: String, status: UInt16, body: String)
     case Serialization(String)
     case EmptyResponse
     case Transcription(String)
@@ -108,6 +108,10 @@ public final class RecordingPipelineCoordinator: RecordingPipelineCoordinating {
         if error is CancellationError {
             return nil
         }
+        // An accidental tap or a silent recording is dropped quietly.
+        if case RecordingError.noSpeechDetected = error {
+            return nil
+        }
         if let error = error as? TranscriptionError {
             switch error {
             case .apiError(let userError):
@@ -193,7 +193,7 @@ public final class RecordingState {
 
         let status = permissionRepository.currentStatus
         guard status.allGranted else {
-            showError(.invalidAPIKey)
+            showError(.invalidAPIKey(provider: nil))
             onRequireOnboarding?()
             return
         }
@@ -203,13 +203,13 @@ public final class RecordingState {
         refreshKeys()
 
         if groqKey.isEmpty {
-            showError(.invalidAPIKey)
+            showError(.invalidAPIKey(provider: nil))
             onRequireOnboarding?()
             return
         }
 
         if llmApiKey.isEmpty {
-            showError(.invalidAPIKey)
+            showError(.invalidAPIKey(provider: nil))
             onRequireOnboarding?()
             return
         }
@@ -10,11 +10,11 @@ final class CapsuleStateAutoHideControllerTests: XCTestCase {
     func testSchedule_whenStaleWorkFires_doesNotHideLatestState() {
         let scheduler = ManualAutoHideScheduler()
         let sut = CapsuleStateAutoHideController(scheduleWork: scheduler.schedule)
-        var state: CapsuleState = .error(.invalidAPIKey)
+        var state: CapsuleState = .error(.invalidAPIKey(provider: nil))
 
         sut.schedule(
             after: 2.0,
-            expectedState: .error(.invalidAPIKey),
+            expectedState: .error(.invalidAPIKey(provider: nil)),
             currentState: { state },
             onHide: { state = .hidden }
         )
@@ -14,7 +14,7 @@ final class CapsuleStateTransitionGuardTests: XCTestCase {
         XCTAssertTrue(sut.canTransition(from: .transcribing(progress: 0), to: .polishing(progress: 0)))
         XCTAssertTrue(sut.canTransition(from: .polishing(progress: 0), to: .done(.copied)))
         XCTAssertTrue(sut.canTransition(from: .done(.copied), to: .hidden))
-        XCTAssertTrue(sut.canTransition(from: .error(.invalidAPIKey), to: .recording))
+        XCTAssertTrue(sut.canTransition(from: .error(.invalidAPIKey(provider: nil)), to: .recording))
     }
 
     func testCanTransition_invalidJumps_returnsFalse() {
@@ -29,7 +29,7 @@ final class CapsuleStateTransitionGuardTests: XCTestCase {
     func testCanTransition_samePhaseTransition_returnsTrue() {
         let sut = CapsuleStateTransitionGuard()
 
-        XCTAssertTrue(sut.canTransition(from: .error(.invalidAPIKey), to: .error(.networkError)))
+        XCTAssertTrue(sut.canTransition(from: .error(.invalidAPIKey(provider: nil)), to: .error(.networkError)))
         XCTAssertTrue(sut.canTransition(from: .hidden, to: .hidden))
     }
 }
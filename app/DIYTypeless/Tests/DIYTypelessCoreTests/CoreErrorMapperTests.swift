@@ -9,7 +9,7 @@ final class CoreErrorMapperTests: XCTestCase {
     func testApiCategory_mapsCommonStatusCodesToExpectedUserErrors() {
         XCTAssertEqual(
             CoreErrorMapper.toUserFacingError(category: .api, message: "401 unauthorized"),
-            .invalidAPIKey
+            .invalidAPIKey(provider: nil)
         )
         XCTAssertEqual(
             CoreErrorMapper.toUserFacingError(category: .api, message: "403 forbidden"),
@@ -17,7 +17,7 @@ final class CoreErrorMapperTests: XCTestCase {
         )
         XCTAssertEqual(
             CoreErrorMapper.toUserFacingError(category: .api, message: "429 too many requests"),
-            .rateLimited
+            .rateLimited(retryAfterSeconds: nil)
         )
         XCTAssertEqual(
             CoreErrorMapper.toUserFacingError(category: .api, message: "503 service unavailable"),
@@ -33,6 +33,80 @@ final class CoreErrorMapperTests: XCTestCase {
         )
     }
 
+    func testCoreError_structuredProviderFailures_mapFromStatusAndFields() {
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.Unauthorized(provider: "Groq", status: 401, body: "")
+            ),
+            .invalidAPIKey(provider: "Groq")
+        )
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.Unauthorized(provider: "OpenAI", status: 403, body: "")
+            ),
+            .invalidAPIKey(provider: "OpenAI")
+        )
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.Unauthorized(provider: "Gemini", status: 400, body: "API_KEY_INVALID")
+            ).message,
+            "Invalid Gemini API key"
+        )
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.RateLimited(provider: "Groq", retryAfterSecs: 12, body: "")
+            ),
+            .rateLimited(retryAfterSeconds: 12)
+        )
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.QuotaExceeded(provider: "OpenAI", status: 429, body: "")
+            ),
+            .quotaExceeded(provider: "OpenAI")
+        )
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.ContentBlocked(provider: "Gemini", reason: "SAFETY")
+            ),
+            .contentBlocked(provider: "Gemini")
+        )
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.Timeout(provider: "Groq", status: 504, body: "")
+            ),
+            .timeout
+        )
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.ApiStatus(provider: "Groq", status: 503, body: "down")
+            ),
+            .serviceUnavailable
+        )
+    }
+
+    func testCoreError_regionBlocked_onlyForGeminiLocationError() {
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(
+                CoreError.ApiStatus(
+                    provider: "Gemini",
+                    status: 400,
+                    body: "User location is not supported for the API use."
+                )
+            ),
+            .regionBlocked
+        )
+        let badRequest = CoreError.ApiStatus(provider: "Groq", status: 400, body: "invalid model")
+        XCTAssertEqual(
+            CoreErrorMapper.toUserFacingError(badRequest),
+            .unknown(badRequest.localizedDescription)
+        )
+    }
+
+    func testRateLimitedMessage_includesRetryHintWhenKnown() {
+        XCTAssertEqual(UserFacingError.rateLimited(retryAfterSeconds: 12).message, "Rate limited, retry in 12s")
+        XCTAssertEqual(UserFacingError.rateLimited(retryAfterSeconds: nil).message, "Rate limited, try again soon")
+    }
+
     func testNetworkCategory_alwaysMapsToNetworkError() {
         XCTAssertEqual(
             CoreErrorMapper.toUserFacingError(category: .network, message: "connection lost"),
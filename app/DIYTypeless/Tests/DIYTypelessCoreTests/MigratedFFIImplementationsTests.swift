@@ -254,6 +254,28 @@ final class StopRecordingUseCaseImplTests: XCTestCase {
             }
         }
     }
+
+    func testExecute_whenNoSpeechDetected_throwsNoSpeechDetected() async {
+        await CoreFFIRuntimeTestLock.shared.run {
+            let recorder = RuntimeRecorder()
+            configureRuntime(
+                recorder: recorder,
+                behavior: RuntimeBehavior(stopRecordingError: CoreError.NoSpeechDetected)
+            )
+            let sut = StopRecordingUseCaseImpl()
+
+            do {
+                _ = try await sut.execute()
+                XCTFail("Expected RecordingError.noSpeechDetected")
+            } catch let error as RecordingError {
+                guard case .noSpeechDetected = error else {
+                    return XCTFail("Expected noSpeechDetected, got \(error)")
+                }
+            } catch {
+                XCTFail("Expected RecordingError, got \(error)")
+            }
+        }
+    }
 }
 
 final class TranscribeAudioUseCaseImplTests: XCTestCase {
@@ -302,7 +324,7 @@ final class TranscribeAudioUseCaseImplTests: XCTestCase {
                 )
                 XCTFail("Expected TranscriptionError.apiError(.invalidAPIKey)")
             } catch let error as TranscriptionError {
-                XCTAssertEqual(error, .apiError(.invalidAPIKey))
+                XCTAssertEqual(error, .apiError(.invalidAPIKey(provider: nil)))
             } catch {
                 XCTFail("Expected TranscriptionError, got \(error)")
             }
@@ -67,7 +67,7 @@ final class ProcessVoiceCommandUseCaseImplTests: XCTestCase {
             )
             XCTFail("Expected UserFacingError.invalidAPIKey")
         } catch let error as UserFacingError {
-            XCTAssertEqual(error, .invalidAPIKey)
+            XCTAssertEqual(error, .invalidAPIKey(provider: nil))
         } catch {
             XCTFail("Expected UserFacingError, got \(error)")
         }
@@ -181,6 +181,18 @@ final class RecordingPipelineCoordinatorTests: XCTestCase {
         XCTAssertNil(mapped)
     }
 
+    func testMapToUserFacingError_noSpeechDetected_mapsToNil() {
+        let sut = RecordingPipelineCoordinator(
+            stopRecordingUseCase: MockStopRecordingUseCase(),
+            transcribeAudioUseCase: MockTranscribeAudioUseCase(),
+            polishTextUseCase: MockPolishTextUseCase(),
+            processVoiceCommandUseCase: MockProcessVoiceCommandUseCase()
+        )
+
+        let mapped = sut.mapToUserFacingError(RecordingError.noSpeechDetected)
+        XCTAssertNil(mapped)
+    }
+
     func testMapToUserFacingError_userFacingError_passthrough() {
         let sut = RecordingPipelineCoordinator(
             stopRecordingUseCase: MockStopRecordingUseCase(),
@@ -189,8 +201,8 @@ final class RecordingPipelineCoordinatorTests: XCTestCase {
             processVoiceCommandUseCase: MockProcessVoiceCommandUseCase()
         )
 
-        let mapped = sut.mapToUserFacingError(UserFacingError.rateLimited)
-        XCTAssertEqual(mapped, .rateLimited)
+        let mapped = sut.mapToUserFacingError(UserFacingError.rateLimited(retryAfterSeconds: 30))
+        XCTAssertEqual(mapped, .rateLimited(retryAfterSeconds: 30))
     }
 
     func testMapToUserFacingError_unknownError_wrapsDescription() {
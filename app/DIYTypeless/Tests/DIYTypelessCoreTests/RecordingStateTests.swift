@@ -21,7 +21,7 @@ final class RecordingStateTests: XCTestCase {
         guard case let .error(error) = sut.capsuleState else {
             return XCTFail("Expected capsule state to be .error")
         }
-        XCTAssertEqual(error, .invalidAPIKey)
+        XCTAssertEqual(error, .invalidAPIKey(provider: nil))
         XCTAssertEqual(onboardingRequestCount, 1)
         XCTAssertEqual(dependencies.recordingControlUseCase.startRecordingCallCount, 0)
     }
@@ -1,15 +1,21 @@
 //! Diagnostic commands for CLI
 
 use anyhow::{anyhow, Context, Result};
-use diy_typeless_core::{start_recording, stop_recording, CancellationToken, CoreError, LlmProvider};
+use diy_typeless_core::{
+    disarm_microphone, get_current_levels, list_input_devices, run_pipeline, stop_recording,
+    AudioLevels, CancellationToken, CoreError, LlmProvider, PipelineOptions, TranscriptionProvider,
+};
 use std::fs;
+use std::io::{self, Write};
 use std::path::PathBuf;
 use std::thread::sleep;
 use std::time::{Duration, Instant};
 
+use crate::commands::export::{render_transcript, TranscriptFormat};
 use crate::commands::utils::{
-    ensure_flac_bytes, format_duration, print_binary_status, print_key_status, resolve_groq_key,
-    resolve_llm_key, resolve_output_dir, timestamp,
+    arm_microphone_on, format_duration, is_flac, prepare_input_audio, print_binary_status,
+    print_key_status, resolve_llm_key, resolve_output_dir, resolve_transcription_key,
+    start_recording_on, timestamp,
 };
 
 /// Run environment diagnostics
@@ -30,6 +36,9 @@ pub(crate) fn run_diagnose_env() -> Result<()> {
     print_key_status("GROQ_API_KEY");
     print_key_status("GEMINI_API_KEY");
     print_key_status("OPENAI_API_KEY");
+    print_key_status("ANTHROPIC_API_KEY");
+    print_key_status("TRANSCRIPTION_API_KEY");
+    print_key_status("LLM_API_KEY");
 
     print_binary_status("pbcopy");
     print_binary_status("tccutil");
@@ -37,8 +46,53 @@ pub(crate) fn run_diagnose_env() -> Result<()> {
     Ok(())
 }
 
+/// Run input device diagnostics
+pub(crate) fn run_diagnose_devices() -> Result<()> {
+    let devices = list_input_devices().context("Failed to enumerate input devices")?;
+
+    println!("CLI diagnostics (devices)");
+    if devices.is_empty() {
+        println!("- no input devices found");
+        return Ok(());
+    }
+
+    for device in devices {
+        let marker = if device.is_default { " [default]" } else { "" };
+        println!("- {}{marker}", device.name);
+        println!("  id: {}", device.id);
+        println!(
+            "  default config: {} Hz, {} ch, {}",
+            device.default_sample_rate, device.default_channels, device.default_sample_format
+        );
+        println!(
+            "  sample formats: {}",
+            device.supported_sample_formats.join(", ")
+        );
+        let rates: Vec<String> = device
+            .supported_sample_rates
+            .iter()
+            .map(u32::to_string)
+            .collect();
+        println!("  sample rates: {}", rates.join(", "));
+    }
+
+    Ok(())
+}
+
+const METER_MIN_DB: f32 = -60.0;
+const METER_WIDTH: usize = 40;
+const METER_INTERVAL: Duration = Duration::from_millis(50);
+/// Time to let an armed microphone fill its pre-roll before recording
+const ARM_WARMUP: Duration = Duration::from_secs(1);
+
 /// Run audio diagnostics
-pub(crate) fn run_diagnose_audio(duration_seconds: u64, output: Option<PathBuf>) -> Result<()> {
+pub(crate) fn run_diagnose_audio(
+    duration_seconds: u64,
+    output: Option<PathBuf>,
+    device: Option<String>,
+    meter: bool,
+    armed: bool,
+) -> Result<()> {
     if duration_seconds == 0 {
         return Err(anyhow!("--duration-seconds must be greater than 0"));
     }
@@ -58,17 +112,67 @@ pub(crate) fn run_diagnose_audio(duration_seconds: u64, output: Option<PathBuf>)
 
     println!("CLI diagnostics (audio)");
     println!("- recording duration: {duration_seconds}s");
+    println!(
+        "- device: {}",
+        device.as_deref().unwrap_or("system default")
+    );
+
+    if armed {
+        arm_microphone_on(device.as_deref())?;
+        println!(
+            "- armed: buffering pre-roll for {}",
+            format_duration(ARM_WARMUP)
+        );
+        sleep(ARM_WARMUP);
+    }
 
     let start = Instant::now();
-    start_recording().context("Failed to start recording")?;
-    sleep(Duration::from_secs(duration_seconds));
-    let audio_data = stop_recording().context("Failed to stop recording")?;
+    start_recording_on(device.as_deref())?;
+    let start_latency = start.elapsed();
+    let meter_summary = if meter {
+        Some(print_level_meter(Duration::from_secs(duration_seconds)))
+    } else {
+        sleep(Duration::from_secs(duration_seconds));
+        None
+    };
+    let audio_data = stop_recording();
     let elapsed = start.elapsed();
+    if armed {
+        disarm_microphone().context("Failed to disarm microphone")?;
+    }
+    let audio_data = audio_data.context("Failed to stop recording")?;
 
     fs::write(&output_path, &audio_data.bytes)
         .with_context(|| format!("Failed to write {}", output_path.display()))?;
+    println!("- start latency: {}", format_duration(start_latency));
     println!("- capture wall time: {}", format_duration(elapsed));
+    println!("- pre-roll: {:.2}s", audio_data.pre_roll_seconds);
+    println!(
+        "- truncated: {}",
+        if audio_data.truncated { "yes" } else { "no" }
+    );
+    let clip_stats = &audio_data.clip_stats;
+    println!(
+        "- input peak: {:.1} dBFS, clipped samples: {} ({:.2}%)",
+        clip_stats.input_peak_db,
+        clip_stats.input_clipped_samples,
+        clip_stats.input_clipped_ratio * 100.0
+    );
+    println!(
+        "- limiter: {} samples, max reduction {:.1} dB ({} over full scale)",
+        clip_stats.limited_samples,
+        clip_stats.max_gain_reduction_db,
+        clip_stats.processing_clipped_samples
+    );
+    if let Some((max_peak_db, clipped)) = meter_summary {
+        println!("- max peak: {max_peak_db:.1} dBFS");
+        println!("- clipping: {}", if clipped { "yes" } else { "no" });
+    }
     println!("- reported duration: {:.2}s", audio_data.duration_seconds);
+    println!(
+        "- trimmed duration: {:.2}s",
+        audio_data.trimmed_duration_seconds
+    );
     println!("- format: FLAC (header verified)");
     println!("- bytes: {}", audio_data.bytes.len());
     println!("- output: {}", output_path.display());
@@ -76,6 +180,56 @@ pub(crate) fn run_diagnose_audio(duration_seconds: u64, output: Option<PathBuf>)
     Ok(())
 }
 
+/// Print a live level bar until `duration` has elapsed; returns the highest peak
+/// in dBFS and whether clipping was seen
+fn print_level_meter(duration: Duration) -> (f32, bool) {
+    let start = Instant::now();
+    let mut max_peak_db = f32::NEG_INFINITY;
+    let mut clipped = false;
+    let mut stdout = io::stdout();
+    while start.elapsed() < duration {
+        let levels = get_current_levels();
+        max_peak_db = max_peak_db.max(levels.peak_db);
+        clipped |= levels.is_clipping;
+        print!("\r{}", level_line(&levels));
+        let _ = stdout.flush();
+        sleep(METER_INTERVAL);
+    }
+    println!();
+    (max_peak_db, clipped)
+}
+
+fn level_line(levels: &AudioLevels) -> String {
+    let clip = if levels.is_clipping { " CLIP" } else { "     " };
+    format!(
+        "[{}] rms {:>6.1} dBFS  peak {:>6.1} dBFS{clip}",
+        level_bar(levels.rms_db, levels.peak_db, METER_WIDTH),
+        levels.rms_db,
+        levels.peak_db
+    )
+}
+
+/// Bar filled up to the RMS level with `|` marking the peak, on a -60..0 dBFS scale
+fn level_bar(rms_db: f32, peak_db: f32, width: usize) -> String {
+    let position = |db: f32| {
+        let fraction = ((db - METER_MIN_DB) / -METER_MIN_DB).clamp(0.0, 1.0);
+        (fraction * width as f32).round() as usize
+    };
+    let filled = position(rms_db);
+    let peak = position(peak_db).checked_sub(1);
+    (0..width)
+        .map(|i| {
+            if i < filled {
+                '#'
+            } else if Some(i) == peak {
+                '|'
+            } else {
+                ' '
+            }
+        })
+        .collect()
+}
+
 /// Run LLM diagnostics
 pub(crate) fn run_diagnose_llm(
     prompt: String,
@@ -95,7 +249,7 @@ pub(crate) fn run_diagnose_llm(
         "cancelled-before-request".to_string()
     } else {
         use secrecy::ExposeSecret;
-        resolve_llm_key(provider, llm_key)?
+        resolve_llm_key(&provider, llm_key)?
             .expose_secret()
             .to_string()
     };
@@ -140,15 +294,20 @@ pub(crate) fn run_diagnose_llm(
 pub(crate) fn run_diagnose_pipeline(
     file: PathBuf,
     output_dir: Option<PathBuf>,
-    groq_key: Option<String>,
+    transcription_provider: TranscriptionProvider,
+    transcription_key: Option<String>,
     provider: LlmProvider,
     llm_key: Option<String>,
     language: Option<String>,
     transcribe_only: bool,
     context: Option<String>,
+    format: TranscriptFormat,
+    output: Option<PathBuf>,
 ) -> Result<()> {
     let audio_bytes = fs::read(&file).context("Failed to read audio file")?;
-    ensure_flac_bytes(&audio_bytes, &file)?;
+    let input_len = audio_bytes.len();
+    let input_is_flac = is_flac(&audio_bytes);
+    let audio_bytes = prepare_input_audio(audio_bytes, &file)?;
 
     let output_dir = resolve_output_dir(output_dir)?;
     fs::create_dir_all(&output_dir)?;
@@ -158,27 +317,45 @@ pub(crate) fn run_diagnose_pipeline(
 
     println!("CLI diagnostics (pipeline)");
     println!("- input: {}", file.display());
-    println!("- format: FLAC (header verified)");
-    println!("- bytes: {}", audio_bytes.len());
+    if input_is_flac {
+        println!("- format: FLAC (header verified)");
+        println!("- bytes: {input_len}");
+    } else {
+        println!("- format: decoded and re-encoded to FLAC");
+        println!("- bytes: {input_len} -> {}", audio_bytes.len());
+    }
 
-    let groq_key = resolve_groq_key(groq_key)?;
-    let transcribe_start = Instant::now();
+    let transcription_key = resolve_transcription_key(&transcription_provider, transcription_key)?;
     // SecretString is passed by reference to core functions
     use secrecy::ExposeSecret;
-    let raw_text = diy_typeless_core::transcribe_audio_bytes(
-        groq_key.expose_secret().to_string(),
-        audio_bytes,
+    let llm_api_key = if transcribe_only {
+        String::new()
+    } else {
+        resolve_llm_key(&provider, llm_key)?
+            .expose_secret()
+            .to_string()
+    };
+    let options = PipelineOptions {
+        transcription_provider,
+        transcription_api_key: transcription_key.expose_secret().to_string(),
         language,
-    )
-    .context("Transcribe step failed")?;
-    let transcribe_elapsed = transcribe_start.elapsed();
-    let raw_path = output_dir.join(format!("{}_raw.txt", base));
-    fs::write(&raw_path, &raw_text)?;
+        llm_provider: provider,
+        llm_api_key,
+        context,
+        skip_polish: transcribe_only,
+    };
+    let result =
+        run_pipeline(audio_bytes, options, CancellationToken::new()).context("Pipeline failed")?;
+
+    let raw_path =
+        output.unwrap_or_else(|| output_dir.join(format!("{}_raw.{}", base, format.extension())));
+    fs::write(&raw_path, render_transcript(&result.transcript, format))
+        .with_context(|| format!("Failed to write {}", raw_path.display()))?;
 
     println!(
         "- transcribe: {} | {} chars",
-        format_duration(transcribe_elapsed),
-        raw_text.chars().count()
+        format_duration(Duration::from_millis(result.transcribe_ms)),
+        result.raw_text.chars().count()
     );
     println!("- raw text: {}", raw_path.display());
 
@@ -186,23 +363,17 @@ pub(crate) fn run_diagnose_pipeline(
         return Ok(());
     }
 
-    let llm_key = resolve_llm_key(provider, llm_key)?;
-    let polish_start = Instant::now();
-    let polished_text = diy_typeless_core::polish_text(
-        provider,
-        llm_key.expose_secret().to_string(),
-        raw_text,
-        context,
-    )
-    .context("Polish step failed")?;
-    let polish_elapsed = polish_start.elapsed();
+    if let Some(error) = &result.polish_error {
+        return Err(anyhow!("Polish step failed: {error}"));
+    }
+
     let polished_path = output_dir.join(format!("{}_polished.txt", base));
-    fs::write(&polished_path, &polished_text)?;
+    fs::write(&polished_path, &result.polished_text)?;
 
     println!(
         "- polish: {} | {} chars",
-        format_duration(polish_elapsed),
-        polished_text.chars().count()
+        format_duration(Duration::from_millis(result.polish_ms)),
+        result.polished_text.chars().count()
     );
     println!("- polished text: {}", polished_path.display());
 
@@ -211,14 +382,22 @@ pub(crate) fn run_diagnose_pipeline(
 
 #[cfg(test)]
 mod tests {
-    use super::run_diagnose_audio;
+    use super::{level_bar, run_diagnose_audio};
 
     #[test]
     fn run_diagnose_audio_should_fail_when_duration_is_zero() {
-        let result = run_diagnose_audio(0, None);
+        let result = run_diagnose_audio(0, None, None, false, false);
         assert!(result
             .expect_err("zero duration should fail")
             .to_string()
             .contains("--duration-seconds must be greater than 0"));
     }
+
+    #[test]
+    fn level_bar_should_fill_to_rms_and_mark_peak() {
+        assert_eq!(level_bar(-30.0, -15.0, 8), "#### |  ");
+        assert_eq!(level_bar(-100.0, -100.0, 4), "    ");
+        assert_eq!(level_bar(0.0, 0.0, 4), "####");
+        assert_eq!(level_bar(-60.0, 3.0, 4), "   |");
+    }
 }
@@ -0,0 +1,236 @@
+//! Vocabulary and replacement dictionary commands
+
+use anyhow::{bail, Context, Result};
+use diy_typeless_core::{
+    add_dictionary_entry, add_vocabulary_term, get_dictionary, remove_dictionary_entry,
+    remove_vocabulary_term, set_dictionary, Dictionary, DictionaryEntry,
+};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+const DICTIONARY_ENV: &str = "DIY_TYPELESS_DICTIONARY";
+
+/// Resolve the dictionary file: `--file`, then `DIY_TYPELESS_DICTIONARY`, then
+/// `dictionary.json` in the user config directory
+pub(crate) fn resolve_dictionary_path(file: Option<PathBuf>) -> Result<PathBuf> {
+    dictionary_path_from(
+        file,
+        std::env::var_os(DICTIONARY_ENV).map(PathBuf::from),
+        dirs::config_dir(),
+    )
+}
+
+fn dictionary_path_from(
+    file: Option<PathBuf>,
+    env_file: Option<PathBuf>,
+    config_dir: Option<PathBuf>,
+) -> Result<PathBuf> {
+    if let Some(path) = file.or(env_file) {
+        return Ok(path);
+    }
+    let config_dir = config_dir.context("Failed to resolve config directory")?;
+    Ok(config_dir.join("diy-typeless").join("dictionary.json"))
+}
+
+/// Read a dictionary file; a missing file is an empty dictionary
+fn read_dictionary(path: &Path) -> Result<Dictionary> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .with_context(|| format!("Invalid dictionary file {}", path.display())),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(Dictionary::default()),
+        Err(e) => Err(e).with_context(|| format!("Failed to read {}", path.display())),
+    }
+}
+
+fn write_dictionary(path: &Path, dictionary: &Dictionary) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_string_pretty(dictionary)?;
+    fs::write(path, contents + "\n").with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Load a dictionary file into the core
+fn install_dictionary(path: &Path) -> Result<()> {
+    set_dictionary(read_dictionary(path)?)
+        .with_context(|| format!("Invalid dictionary file {}", path.display()))
+}
+
+/// Load the saved dictionary so transcription commands use it
+pub(crate) fn install_saved_dictionary() -> Result<()> {
+    install_dictionary(&resolve_dictionary_path(None)?)
+}
+
+/// Add a vocabulary term, or a replacement when `replacement` is given
+pub(crate) fn run_dict_add(
+    file: Option<PathBuf>,
+    text: String,
+    replacement: Option<String>,
+    case_sensitive: bool,
+    regex: bool,
+) -> Result<()> {
+    let path = resolve_dictionary_path(file)?;
+    install_dictionary(&path)?;
+
+    match replacement {
+        Some(replacement) => {
+            add_dictionary_entry(DictionaryEntry {
+                pattern: text.clone(),
+                replacement: replacement.clone(),
+                case_sensitive,
+                is_regex: regex,
+            })?;
+            println!("Added replacement: {text} -> {replacement}");
+        }
+        None => {
+            add_vocabulary_term(text.clone())?;
+            println!("Added vocabulary term: {text}");
+        }
+    }
+
+    write_dictionary(&path, &get_dictionary())
+}
+
+/// Print the vocabulary and replacements
+pub(crate) fn run_dict_list(file: Option<PathBuf>) -> Result<()> {
+    let path = resolve_dictionary_path(file)?;
+    let dictionary = read_dictionary(&path)?;
+
+    println!("Dictionary: {}", path.display());
+    println!("Vocabulary ({}):", dictionary.vocabulary.len());
+    for term in &dictionary.vocabulary {
+        println!("- {term}");
+    }
+    println!("Replacements ({}):", dictionary.entries.len());
+    for entry in &dictionary.entries {
+        let mut flags = Vec::new();
+        if entry.case_sensitive {
+            flags.push("case-sensitive");
+        }
+        if entry.is_regex {
+            flags.push("regex");
+        }
+        let flags = if flags.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", flags.join(", "))
+        };
+        println!("- {} -> {}{flags}", entry.pattern, entry.replacement);
+    }
+    Ok(())
+}
+
+/// Remove a vocabulary term or replacement pattern
+pub(crate) fn run_dict_remove(file: Option<PathBuf>, text: String) -> Result<()> {
+    let path = resolve_dictionary_path(file)?;
+    install_dictionary(&path)?;
+
+    let removed_term = remove_vocabulary_term(text.clone())?;
+    let removed_entry = remove_dictionary_entry(text.clone())?;
+    if !removed_term && !removed_entry {
+        bail!("'{text}' is not in the dictionary");
+    }
+
+    write_dictionary(&path, &get_dictionary())?;
+    println!("Removed: {text}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        dictionary_path_from, read_dictionary, run_dict_add, run_dict_remove, write_dictionary,
+    };
+    use diy_typeless_core::{Dictionary, DictionaryEntry};
+    use std::fs;
+    use std::path::PathBuf;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_file(label: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system time should be after unix epoch")
+            .as_nanos();
+        std::env::temp_dir()
+            .join(format!(
+                "diy_typeless_cli_dict_{label}_{}_{}",
+                std::process::id(),
+                nanos
+            ))
+            .join("dictionary.json")
+    }
+
+    #[test]
+    fn dictionary_path_from_should_prefer_flag_then_env_then_config_dir() {
+        let flag = PathBuf::from("/tmp/flag.json");
+        let env = PathBuf::from("/tmp/env.json");
+        let config = PathBuf::from("/tmp/config");
+
+        let resolved = dictionary_path_from(Some(flag.clone()), Some(env.clone()), None)
+            .expect("path should resolve");
+        assert_eq!(resolved, flag);
+        let resolved = dictionary_path_from(None, Some(env.clone()), Some(config.clone()))
+            .expect("path should resolve");
+        assert_eq!(resolved, env);
+        let resolved = dictionary_path_from(None, None, Some(config)).expect("path should resolve");
+        assert_eq!(
+            resolved,
+            PathBuf::from("/tmp/config/diy-typeless/dictionary.json")
+        );
+        assert!(dictionary_path_from(None, None, None).is_err());
+    }
+
+    #[test]
+    fn read_dictionary_should_treat_missing_file_as_empty() {
+        let dictionary = read_dictionary(&temp_file("missing")).expect("missing file is empty");
+        assert_eq!(dictionary, Dictionary::default());
+    }
+
+    #[test]
+    fn write_dictionary_should_round_trip_and_create_parent_directory() {
+        let path = temp_file("round_trip");
+        let dictionary = Dictionary {
+            vocabulary: vec!["Anya".to_string()],
+            entries: vec![DictionaryEntry {
+                pattern: "cooper netties".to_string(),
+                replacement: "Kubernetes".to_string(),
+                case_sensitive: false,
+                is_regex: false,
+            }],
+        };
+
+        write_dictionary(&path, &dictionary).expect("dictionary should be written");
+        let loaded = read_dictionary(&path).expect("dictionary should be read");
+        assert_eq!(loaded, dictionary);
+
+        fs::remove_dir_all(path.parent().expect("temp dir")).ok();
+    }
+
+    #[test]
+    fn run_dict_add_and_remove_should_persist_changes() {
+        let path = temp_file("add_remove");
+
+        run_dict_add(Some(path.clone()), "Anya".to_string(), None, false, false)
+            .expect("term should be added");
+        run_dict_add(
+            Some(path.clone()),
+            "cooper netties".to_string(),
+            Some("Kubernetes".to_string()),
+            false,
+            false,
+        )
+        .expect("replacement should be added");
+        let saved = read_dictionary(&path).expect("dictionary should be read");
+        assert_eq!(saved.vocabulary, vec!["Anya"]);
+        assert_eq!(saved.entries[0].replacement, "Kubernetes");
+
+        run_dict_remove(Some(path.clone()), "Anya".to_string()).expect("term should be removed");
+        assert!(run_dict_remove(Some(path.clone()), "Anya".to_string()).is_err());
+        let saved = read_dictionary(&path).expect("dictionary should be read");
+        assert!(saved.vocabulary.is_empty());
+        assert_eq!(saved.entries.len(), 1);
+
+        fs::remove_dir_all(path.parent().expect("temp dir")).ok();
+    }
+}
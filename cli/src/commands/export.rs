@@ -0,0 +1,240 @@
+//! Transcript export formats for CLI output
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use diy_typeless_core::{Transcript, TranscriptSegment};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+/// Output format for transcripts
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub(crate) enum TranscriptFormat {
+    /// Plain transcript text
+    #[default]
+    Text,
+    /// Transcript with segments, words and metadata as JSON
+    Json,
+    /// SubRip subtitles
+    Srt,
+    /// WebVTT subtitles
+    Vtt,
+}
+
+impl TranscriptFormat {
+    /// File extension for this format
+    pub(crate) fn extension(self) -> &'static str {
+        match self {
+            TranscriptFormat::Text => "txt",
+            TranscriptFormat::Json => "json",
+            TranscriptFormat::Srt => "srt",
+            TranscriptFormat::Vtt => "vtt",
+        }
+    }
+}
+
+/// Render a transcript in the requested format
+pub(crate) fn render_transcript(transcript: &Transcript, format: TranscriptFormat) -> String {
+    match format {
+        TranscriptFormat::Text => format!("{}\n", transcript.text),
+        TranscriptFormat::Json => render_json(transcript),
+        TranscriptFormat::Srt => render_cues(transcript, CueStyle::Srt),
+        TranscriptFormat::Vtt => render_cues(transcript, CueStyle::Vtt),
+    }
+}
+
+/// Write rendered output to `output`, or stdout when no path is given
+pub(crate) fn write_output(content: &str, output: Option<&Path>) -> Result<()> {
+    match output {
+        Some(path) => {
+            fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+        }
+        None => {
+            print!("{content}");
+            Ok(())
+        }
+    }
+}
+
+fn render_json(transcript: &Transcript) -> String {
+    let segments: Vec<_> = transcript
+        .segments
+        .iter()
+        .map(|segment| {
+            serde_json::json!({
+                "start": segment.start_seconds,
+                "end": segment.end_seconds,
+                "text": segment.text,
+                "avg_logprob": segment.avg_logprob,
+                "no_speech_prob": segment.no_speech_prob,
+            })
+        })
+        .collect();
+    let words: Vec<_> = transcript
+        .words
+        .iter()
+        .map(|word| {
+            serde_json::json!({
+                "word": word.word,
+                "start": word.start_seconds,
+                "end": word.end_seconds,
+            })
+        })
+        .collect();
+    let value = serde_json::json!({
+        "text": transcript.text,
+        "language": transcript.language,
+        "duration": transcript.duration_seconds,
+        "segments": segments,
+        "words": words,
+    });
+
+    format!("{value:#}\n")
+}
+
+#[derive(Clone, Copy)]
+enum CueStyle {
+    Srt,
+    Vtt,
+}
+
+/// Segments to emit as cues; transcripts without timings become one cue
+/// spanning the whole recording.
+fn cues(transcript: &Transcript) -> Vec<TranscriptSegment> {
+    if !transcript.segments.is_empty() {
+        return transcript.segments.clone();
+    }
+    vec![TranscriptSegment {
+        start_seconds: 0.0,
+        end_seconds: transcript.duration_seconds.unwrap_or(0.0),
+        text: transcript.text.clone(),
+        avg_logprob: None,
+        no_speech_prob: None,
+    }]
+}
+
+fn render_cues(transcript: &Transcript, style: CueStyle) -> String {
+    let mut out = String::new();
+    if matches!(style, CueStyle::Vtt) {
+        out.push_str("WEBVTT\n\n");
+    }
+
+    let cues = cues(transcript);
+    for (index, cue) in cues.iter().filter(|cue| !cue.text.is_empty()).enumerate() {
+        if matches!(style, CueStyle::Srt) {
+            let _ = writeln!(out, "{}", index + 1);
+        }
+        let _ = writeln!(
+            out,
+            "{} --> {}",
+            cue_timestamp(cue.start_seconds, style),
+            cue_timestamp(cue.end_seconds.max(cue.start_seconds), style)
+        );
+        let _ = writeln!(out, "{}\n", cue.text);
+    }
+
+    out
+}
+
+/// Format seconds as `HH:MM:SS,mmm` (SRT) or `HH:MM:SS.mmm` (WebVTT)
+fn cue_timestamp(seconds: f64, style: CueStyle) -> String {
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    let (hours, rest) = (total_ms / 3_600_000, total_ms % 3_600_000);
+    let (minutes, rest) = (rest / 60_000, rest % 60_000);
+    let (secs, millis) = (rest / 1000, rest % 1000);
+    let separator = match style {
+        CueStyle::Srt => ',',
+        CueStyle::Vtt => '.',
+    };
+    format!("{hours:02}:{minutes:02}:{secs:02}{separator}{millis:03}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cue_timestamp, render_transcript, CueStyle, TranscriptFormat};
+    use diy_typeless_core::{Transcript, TranscriptSegment, TranscriptWord};
+
+    fn segment(start: f64, end: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            start_seconds: start,
+            end_seconds: end,
+            text: text.to_string(),
+            avg_logprob: Some(-0.2),
+            no_speech_prob: None,
+        }
+    }
+
+    fn transcript() -> Transcript {
+        Transcript {
+            text: "Hello there. General Kenobi.".to_string(),
+            language: Some("english".to_string()),
+            duration_seconds: Some(3725.5),
+            segments: vec![
+                segment(0.0, 1.25, "Hello there."),
+                segment(3661.0, 3725.5, "General Kenobi."),
+            ],
+            words: vec![TranscriptWord {
+                word: "Hello".to_string(),
+                start_seconds: 0.0,
+                end_seconds: 0.4,
+            }],
+        }
+    }
+
+    #[test]
+    fn cue_timestamp_should_use_style_specific_separator() {
+        assert_eq!(cue_timestamp(3661.0456, CueStyle::Srt), "01:01:01,046");
+        assert_eq!(cue_timestamp(0.5, CueStyle::Vtt), "00:00:00.500");
+        assert_eq!(cue_timestamp(-1.0, CueStyle::Srt), "00:00:00,000");
+    }
+
+    #[test]
+    fn render_transcript_should_number_srt_cues() {
+        let srt = render_transcript(&transcript(), TranscriptFormat::Srt);
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:01,250\nHello there.\n\n\
+             2\n01:01:01,000 --> 01:02:05,500\nGeneral Kenobi.\n\n"
+        );
+    }
+
+    #[test]
+    fn render_transcript_should_write_vtt_header_and_unnumbered_cues() {
+        let vtt = render_transcript(&transcript(), TranscriptFormat::Vtt);
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n00:00:00.000 --> 00:00:01.250\nHello there.\n\n\
+             01:01:01.000 --> 01:02:05.500\nGeneral Kenobi.\n\n"
+        );
+    }
+
+    #[test]
+    fn render_transcript_should_fall_back_to_single_cue_without_segments() {
+        let bare = Transcript {
+            segments: Vec::new(),
+            duration_seconds: Some(2.0),
+            ..transcript()
+        };
+        let srt = render_transcript(&bare, TranscriptFormat::Srt);
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:02,000\nHello there. General Kenobi.\n\n"
+        );
+    }
+
+    #[test]
+    fn render_transcript_should_emit_json_with_segments_and_words() {
+        let json = render_transcript(&transcript(), TranscriptFormat::Json);
+        let value: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+        assert_eq!(value["language"], "english");
+        assert_eq!(value["segments"][1]["start"], 3661.0);
+        assert_eq!(value["segments"][0]["text"], "Hello there.");
+        assert_eq!(value["words"][0]["word"], "Hello");
+    }
+
+    #[test]
+    fn render_transcript_should_end_text_with_newline() {
+        let text = render_transcript(&transcript(), TranscriptFormat::Text);
+        assert_eq!(text, "Hello there. General Kenobi.\n");
+    }
+}
@@ -3,4 +3,6 @@
 //! This module contains implementations for various CLI subcommands.
 
 pub(crate) mod diagnose;
+pub(crate) mod dict;
+pub(crate) mod export;
 pub(crate) mod utils;
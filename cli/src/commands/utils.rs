@@ -1,9 +1,13 @@
 //! Utility functions for CLI
 
 use anyhow::{Context, Result};
-use diy_typeless_core::LlmProvider;
+use diy_typeless_core::{
+    arm_microphone, arm_microphone_with_device, pause_recording, prepare_audio_file,
+    resume_recording, start_recording, start_recording_with_device, AudioData, LlmProvider,
+    LlmStreamListener, TranscriptionProvider,
+};
 use secrecy::SecretString;
-use std::io::{self, Read};
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
@@ -59,6 +63,24 @@ pub(crate) fn resolve_groq_key(provided: Option<String>) -> Result<SecretString>
     resolve_api_key_value(provided, std::env::var("GROQ_API_KEY").ok(), "GROQ_API_KEY")
 }
 
+/// Resolve the transcription API key for the selected provider.
+///
+/// OpenAI-compatible servers are often self-hosted without authentication, so a
+/// missing key resolves to an empty secret instead of an error.
+pub(crate) fn resolve_transcription_key(
+    provider: &TranscriptionProvider,
+    provided: Option<String>,
+) -> Result<SecretString> {
+    match provider {
+        TranscriptionProvider::Groq => resolve_groq_key(provided),
+        TranscriptionProvider::Openai => resolve_openai_key(provided),
+        TranscriptionProvider::OpenAiCompatible { .. } => Ok(resolve_optional_api_key_value(
+            provided,
+            std::env::var("TRANSCRIPTION_API_KEY").ok(),
+        )),
+    }
+}
+
 /// Resolve Gemini API key from argument or environment
 pub(crate) fn resolve_gemini_key(provided: Option<String>) -> Result<SecretString> {
     resolve_api_key_value(
@@ -68,15 +90,40 @@ pub(crate) fn resolve_gemini_key(provided: Option<String>) -> Result<SecretStrin
     )
 }
 
+/// Resolve OpenAI API key from argument or environment
+pub(crate) fn resolve_openai_key(provided: Option<String>) -> Result<SecretString> {
+    resolve_api_key_value(
+        provided,
+        std::env::var("OPENAI_API_KEY").ok(),
+        "OPENAI_API_KEY",
+    )
+}
+
+/// Resolve Anthropic API key from argument or environment
+pub(crate) fn resolve_anthropic_key(provided: Option<String>) -> Result<SecretString> {
+    resolve_api_key_value(
+        provided,
+        std::env::var("ANTHROPIC_API_KEY").ok(),
+        "ANTHROPIC_API_KEY",
+    )
+}
+
+/// Resolve the LLM API key for the selected provider.
+///
+/// Local OpenAI-compatible servers usually run without authentication, so a
+/// missing key resolves to an empty secret instead of an error.
 pub(crate) fn resolve_llm_key(
-    provider: LlmProvider,
+    provider: &LlmProvider,
     provided: Option<String>,
 ) -> Result<SecretString> {
     match provider {
         LlmProvider::GoogleAiStudio => resolve_gemini_key(provided),
-        LlmProvider::Openai => {
-            resolve_api_key_value(provided, std::env::var("OPENAI_API_KEY").ok(), "OPENAI_API_KEY")
-        }
+        LlmProvider::Openai => resolve_openai_key(provided),
+        LlmProvider::Anthropic => resolve_anthropic_key(provided),
+        LlmProvider::OpenAiCompatible { .. } => Ok(resolve_optional_api_key_value(
+            provided,
+            std::env::var("LLM_API_KEY").ok(),
+        )),
     }
 }
 
@@ -102,6 +149,43 @@ fn resolve_api_key_value(
     Ok(SecretString::from(trimmed.to_string()))
 }
 
+fn resolve_optional_api_key_value(
+    provided: Option<String>,
+    env_value: Option<String>,
+) -> SecretString {
+    let key = provided.or(env_value).unwrap_or_default();
+    SecretString::from(key.trim().to_string())
+}
+
+/// Start recording on the requested input device, or the system default
+pub(crate) fn start_recording_on(device: Option<&str>) -> Result<()> {
+    match device {
+        Some(device_id) => start_recording_with_device(device_id.to_string()),
+        None => start_recording(),
+    }
+    .context("Failed to start recording")
+}
+
+/// Arm the given input device (or the system default) for pre-roll
+pub(crate) fn arm_microphone_on(device: Option<&str>) -> Result<()> {
+    match device {
+        Some(device_id) => arm_microphone_with_device(device_id.to_string()),
+        None => arm_microphone(),
+    }
+    .context("Failed to arm microphone")
+}
+
+/// Print streamed LLM output to stdout as it arrives
+pub(crate) struct StdoutStreamListener;
+
+impl LlmStreamListener for StdoutStreamListener {
+    fn on_delta(&self, delta: String) {
+        let mut stdout = io::stdout().lock();
+        let _ = stdout.write_all(delta.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
 /// Wait for user to press Enter
 pub(crate) fn wait_for_enter() -> Result<()> {
     let mut input = String::new();
@@ -109,6 +193,67 @@ pub(crate) fn wait_for_enter() -> Result<()> {
     Ok(())
 }
 
+/// Input accepted while recording interactively
+#[derive(Debug, Eq, PartialEq)]
+enum RecordingKey {
+    Stop,
+    TogglePause,
+    Unknown,
+}
+
+fn parse_recording_key(line: &str) -> RecordingKey {
+    match line.trim() {
+        "" => RecordingKey::Stop,
+        key if key.eq_ignore_ascii_case("p") => RecordingKey::TogglePause,
+        _ => RecordingKey::Unknown,
+    }
+}
+
+/// Keep recording until Enter; `p` then Enter toggles pause
+pub(crate) fn wait_for_stop_with_pause() -> Result<()> {
+    println!("Recording... Press Enter to stop, or type p and Enter to pause.");
+    let mut paused = false;
+    loop {
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        match parse_recording_key(&input) {
+            RecordingKey::Stop => return Ok(()),
+            RecordingKey::TogglePause if paused => {
+                resume_recording().context("Failed to resume recording")?;
+                paused = false;
+                println!("Recording resumed. Press Enter to stop, or p to pause.");
+            }
+            RecordingKey::TogglePause => {
+                pause_recording().context("Failed to pause recording")?;
+                paused = true;
+                println!("Recording paused. Type p and Enter to resume, or Enter to stop.");
+            }
+            RecordingKey::Unknown => {
+                println!("Press Enter to stop, or type p and Enter to pause/resume.")
+            }
+        }
+    }
+}
+
+/// Share of clipped input samples worth warning about
+const CLIP_WARNING_RATIO: f32 = 0.001;
+
+/// Warnings about a finished recording: cut short at the length limit, or
+/// clipping at the microphone
+pub(crate) fn recording_warnings(audio: &AudioData) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if audio.truncated {
+        warnings.push("recording reached its length limit and was cut short".to_string());
+    }
+    if audio.clip_stats.input_clipped_ratio >= CLIP_WARNING_RATIO {
+        warnings.push(format!(
+            "input clipped on {:.1}% of samples; move further from the microphone or lower its gain",
+            audio.clip_stats.input_clipped_ratio * 100.0
+        ));
+    }
+    warnings
+}
+
 /// Read all text from stdin
 pub(crate) fn read_stdin() -> Result<String> {
     let mut buffer = String::new();
@@ -116,16 +261,20 @@ pub(crate) fn read_stdin() -> Result<String> {
     Ok(buffer.trim().to_string())
 }
 
-/// Validate that bytes start with the FLAC stream marker.
-pub(crate) fn ensure_flac_bytes(bytes: &[u8], path: &Path) -> Result<()> {
-    const FLAC_MAGIC: &[u8; 4] = b"fLaC";
-    if bytes.len() < FLAC_MAGIC.len() || &bytes[..FLAC_MAGIC.len()] != FLAC_MAGIC {
-        anyhow::bail!(
-            "Input file is not FLAC: {} (expected 'fLaC' header)",
-            path.display()
-        );
+/// Check whether bytes start with the FLAC stream marker
+pub(crate) fn is_flac(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"fLaC")
+}
+
+/// Pass FLAC input through; decode other formats (WAV, MP3, OGG, M4A) and
+/// re-encode them to FLAC
+pub(crate) fn prepare_input_audio(bytes: Vec<u8>, path: &Path) -> Result<Vec<u8>> {
+    if is_flac(&bytes) {
+        return Ok(bytes);
     }
-    Ok(())
+    let audio = prepare_audio_file(bytes)
+        .with_context(|| format!("Failed to decode audio file: {}", path.display()))?;
+    Ok(audio.bytes)
 }
 
 /// Copy text to clipboard (macOS only)
@@ -135,7 +284,6 @@ pub(crate) fn copy_to_clipboard(text: &str) {
             .stdin(std::process::Stdio::piped())
             .spawn()
             .and_then(|mut child| {
-                use std::io::Write;
                 if let Some(stdin) = child.stdin.as_mut() {
                     stdin.write_all(text.as_bytes())?;
                 }
@@ -166,11 +314,12 @@ pub(crate) fn print_binary_status(binary: &str) {
 #[cfg(test)]
 mod tests {
     use super::{
-        default_output_dir, ensure_flac_bytes, find_binary_in_path, format_duration, mask_secret,
-        resolve_api_key_value, resolve_gemini_key, resolve_groq_key, resolve_llm_key,
-        resolve_output_dir,
+        default_output_dir, find_binary_in_path, format_duration, mask_secret, parse_recording_key,
+        prepare_input_audio, recording_warnings, resolve_api_key_value, resolve_gemini_key,
+        resolve_groq_key, resolve_llm_key, resolve_optional_api_key_value, resolve_output_dir,
+        resolve_transcription_key, RecordingKey,
     };
-    use diy_typeless_core::LlmProvider;
+    use diy_typeless_core::{AudioData, ClipStats, LlmProvider, TranscriptionProvider};
     use secrecy::ExposeSecret;
     use std::ffi::OsString;
     use std::fs;
@@ -312,27 +461,33 @@ mod tests {
     }
 
     #[test]
-    fn ensure_flac_bytes_accepts_valid_flac_header() {
-        let bytes = b"fLaC\x00\x00\x00\x22";
-        let result = ensure_flac_bytes(bytes, Path::new("audio.flac"));
-        assert!(result.is_ok());
+    fn parse_recording_key_maps_enter_and_p() {
+        assert_eq!(parse_recording_key("\n"), RecordingKey::Stop);
+        assert_eq!(parse_recording_key(" p \n"), RecordingKey::TogglePause);
+        assert_eq!(parse_recording_key("P\r\n"), RecordingKey::TogglePause);
+        assert_eq!(parse_recording_key("q\n"), RecordingKey::Unknown);
+    }
+
+    #[test]
+    fn prepare_input_audio_passes_flac_through() {
+        let bytes = b"fLaC\x00\x00\x00\x22".to_vec();
+        let result = prepare_input_audio(bytes.clone(), Path::new("audio.flac"));
+        assert_eq!(result.expect("flac should pass through"), bytes);
     }
 
     #[test]
-    fn ensure_flac_bytes_rejects_invalid_header() {
-        let bytes = b"RIFF";
-        let result = ensure_flac_bytes(bytes, Path::new("audio.bin"));
+    fn prepare_input_audio_rejects_unknown_format() {
+        let result = prepare_input_audio(b"RIFF".to_vec(), Path::new("audio.bin"));
         assert!(result.is_err());
         assert_eq!(
             result.expect_err("must be error").to_string(),
-            "Input file is not FLAC: audio.bin (expected 'fLaC' header)"
+            "Failed to decode audio file: audio.bin"
         );
     }
 
     #[test]
-    fn ensure_flac_bytes_rejects_too_short_input() {
-        let bytes = b"fL";
-        let result = ensure_flac_bytes(bytes, Path::new("audio.flac"));
+    fn prepare_input_audio_rejects_too_short_input() {
+        let result = prepare_input_audio(b"fL".to_vec(), Path::new("audio.flac"));
         assert!(result.is_err());
     }
 
@@ -545,8 +700,101 @@ mod tests {
             .expect("api key test lock should be acquired");
         let _guard = set_api_keys_for_test(None, None, Some("openai-env"));
 
-        let key = resolve_llm_key(LlmProvider::Openai, None)
+        let key =
+            resolve_llm_key(&LlmProvider::Openai, None).expect("openai env key should resolve");
+        assert_eq!(key.expose_secret(), "openai-env");
+    }
+
+    #[test]
+    fn resolve_llm_key_should_accept_provided_key_for_compatible_server() {
+        let provider = LlmProvider::OpenAiCompatible {
+            base_url: "http://localhost:11434/v1".to_string(),
+            model: "llama3.2".to_string(),
+        };
+
+        let key = resolve_llm_key(&provider, Some(" local-key ".to_string()))
+            .expect("compatible key should resolve");
+        assert_eq!(key.expose_secret(), "local-key");
+    }
+
+    #[test]
+    fn resolve_llm_key_should_read_anthropic_env_variable() {
+        let _lock = API_KEY_TEST_LOCK
+            .lock()
+            .expect("api key test lock should be acquired");
+        let _guard = set_api_keys_for_test(None, None, None);
+        let original_anthropic = std::env::var_os("ANTHROPIC_API_KEY");
+        std::env::set_var("ANTHROPIC_API_KEY", "anthropic-env");
+
+        let key = resolve_llm_key(&LlmProvider::Anthropic, None);
+
+        match original_anthropic {
+            Some(value) => std::env::set_var("ANTHROPIC_API_KEY", value),
+            None => std::env::remove_var("ANTHROPIC_API_KEY"),
+        }
+        let key = key.expect("anthropic env key should resolve");
+        assert_eq!(key.expose_secret(), "anthropic-env");
+    }
+
+    #[test]
+    fn resolve_transcription_key_should_read_openai_env_for_openai_provider() {
+        let _lock = API_KEY_TEST_LOCK
+            .lock()
+            .expect("api key test lock should be acquired");
+        let _guard = set_api_keys_for_test(Some("groq-env"), None, Some("openai-env"));
+
+        let key = resolve_transcription_key(&TranscriptionProvider::Openai, None)
             .expect("openai env key should resolve");
         assert_eq!(key.expose_secret(), "openai-env");
     }
+
+    #[test]
+    fn resolve_transcription_key_should_read_groq_env_for_groq_provider() {
+        let _lock = API_KEY_TEST_LOCK
+            .lock()
+            .expect("api key test lock should be acquired");
+        let _guard = set_api_keys_for_test(Some("groq-env"), None, Some("openai-env"));
+
+        let key = resolve_transcription_key(&TranscriptionProvider::Groq, None)
+            .expect("groq env key should resolve");
+        assert_eq!(key.expose_secret(), "groq-env");
+    }
+
+    #[test]
+    fn resolve_optional_api_key_value_should_allow_missing_key() {
+        let key = resolve_optional_api_key_value(None, None);
+        assert_eq!(key.expose_secret(), "");
+    }
+
+    #[test]
+    fn resolve_optional_api_key_value_should_prefer_trimmed_provided_key() {
+        let key = resolve_optional_api_key_value(
+            Some("  local-key  ".to_string()),
+            Some("env-key".to_string()),
+        );
+        assert_eq!(key.expose_secret(), "local-key");
+    }
+
+    #[test]
+    fn recording_warnings_should_flag_truncation_and_input_clipping() {
+        let mut audio = AudioData {
+            bytes: Vec::new(),
+            duration_seconds: 1.0,
+            trimmed_duration_seconds: 1.0,
+            pre_roll_seconds: 0.0,
+            truncated: false,
+            clip_stats: ClipStats::default(),
+        };
+        assert!(recording_warnings(&audio).is_empty());
+
+        audio.clip_stats.input_clipped_ratio = 0.0005;
+        assert!(recording_warnings(&audio).is_empty());
+
+        audio.truncated = true;
+        audio.clip_stats.input_clipped_ratio = 0.025;
+        let warnings = recording_warnings(&audio);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("length limit"));
+        assert!(warnings[1].starts_with("input clipped on 2.5% of samples"));
+    }
 }
@@ -1,20 +1,28 @@
 //! Command-line interface for local recording, transcription, and polishing.
 
 use anyhow::{Context, Result};
-use clap::{Parser, Subcommand, ValueEnum};
-use diy_typeless_core::{start_recording, stop_recording, LlmProvider};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use diy_typeless_core::{
+    run_pipeline, stop_recording, CancellationToken, LlmProvider, PipelineOptions,
+    TranscriptionProvider,
+};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::thread::sleep;
 use std::time::Duration;
 
 mod commands;
 use commands::diagnose::{
-    run_diagnose_audio, run_diagnose_env, run_diagnose_llm, run_diagnose_pipeline,
+    run_diagnose_audio, run_diagnose_devices, run_diagnose_env, run_diagnose_llm,
+    run_diagnose_pipeline,
 };
+use commands::dict::{install_saved_dictionary, run_dict_add, run_dict_list, run_dict_remove};
+use commands::export::{render_transcript, write_output, TranscriptFormat};
 use commands::utils::{
-    copy_to_clipboard, ensure_flac_bytes, read_stdin, resolve_groq_key, resolve_llm_key,
-    resolve_output_dir, timestamp, wait_for_enter,
+    copy_to_clipboard, prepare_input_audio, read_stdin, recording_warnings, resolve_llm_key,
+    resolve_output_dir, resolve_transcription_key, start_recording_on, timestamp, wait_for_enter,
+    wait_for_stop_with_pause, StdoutStreamListener,
 };
 
 #[derive(Parser)]
@@ -31,13 +39,89 @@ enum CliLlmProvider {
     GoogleAiStudio,
     #[value(name = "openai")]
     Openai,
+    #[value(name = "anthropic")]
+    Anthropic,
+    #[value(name = "openai-compatible")]
+    OpenAiCompatible,
+}
+
+#[derive(Args)]
+struct LlmArgs {
+    #[arg(long, value_enum, default_value = "google-ai-studio")]
+    provider: CliLlmProvider,
+    #[arg(long)]
+    llm_key: Option<String>,
+    #[arg(long)]
+    llm_base_url: Option<String>,
+    #[arg(long)]
+    llm_model: Option<String>,
+}
+
+impl LlmArgs {
+    fn provider(&self) -> Result<LlmProvider> {
+        match self.provider {
+            CliLlmProvider::OpenAiCompatible => {
+                let base_url = self
+                    .llm_base_url
+                    .clone()
+                    .context("--llm-base-url is required for the openai-compatible provider")?;
+                let model = self
+                    .llm_model
+                    .clone()
+                    .context("--llm-model is required for the openai-compatible provider")?;
+                Ok(LlmProvider::OpenAiCompatible { base_url, model })
+            }
+            _ if self.llm_base_url.is_some() || self.llm_model.is_some() => {
+                anyhow::bail!("--llm-base-url and --llm-model require --provider openai-compatible")
+            }
+            CliLlmProvider::GoogleAiStudio => Ok(LlmProvider::GoogleAiStudio),
+            CliLlmProvider::Openai => Ok(LlmProvider::Openai),
+            CliLlmProvider::Anthropic => Ok(LlmProvider::Anthropic),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+enum CliTranscriptionProvider {
+    #[value(name = "groq")]
+    Groq,
+    #[value(name = "openai")]
+    Openai,
+    #[value(name = "openai-compatible")]
+    OpenAiCompatible,
+}
+
+#[derive(Args)]
+struct TranscriptionArgs {
+    #[arg(long, value_enum, default_value = "groq")]
+    transcription_provider: CliTranscriptionProvider,
+    #[arg(long, alias = "groq-key")]
+    transcription_key: Option<String>,
+    #[arg(long)]
+    transcription_base_url: Option<String>,
+    #[arg(long)]
+    transcription_model: Option<String>,
 }
 
-impl From<CliLlmProvider> for LlmProvider {
-    fn from(value: CliLlmProvider) -> Self {
-        match value {
-            CliLlmProvider::GoogleAiStudio => LlmProvider::GoogleAiStudio,
-            CliLlmProvider::Openai => LlmProvider::Openai,
+impl TranscriptionArgs {
+    fn provider(&self) -> Result<TranscriptionProvider> {
+        match self.transcription_provider {
+            CliTranscriptionProvider::OpenAiCompatible => {
+                let base_url = self.transcription_base_url.clone().context(
+                    "--transcription-base-url is required for the openai-compatible provider",
+                )?;
+                let model = self.transcription_model.clone().context(
+                    "--transcription-model is required for the openai-compatible provider",
+                )?;
+                Ok(TranscriptionProvider::OpenAiCompatible { base_url, model })
+            }
+            _ if self.transcription_base_url.is_some() || self.transcription_model.is_some() => {
+                anyhow::bail!(
+                    "--transcription-base-url and --transcription-model require --transcription-provider openai-compatible"
+                )
+            }
+            CliTranscriptionProvider::Groq => Ok(TranscriptionProvider::Groq),
+            CliTranscriptionProvider::Openai => Ok(TranscriptionProvider::Openai),
         }
     }
 }
@@ -49,60 +133,94 @@ enum Commands {
         output_dir: Option<PathBuf>,
         #[arg(long)]
         duration_seconds: Option<u64>,
+        #[arg(long)]
+        device: Option<String>,
     },
     Transcribe {
         file: PathBuf,
-        #[arg(long)]
-        groq_key: Option<String>,
+        #[command(flatten)]
+        transcription: TranscriptionArgs,
         #[arg(long)]
         language: Option<String>,
+        #[arg(long, value_enum, default_value = "text")]
+        format: TranscriptFormat,
+        #[arg(long)]
+        output: Option<PathBuf>,
     },
     Polish {
-        #[arg(long)]
-        llm_key: Option<String>,
-        #[arg(long, value_enum, default_value = "google-ai-studio")]
-        provider: CliLlmProvider,
+        #[command(flatten)]
+        llm: LlmArgs,
         #[arg(long)]
         text: Option<String>,
         #[arg(long)]
         context: Option<String>,
+        #[arg(long)]
+        stream: bool,
     },
     Full {
         #[arg(long)]
         output_dir: Option<PathBuf>,
-        #[arg(long)]
-        groq_key: Option<String>,
-        #[arg(long)]
-        llm_key: Option<String>,
-        #[arg(long, value_enum, default_value = "google-ai-studio")]
-        provider: CliLlmProvider,
+        #[command(flatten)]
+        transcription: TranscriptionArgs,
+        #[command(flatten)]
+        llm: LlmArgs,
         #[arg(long)]
         language: Option<String>,
         #[arg(long)]
         duration_seconds: Option<u64>,
         #[arg(long)]
         context: Option<String>,
+        #[arg(long)]
+        device: Option<String>,
     },
     Diagnose {
         #[command(subcommand)]
         command: DiagnoseCommands,
     },
+    Dict {
+        #[arg(long, global = true)]
+        file: Option<PathBuf>,
+        #[command(subcommand)]
+        command: DictCommands,
+    },
+}
+
+#[derive(Subcommand)]
+enum DictCommands {
+    Add {
+        text: String,
+        #[arg(long = "with")]
+        replacement: Option<String>,
+        #[arg(long, requires = "replacement")]
+        case_sensitive: bool,
+        #[arg(long, requires = "replacement")]
+        regex: bool,
+    },
+    List,
+    Remove {
+        text: String,
+    },
 }
 
 #[derive(Subcommand)]
 enum DiagnoseCommands {
     Env,
+    Devices,
     Audio {
         #[arg(long, default_value_t = 3)]
         duration_seconds: u64,
         #[arg(long)]
         output: Option<PathBuf>,
+        #[arg(long)]
+        device: Option<String>,
+        #[arg(long)]
+        meter: bool,
+        #[arg(long)]
+        armed: bool,
     },
     Llm {
-        #[arg(long)]
-        llm_key: Option<String>,
-        #[arg(long, value_enum, default_value = "google-ai-studio")]
-        provider: CliLlmProvider,
+        #[command(flatten)]
+        llm: LlmArgs,
         #[arg(long)]
         prompt: String,
         #[arg(long)]
@@ -116,75 +234,95 @@ enum DiagnoseCommands {
         file: PathBuf,
         #[arg(long)]
         output_dir: Option<PathBuf>,
-        #[arg(long)]
-        groq_key: Option<String>,
-        #[arg(long)]
-        llm_key: Option<String>,
-        #[arg(long, value_enum, default_value = "google-ai-studio")]
-        provider: CliLlmProvider,
+        #[command(flatten)]
+        transcription: TranscriptionArgs,
+        #[command(flatten)]
+        llm: LlmArgs,
         #[arg(long)]
         language: Option<String>,
         #[arg(long)]
         transcribe_only: bool,
         #[arg(long)]
         context: Option<String>,
+        #[arg(long, value_enum, default_value = "text")]
+        format: TranscriptFormat,
+        #[arg(long)]
+        output: Option<PathBuf>,
     },
 }
 
 fn main() -> Result<()> {
     dotenvy::dotenv().ok();
     let cli = Cli::parse();
+    if !matches!(cli.command, Commands::Dict { .. }) {
+        install_saved_dictionary()?;
+    }
 
     match cli.command {
         Commands::Record {
             output_dir,
             duration_seconds,
-        } => cmd_record(output_dir, duration_seconds),
+            device,
+        } => cmd_record(output_dir, duration_seconds, device),
         Commands::Transcribe {
             file,
-            groq_key,
+            transcription,
+            language,
+            format,
+            output,
+        } => cmd_transcribe(
+            file,
+            transcription.provider()?,
+            transcription.transcription_key,
             language,
-        } => cmd_transcribe(file, groq_key, language),
+            format,
+            output,
+        ),
         Commands::Polish {
-            llm_key,
-            provider,
+            llm,
             text,
             context,
-        } => cmd_polish(provider.into(), llm_key, text, context),
+            stream,
+        } => cmd_polish(llm.provider()?, llm.llm_key, text, context, stream),
         Commands::Full {
             output_dir,
-            groq_key,
-            llm_key,
-            provider,
+            transcription,
+            llm,
             language,
             duration_seconds,
             context,
+            device,
         } => cmd_full(
             output_dir,
-            groq_key,
-            provider.into(),
-            llm_key,
+            transcription.provider()?,
+            transcription.transcription_key,
+            llm.provider()?,
+            llm.llm_key,
             language,
             duration_seconds,
             context,
+            device,
         ),
         Commands::Diagnose { command } => match command {
             DiagnoseCommands::Env => run_diagnose_env(),
+            DiagnoseCommands::Devices => run_diagnose_devices(),
             DiagnoseCommands::Audio {
                 duration_seconds,
                 output,
-            } => run_diagnose_audio(duration_seconds, output),
+                device,
+                meter,
+                armed,
+            } => run_diagnose_audio(duration_seconds, output, device, meter, armed),
             DiagnoseCommands::Llm {
-                llm_key,
-                provider,
+                llm,
                 prompt,
                 system_instruction,
                 temperature,
                 cancel_immediately,
             } => run_diagnose_llm(
                 prompt,
-                provider.into(),
-                llm_key,
+                llm.provider()?,
+                llm.llm_key,
                 system_instruction,
                 temperature,
                 cancel_immediately,
@@ -192,67 +330,95 @@ fn main() -> Result<()> {
             DiagnoseCommands::Pipeline {
                 file,
                 output_dir,
-                groq_key,
-                llm_key,
-                provider,
+                transcription,
+                llm,
                 language,
                 transcribe_only,
                 context,
+                format,
+                output,
             } => run_diagnose_pipeline(
                 file,
                 output_dir,
-                groq_key,
-                provider.into(),
-                llm_key,
+                transcription.provider()?,
+                transcription.transcription_key,
+                llm.provider()?,
+                llm.llm_key,
                 language,
                 transcribe_only,
                 context,
+                format,
+                output,
             ),
         },
+        Commands::Dict { file, command } => match command {
+            DictCommands::Add {
+                text,
+                replacement,
+                case_sensitive,
+                regex,
+            } => run_dict_add(file, text, replacement, case_sensitive, regex),
+            DictCommands::List => run_dict_list(file),
+            DictCommands::Remove { text } => run_dict_remove(file, text),
+        },
     }
 }
 
-fn cmd_record(output_dir: Option<PathBuf>, duration_seconds: Option<u64>) -> Result<()> {
+fn cmd_record(
+    output_dir: Option<PathBuf>,
+    duration_seconds: Option<u64>,
+    device: Option<String>,
+) -> Result<()> {
     let output_dir = resolve_output_dir(output_dir)?;
     fs::create_dir_all(&output_dir)?;
 
     if let Some(duration) = duration_seconds {
-        start_recording().context("Failed to start recording")?;
+        start_recording_on(device.as_deref())?;
         println!("Recording for {duration}s (auto-start)...");
         sleep(Duration::from_secs(duration));
     } else {
         println!("Press Enter to start recording...");
         wait_for_enter()?;
-        start_recording().context("Failed to start recording")?;
-        println!("Recording... Press Enter to stop.");
-        wait_for_enter()?;
+        start_recording_on(device.as_deref())?;
+        wait_for_stop_with_pause()?;
     }
 
     let audio_data = stop_recording().context("Failed to stop recording")?;
     let flac_path = output_dir.join(format!("recording_{}.flac", timestamp()));
-    fs::write(&flac_path, audio_data.bytes)?;
+    fs::write(&flac_path, &audio_data.bytes)?;
 
     println!(
         "Saved FLAC to {} (duration {:.2}s)",
         flac_path.display(),
         audio_data.duration_seconds
     );
+    for warning in recording_warnings(&audio_data) {
+        eprintln!("Warning: {warning}");
+    }
 
     Ok(())
 }
 
-fn cmd_transcribe(file: PathBuf, groq_key: Option<String>, language: Option<String>) -> Result<()> {
+fn cmd_transcribe(
+    file: PathBuf,
+    transcription_provider: TranscriptionProvider,
+    transcription_key: Option<String>,
+    language: Option<String>,
+    format: TranscriptFormat,
+    output: Option<PathBuf>,
+) -> Result<()> {
     let audio_bytes = fs::read(&file).context("Failed to read audio file")?;
-    ensure_flac_bytes(&audio_bytes, &file)?;
-    let api_key = resolve_groq_key(groq_key)?;
+    let audio_bytes = prepare_input_audio(audio_bytes, &file)?;
+    let api_key = resolve_transcription_key(&transcription_provider, transcription_key)?;
     use secrecy::ExposeSecret;
-    let text = diy_typeless_core::transcribe_audio_bytes(
+    let transcript = diy_typeless_core::transcribe_audio_detailed(
+        transcription_provider,
         api_key.expose_secret().to_string(),
         audio_bytes,
         language,
+        false,
     )?;
-    println!("{text}");
-    Ok(())
+    write_output(&render_transcript(&transcript, format), output.as_deref())
 }
 
 fn cmd_polish(
@@ -260,20 +426,35 @@ fn cmd_polish(
     llm_key: Option<String>,
     text: Option<String>,
     context: Option<String>,
+    stream: bool,
 ) -> Result<()> {
-    let api_key = resolve_llm_key(provider, llm_key)?;
+    let api_key = resolve_llm_key(&provider, llm_key)?;
     let raw_text = match text {
         Some(text) => text,
         None => read_stdin()?,
     };
     use secrecy::ExposeSecret;
-    let polished = diy_typeless_core::polish_text(
-        provider,
-        api_key.expose_secret().to_string(),
-        raw_text,
-        context,
-    )?;
-    println!("{polished}");
+    let polished = if stream {
+        let polished = diy_typeless_core::polish_text_streaming(
+            provider,
+            api_key.expose_secret().to_string(),
+            raw_text,
+            context,
+            Arc::new(StdoutStreamListener),
+            CancellationToken::new(),
+        )?;
+        println!();
+        polished
+    } else {
+        let polished = diy_typeless_core::polish_text(
+            provider,
+            api_key.expose_secret().to_string(),
+            raw_text,
+            context,
+        )?;
+        println!("{polished}");
+        polished
+    };
     copy_to_clipboard(&polished);
     Ok(())
 }
@@ -281,81 +462,79 @@ fn cmd_polish(
 #[allow(clippy::too_many_arguments)]
 fn cmd_full(
     output_dir: Option<PathBuf>,
-    groq_key: Option<String>,
+    transcription_provider: TranscriptionProvider,
+    transcription_key: Option<String>,
     provider: LlmProvider,
     llm_key: Option<String>,
     language: Option<String>,
     duration_seconds: Option<u64>,
     context: Option<String>,
+    device: Option<String>,
 ) -> Result<()> {
-    let llm_key = resolve_llm_key(provider, llm_key)?;
+    let transcription_key = resolve_transcription_key(&transcription_provider, transcription_key)?;
+    let llm_key = resolve_llm_key(&provider, llm_key)?;
     let output_dir = resolve_output_dir(output_dir)?;
     fs::create_dir_all(&output_dir)?;
 
-    let raw_text = run_groq_full(&output_dir, duration_seconds, groq_key, language)?;
+    let audio_bytes = record_audio(duration_seconds, device.as_deref())?;
+    let base = format!("recording_{}", timestamp());
+    let flac_path = output_dir.join(format!("{base}.flac"));
+    fs::write(&flac_path, &audio_bytes)?;
 
-    println!("Polishing...");
+    println!("Transcribing and polishing...");
     use secrecy::ExposeSecret;
-    let polished_text = diy_typeless_core::polish_text(
-        provider,
-        llm_key.expose_secret().to_string(),
-        raw_text,
+    let options = PipelineOptions {
+        transcription_provider,
+        transcription_api_key: transcription_key.expose_secret().to_string(),
+        language,
+        llm_provider: provider,
+        llm_api_key: llm_key.expose_secret().to_string(),
         context,
-    )?;
+        skip_polish: false,
+    };
+    let result = run_pipeline(audio_bytes, options, CancellationToken::new())?;
 
-    let polished_path = output_dir.join(format!("recording_{}_polished.txt", timestamp()));
-    fs::write(&polished_path, &polished_text)?;
+    let raw_path = output_dir.join(format!("{base}_raw.txt"));
+    fs::write(&raw_path, &result.raw_text)?;
+    let polished_path = output_dir.join(format!("{base}_polished.txt"));
+    fs::write(&polished_path, &result.polished_text)?;
 
-    println!("Polished text:\n{}", polished_text);
-    copy_to_clipboard(&polished_text);
+    if let Some(error) = &result.polish_error {
+        eprintln!("Polish failed, using raw transcript: {error}");
+    }
+    println!("Polished text:\n{}", result.polished_text);
+    copy_to_clipboard(&result.polished_text);
 
     println!("Saved: {}", polished_path.display());
 
     Ok(())
 }
 
-fn run_groq_full(
-    output_dir: &std::path::Path,
-    duration_seconds: Option<u64>,
-    groq_key: Option<String>,
-    language: Option<String>,
-) -> Result<String> {
-    let groq_key = resolve_groq_key(groq_key)?;
-
+fn record_audio(duration_seconds: Option<u64>, device: Option<&str>) -> Result<Vec<u8>> {
     if let Some(duration) = duration_seconds {
-        start_recording().context("Failed to start recording")?;
+        start_recording_on(device)?;
         println!("Recording for {duration}s (auto-start)...");
         sleep(Duration::from_secs(duration));
     } else {
         println!("Press Enter to start recording...");
         wait_for_enter()?;
-        start_recording().context("Failed to start recording")?;
-        println!("Recording... Press Enter to stop.");
-        wait_for_enter()?;
+        start_recording_on(device)?;
+        wait_for_stop_with_pause()?;
     }
 
     let audio_data = stop_recording().context("Failed to stop recording")?;
-    let base = format!("recording_{}", timestamp());
-    let flac_path = output_dir.join(format!("{base}.flac"));
-    fs::write(&flac_path, &audio_data.bytes)?;
-
-    println!("Transcribing with Groq API...");
-    use secrecy::ExposeSecret;
-    let text = diy_typeless_core::transcribe_audio_bytes(
-        groq_key.expose_secret().to_string(),
-        audio_data.bytes,
-        language,
-    )?;
-    let raw_path = output_dir.join(format!("{base}_raw.txt"));
-    fs::write(&raw_path, &text)?;
-
-    Ok(text)
+    for warning in recording_warnings(&audio_data) {
+        eprintln!("Warning: {warning}");
+    }
+    Ok(audio_data.bytes)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{Cli, CliLlmProvider, Commands};
+    use super::{Cli, CliLlmProvider, Commands, DiagnoseCommands, DictCommands, TranscriptFormat};
     use clap::Parser;
+    use diy_typeless_core::{LlmProvider, TranscriptionProvider};
+    use std::path::PathBuf;
 
     #[test]
     fn polish_command_should_accept_openai_provider() {
@@ -370,11 +549,125 @@ mod tests {
         .expect("cli should parse");
 
         match cli.command {
-            Commands::Polish { provider, .. } => assert_eq!(provider, CliLlmProvider::Openai),
+            Commands::Polish { llm, .. } => assert_eq!(llm.provider, CliLlmProvider::Openai),
+            _ => panic!("expected polish command"),
+        }
+    }
+
+    #[test]
+    fn polish_command_should_accept_anthropic_provider() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "polish",
+            "--provider",
+            "anthropic",
+            "--text",
+            "hello",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Polish { llm, .. } => {
+                assert_eq!(llm.provider, CliLlmProvider::Anthropic);
+                assert_eq!(
+                    llm.provider().expect("provider should resolve"),
+                    LlmProvider::Anthropic
+                );
+            }
+            _ => panic!("expected polish command"),
+        }
+    }
+
+    #[test]
+    fn polish_command_should_build_openai_compatible_provider() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "polish",
+            "--provider",
+            "openai-compatible",
+            "--llm-base-url",
+            "http://localhost:11434/v1",
+            "--llm-model",
+            "llama3.2",
+            "--text",
+            "hello",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Polish { llm, .. } => assert_eq!(
+                llm.provider().expect("provider should resolve"),
+                LlmProvider::OpenAiCompatible {
+                    base_url: "http://localhost:11434/v1".to_string(),
+                    model: "llama3.2".to_string(),
+                }
+            ),
+            _ => panic!("expected polish command"),
+        }
+    }
+
+    #[test]
+    fn openai_compatible_llm_should_require_model() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "polish",
+            "--provider",
+            "openai-compatible",
+            "--llm-base-url",
+            "http://localhost:11434/v1",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Polish { llm, .. } => assert!(llm
+                .provider()
+                .expect_err("missing model should fail")
+                .to_string()
+                .contains("--llm-model is required")),
             _ => panic!("expected polish command"),
         }
     }
 
+    #[test]
+    fn llm_base_url_should_be_rejected_for_hosted_providers() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "polish",
+            "--provider",
+            "openai",
+            "--llm-base-url",
+            "http://localhost:11434/v1",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Polish { llm, .. } => assert!(llm.provider().is_err()),
+            _ => panic!("expected polish command"),
+        }
+    }
+
+    #[test]
+    fn polish_command_should_accept_stream_flag() {
+        let cli = Cli::try_parse_from(["diy-typeless", "polish", "--stream", "--text", "hello"])
+            .expect("cli should parse");
+
+        match cli.command {
+            Commands::Polish { stream, .. } => assert!(stream),
+            _ => panic!("expected polish command"),
+        }
+    }
+
+    #[test]
+    fn record_command_should_accept_device() {
+        let cli = Cli::try_parse_from(["diy-typeless", "record", "--device", "USB Headset"])
+            .expect("cli should parse");
+
+        match cli.command {
+            Commands::Record { device, .. } => assert_eq!(device.as_deref(), Some("USB Headset")),
+            _ => panic!("expected record command"),
+        }
+    }
+
     #[test]
     fn full_command_should_accept_openai_provider() {
         let cli = Cli::try_parse_from([
@@ -388,8 +681,213 @@ mod tests {
         .expect("cli should parse");
 
         match cli.command {
-            Commands::Full { provider, .. } => assert_eq!(provider, CliLlmProvider::Openai),
+            Commands::Full { llm, .. } => assert_eq!(llm.provider, CliLlmProvider::Openai),
             _ => panic!("expected full command"),
         }
     }
+
+    #[test]
+    fn transcribe_command_should_default_to_groq_and_accept_legacy_key_flag() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "transcribe",
+            "audio.flac",
+            "--groq-key",
+            "gsk-test",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Transcribe { transcription, .. } => {
+                assert_eq!(
+                    transcription.provider().expect("provider should resolve"),
+                    TranscriptionProvider::Groq
+                );
+                assert_eq!(transcription.transcription_key.as_deref(), Some("gsk-test"));
+            }
+            _ => panic!("expected transcribe command"),
+        }
+    }
+
+    #[test]
+    fn transcribe_command_should_default_to_text_on_stdout() {
+        let cli = Cli::try_parse_from(["diy-typeless", "transcribe", "audio.flac"])
+            .expect("cli should parse");
+
+        match cli.command {
+            Commands::Transcribe { format, output, .. } => {
+                assert_eq!(format, TranscriptFormat::Text);
+                assert_eq!(output, None);
+            }
+            _ => panic!("expected transcribe command"),
+        }
+    }
+
+    #[test]
+    fn transcribe_command_should_accept_subtitle_format_and_output() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "transcribe",
+            "audio.flac",
+            "--format",
+            "srt",
+            "--output",
+            "meeting.srt",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Transcribe { format, output, .. } => {
+                assert_eq!(format, TranscriptFormat::Srt);
+                assert_eq!(output, Some(PathBuf::from("meeting.srt")));
+            }
+            _ => panic!("expected transcribe command"),
+        }
+    }
+
+    #[test]
+    fn diagnose_pipeline_should_accept_vtt_format() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "diagnose",
+            "pipeline",
+            "audio.flac",
+            "--format",
+            "vtt",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Diagnose {
+                command: DiagnoseCommands::Pipeline { format, .. },
+            } => assert_eq!(format, TranscriptFormat::Vtt),
+            _ => panic!("expected diagnose pipeline command"),
+        }
+    }
+
+    #[test]
+    fn diagnose_audio_should_accept_meter_and_armed_flags() {
+        let cli = Cli::try_parse_from(["diy-typeless", "diagnose", "audio", "--meter", "--armed"])
+            .expect("cli should parse");
+
+        match cli.command {
+            Commands::Diagnose {
+                command: DiagnoseCommands::Audio { meter, armed, .. },
+            } => assert!(meter && armed),
+            _ => panic!("expected diagnose audio command"),
+        }
+    }
+
+    #[test]
+    fn dict_add_should_accept_replacement_with_flags() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "dict",
+            "add",
+            "cooper netties",
+            "--with",
+            "Kubernetes",
+            "--case-sensitive",
+            "--file",
+            "words.json",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Dict {
+                file,
+                command:
+                    DictCommands::Add {
+                        text,
+                        replacement,
+                        case_sensitive,
+                        regex,
+                    },
+            } => {
+                assert_eq!(file, Some(PathBuf::from("words.json")));
+                assert_eq!(text, "cooper netties");
+                assert_eq!(replacement.as_deref(), Some("Kubernetes"));
+                assert!(case_sensitive);
+                assert!(!regex);
+            }
+            _ => panic!("expected dict add command"),
+        }
+    }
+
+    #[test]
+    fn dict_add_should_reject_regex_flag_without_replacement() {
+        let result = Cli::try_parse_from(["diy-typeless", "dict", "add", "Anya", "--regex"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn transcribe_command_should_build_openai_compatible_provider() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "transcribe",
+            "audio.flac",
+            "--transcription-provider",
+            "openai-compatible",
+            "--transcription-base-url",
+            "http://localhost:8000/v1",
+            "--transcription-model",
+            "Systran/faster-whisper-small",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Transcribe { transcription, .. } => assert_eq!(
+                transcription.provider().expect("provider should resolve"),
+                TranscriptionProvider::OpenAiCompatible {
+                    base_url: "http://localhost:8000/v1".to_string(),
+                    model: "Systran/faster-whisper-small".to_string(),
+                }
+            ),
+            _ => panic!("expected transcribe command"),
+        }
+    }
+
+    #[test]
+    fn openai_compatible_provider_should_require_base_url() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "transcribe",
+            "audio.flac",
+            "--transcription-provider",
+            "openai-compatible",
+            "--transcription-model",
+            "whisper",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Transcribe { transcription, .. } => assert!(transcription
+                .provider()
+                .expect_err("missing base url should fail")
+                .to_string()
+                .contains("--transcription-base-url is required")),
+            _ => panic!("expected transcribe command"),
+        }
+    }
+
+    #[test]
+    fn transcription_base_url_should_be_rejected_for_hosted_providers() {
+        let cli = Cli::try_parse_from([
+            "diy-typeless",
+            "transcribe",
+            "audio.flac",
+            "--transcription-provider",
+            "openai",
+            "--transcription-base-url",
+            "http://localhost:8000/v1",
+        ])
+        .expect("cli should parse");
+
+        match cli.command {
+            Commands::Transcribe { transcription, .. } => {
+                assert!(transcription.provider().is_err());
+            }
+            _ => panic!("expected transcribe command"),
+        }
+    }
 }
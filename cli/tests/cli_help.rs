@@ -25,6 +25,7 @@ fn cli_should_print_diagnose_help() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("env"));
     assert!(stdout.contains("audio"));
+    assert!(stdout.contains("devices"));
     assert!(stdout.contains("pipeline"));
 }
 
@@ -1,8 +1,18 @@
-use crate::config::{HIGHPASS_FREQ_HZ, TARGET_RMS_DB, WHISPER_CHANNELS, WHISPER_SAMPLE_RATE};
+use crate::config::{
+    HIGHPASS_FREQ_HZ, METER_CLIP_THRESHOLD, RESAMPLER_CUTOFF, RESAMPLER_KAISER_BETA,
+    RESAMPLER_MAX_PHASES, RESAMPLER_ZERO_CROSSINGS, TARGET_RMS_DB, VAD_DEFAULT_PADDING_MS,
+    VAD_FRAME_MS, VAD_MIN_SPEECH_DB, VAD_MIN_SPEECH_FRAMES, VAD_THRESHOLD_ABOVE_FLOOR_DB,
+    WHISPER_CHANNELS, WHISPER_SAMPLE_RATE,
+};
+use crate::denoise::{suppress_noise, NoiseProfile};
 use crate::error::CoreError;
+use crate::limiter::soft_limit;
+use crate::meter::{amplitude_to_db, LevelMeter};
 use biquad::{Biquad, Coefficients, DirectForm1, ToHertz, Type, Q_BUTTERWORTH_F32};
 use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
-use std::sync::{Arc, LazyLock, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::SyncSender;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 #[derive(Debug, uniffi::Record)]
 #[must_use]
@@ -10,46 +20,334 @@ use std::sync::{Arc, LazyLock, Mutex};
 pub struct AudioData {
     /// Encoded audio bytes in FLAC format.
     pub bytes: Vec<u8>,
-    /// Approximate capture duration in seconds before post-processing.
+    /// Approximate capture duration in seconds before post-processing, excluding
+    /// paused intervals.
     pub duration_seconds: f32,
+    /// Duration in seconds of the encoded audio after silence trimming.
+    pub trimmed_duration_seconds: f32,
+    /// Seconds of audio captured before recording started, from an armed microphone.
+    pub pre_roll_seconds: f32,
+    /// Whether capture stopped early because the recording reached its duration or
+    /// memory limit; audio after that point is missing.
+    pub truncated: bool,
+    /// Clipping in the captured audio and during post-processing.
+    pub clip_stats: ClipStats,
 }
 
-struct RecordingState {
-    is_recording: bool,
-    stream: Option<cpal::Stream>,
-    samples: Arc<Mutex<Vec<f32>>>,
-    sample_rate: u32,
-    channels: u16,
+#[derive(Clone, Debug, Default, PartialEq, uniffi::Record)]
+/// Clipping measured while preparing audio for transcription.
+///
+/// Input clipping happened at the microphone or its gain stage and cannot be undone;
+/// a noticeable `input_clipped_ratio` usually means the speaker is too close to the
+/// microphone or the input gain is too high.
+pub struct ClipStats {
+    /// Captured samples at or near full scale, before any processing.
+    pub input_clipped_samples: u64,
+    /// Share of captured samples that clipped, from 0.0 to 1.0.
+    pub input_clipped_ratio: f32,
+    /// Highest captured level in dBFS, floored at -100 dB for silence.
+    pub input_peak_db: f32,
+    /// Samples that normalization gain pushed past full scale; the limiter kept them
+    /// from clipping.
+    pub processing_clipped_samples: u64,
+    /// Samples turned down by the limiter.
+    pub limited_samples: u64,
+    /// Largest gain reduction applied by the limiter in dB; 0 when it stayed idle.
+    pub max_gain_reduction_db: f32,
+}
+
+#[derive(Clone, Debug, PartialEq, uniffi::Record)]
+/// Post-processing options applied when a recording is stopped.
+pub struct AudioProcessingOptions {
+    /// Trim leading and trailing silence before encoding.
+    #[uniffi(default = true)]
+    pub trim_silence: bool,
+    /// Silence kept around detected speech, in milliseconds.
+    #[uniffi(default = 250)]
+    pub silence_padding_ms: u32,
+    /// Resample with linear interpolation instead of the band-limited sinc filter.
+    ///
+    /// Faster, but lets noise above 8 kHz alias into the speech band.
+    #[uniffi(default = false)]
+    pub fast_resampling: bool,
+    /// Reduce steady background noise such as fans or café chatter before gain is
+    /// applied, using a noise profile taken from the quietest parts of the recording.
+    #[uniffi(default = false)]
+    pub noise_suppression: bool,
 }
 
-impl RecordingState {
-    fn new() -> Self {
+impl Default for AudioProcessingOptions {
+    fn default() -> Self {
         Self {
-            is_recording: false,
-            stream: None,
-            samples: Arc::new(Mutex::new(Vec::new())),
-            sample_rate: WHISPER_SAMPLE_RATE,
-            channels: WHISPER_CHANNELS,
+            trim_silence: true,
+            silence_padding_ms: VAD_DEFAULT_PADDING_MS,
+            fast_resampling: false,
+            noise_suppression: false,
         }
     }
 }
 
-static RECORDING_STATE: LazyLock<Mutex<RecordingState>> =
-    LazyLock::new(|| Mutex::new(RecordingState::new()));
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+/// Input device description used for device selection.
+pub struct InputDeviceInfo {
+    /// Stable identifier accepted by `start_recording_with_device`.
+    pub id: String,
+    /// Human-readable device name.
+    pub name: String,
+    /// Whether this is the current system default input device.
+    pub is_default: bool,
+    /// Sample rate of the default input configuration in Hz.
+    pub default_sample_rate: u32,
+    /// Channel count of the default input configuration.
+    pub default_channels: u16,
+    /// Sample format of the default input configuration (e.g. `f32`).
+    pub default_sample_format: String,
+    /// Sample formats supported by any input configuration.
+    pub supported_sample_formats: Vec<String>,
+    /// Common sample rates (Hz) covered by the supported input configurations.
+    pub supported_sample_rates: Vec<u32>,
+}
+
+/// Common sample rates probed against the supported configuration ranges.
+const COMMON_SAMPLE_RATES: [u32; 9] = [
+    8_000, 16_000, 22_050, 24_000, 32_000, 44_100, 48_000, 88_200, 96_000,
+];
+
+/// Sample buffer shared between a recording session and its capture callback.
+pub(crate) struct CaptureBuffer {
+    samples: Mutex<Vec<f32>>,
+    paused: AtomicBool,
+    /// While armed, only the most recent this-many samples are needed; 0 keeps all.
+    pre_roll_limit: AtomicUsize,
+    /// Recording stops buffering at this many samples; 0 means unlimited.
+    max_samples: AtomicUsize,
+    /// Pre-roll samples at the front of the buffer, which `max_samples` does not count.
+    pre_roll_kept: AtomicUsize,
+    limit_reached: AtomicBool,
+    /// Kept recording samples at or above `METER_CLIP_THRESHOLD`, including the pre-roll
+    /// once recording begins.
+    clipped_samples: AtomicU64,
+    /// Woken once when `max_samples` is reached.
+    limit_signal: Mutex<Option<SyncSender<()>>>,
+    meter: LevelMeter,
+}
+
+impl CaptureBuffer {
+    pub(crate) fn new() -> Self {
+        Self {
+            samples: Mutex::new(Vec::new()),
+            paused: AtomicBool::new(false),
+            pre_roll_limit: AtomicUsize::new(0),
+            max_samples: AtomicUsize::new(0),
+            pre_roll_kept: AtomicUsize::new(0),
+            limit_reached: AtomicBool::new(false),
+            clipped_samples: AtomicU64::new(0),
+            limit_signal: Mutex::new(None),
+            meter: LevelMeter::new(),
+        }
+    }
+
+    /// While paused, the callback drops incoming audio instead of buffering it.
+    pub(crate) fn set_paused(&self, paused: bool) {
+        self.paused.store(paused, Ordering::Relaxed);
+    }
+
+    pub(crate) fn meter(&self) -> &LevelMeter {
+        &self.meter
+    }
+
+    fn lock_samples(&self) -> Result<MutexGuard<'_, Vec<f32>>, CoreError> {
+        self.samples
+            .lock()
+            .map_err(|_| CoreError::AudioCapture("Sample lock poisoned".to_string()))
+    }
+
+    /// Take all captured samples, then keep at most `pre_roll_limit` samples from now
+    /// on (0 keeps everything). Clears any recording limit.
+    pub(crate) fn reset(&self, pre_roll_limit: usize) -> Result<Vec<f32>, CoreError> {
+        self.take_recording(pre_roll_limit)
+            .map(|(samples, _)| samples)
+    }
+
+    /// [`reset`](Self::reset), also returning how many of the taken samples clipped.
+    pub(crate) fn take_recording(
+        &self,
+        pre_roll_limit: usize,
+    ) -> Result<(Vec<f32>, u64), CoreError> {
+        let mut samples = self.lock_samples()?;
+        self.pre_roll_limit.store(pre_roll_limit, Ordering::Relaxed);
+        self.pre_roll_kept.store(0, Ordering::Relaxed);
+        self.set_limit(0, None);
+        let clipped = self.clipped_samples.swap(0, Ordering::Relaxed);
+        Ok((std::mem::take(&mut *samples), clipped))
+    }
 
-pub(crate) fn start_recording() -> Result<(), CoreError> {
-    let mut state = RECORDING_STATE
-        .lock()
-        .map_err(|_| CoreError::AudioCapture("Recording lock poisoned".to_string()))?;
+    /// Stop buffering once the recording holds `max_samples` samples after its pre-roll
+    /// (0 disables the limit) and send one message on `signal` when that happens.
+    pub(crate) fn set_limit(&self, max_samples: usize, signal: Option<SyncSender<()>>) {
+        if let Ok(mut current) = self.limit_signal.lock() {
+            *current = signal;
+        }
+        self.max_samples.store(max_samples, Ordering::Relaxed);
+        self.limit_reached.store(false, Ordering::Relaxed);
+    }
 
-    if state.is_recording {
-        return Err(CoreError::RecordingAlreadyActive);
+    /// Whether the recording hit its sample limit and dropped later audio.
+    pub(crate) fn limit_reached(&self) -> bool {
+        self.limit_reached.load(Ordering::Relaxed)
     }
 
+    fn accepts_samples(&self) -> bool {
+        !self.paused.load(Ordering::Relaxed) && !self.limit_reached()
+    }
+
+    /// Start keeping every sample, beginning with the buffered pre-roll.
+    ///
+    /// Returns the number of pre-roll samples kept.
+    pub(crate) fn begin_from_pre_roll(&self) -> Result<usize, CoreError> {
+        let mut samples = self.lock_samples()?;
+        let limit = self.pre_roll_limit.swap(0, Ordering::Relaxed);
+        if limit > 0 && samples.len() > limit {
+            let excess = samples.len() - limit;
+            samples.drain(..excess);
+        }
+        self.pre_roll_kept.store(samples.len(), Ordering::Relaxed);
+        self.clipped_samples
+            .store(count_clipped(&samples) as u64, Ordering::Relaxed);
+        Ok(samples.len())
+    }
+
+    /// Update levels and clip counts and apply the pre-roll and recording limits after
+    /// the callback appended `buffer[start..]`.
+    fn after_capture(&self, buffer: &mut Vec<f32>, start: usize) {
+        self.meter.record(&buffer[start..]);
+        let limit = self.pre_roll_limit.load(Ordering::Relaxed);
+        if limit > 0 {
+            // Let the pre-roll grow to twice its length before trimming, so the front
+            // of the buffer is shifted once per pre-roll length rather than on every
+            // callback. Its clipping is counted by `begin_from_pre_roll`, once it is
+            // known which samples are kept.
+            if buffer.len() >= 2 * limit {
+                let excess = buffer.len() - limit;
+                buffer.drain(..excess);
+            }
+            return;
+        }
+
+        let max_samples = self.max_samples.load(Ordering::Relaxed);
+        let pre_roll_kept = self.pre_roll_kept.load(Ordering::Relaxed);
+        let reached = max_samples > 0 && buffer.len().saturating_sub(pre_roll_kept) >= max_samples;
+        if reached {
+            buffer.truncate(pre_roll_kept + max_samples);
+        }
+        // Count after truncating so audio dropped at the limit is not reported.
+        let kept = &buffer[start.min(buffer.len())..];
+        self.clipped_samples
+            .fetch_add(count_clipped(kept) as u64, Ordering::Relaxed);
+
+        if reached {
+            self.limit_reached.store(true, Ordering::Relaxed);
+            // `try_lock` keeps the audio thread from ever waiting on the session.
+            if let Ok(signal) = self.limit_signal.try_lock() {
+                if let Some(signal) = signal.as_ref() {
+                    let _ = signal.try_send(());
+                }
+            }
+        }
+    }
+}
+
+/// Identifier used to select a device; falls back to the name when the host has no stable IDs.
+fn device_identifier(device: &cpal::Device) -> String {
+    match device.id() {
+        Ok(id) => id.to_string(),
+        Err(_) => device_name(device),
+    }
+}
+
+fn device_name(device: &cpal::Device) -> String {
+    device
+        .description()
+        .map(|description| description.name().to_string())
+        .unwrap_or_else(|_| "Unknown device".to_string())
+}
+
+fn sample_rates_within(ranges: &[(u32, u32)]) -> Vec<u32> {
+    COMMON_SAMPLE_RATES
+        .into_iter()
+        .filter(|rate| ranges.iter().any(|(min, max)| (*min..=*max).contains(rate)))
+        .collect()
+}
+
+fn describe_input_device(
+    device: &cpal::Device,
+    default_id: Option<&str>,
+) -> Option<InputDeviceInfo> {
+    let default_config = device.default_input_config().ok()?;
+    let mut formats = Vec::new();
+    let mut ranges = Vec::new();
+    if let Ok(configs) = device.supported_input_configs() {
+        for config in configs {
+            let format = config.sample_format().to_string();
+            if !formats.contains(&format) {
+                formats.push(format);
+            }
+            ranges.push((config.min_sample_rate(), config.max_sample_rate()));
+        }
+    }
+
+    let id = device_identifier(device);
+    Some(InputDeviceInfo {
+        is_default: default_id == Some(id.as_str()),
+        id,
+        name: device_name(device),
+        default_sample_rate: default_config.sample_rate(),
+        default_channels: default_config.channels(),
+        default_sample_format: default_config.sample_format().to_string(),
+        supported_sample_formats: formats,
+        supported_sample_rates: sample_rates_within(&ranges),
+    })
+}
+
+pub(crate) fn list_input_devices() -> Result<Vec<InputDeviceInfo>, CoreError> {
     let host = cpal::default_host();
-    let device = host
+    let default_id = host
         .default_input_device()
-        .ok_or(CoreError::AudioDeviceUnavailable)?;
+        .map(|device| device_identifier(&device));
+    let devices = host
+        .input_devices()
+        .map_err(|e| CoreError::AudioCapture(e.to_string()))?;
+
+    Ok(devices
+        .filter_map(|device| describe_input_device(&device, default_id.as_deref()))
+        .collect())
+}
+
+/// Resolve an input device by identifier or exact name, or the system default when `None`.
+fn resolve_input_device(device_id: Option<&str>) -> Result<cpal::Device, CoreError> {
+    let host = cpal::default_host();
+    let Some(requested) = device_id else {
+        return host
+            .default_input_device()
+            .ok_or(CoreError::AudioDeviceUnavailable);
+    };
+
+    let mut devices = host
+        .input_devices()
+        .map_err(|e| CoreError::AudioCapture(e.to_string()))?;
+    devices
+        .find(|device| device_identifier(device) == requested || device_name(device) == requested)
+        .ok_or_else(|| CoreError::AudioDeviceNotFound(requested.to_string()))
+}
+
+/// Open and start an input stream that feeds `capture`.
+///
+/// Returns the running stream, which stops capturing when dropped, and its sample rate.
+pub(crate) fn open_input_stream(
+    device_id: Option<&str>,
+    capture: &Arc<CaptureBuffer>,
+) -> Result<(cpal::Stream, u32), CoreError> {
+    let device = resolve_input_device(device_id)?;
     let supported_config = device
         .default_input_config()
         .map_err(|e| CoreError::AudioCapture(e.to_string()))?;
@@ -60,27 +358,26 @@ pub(crate) fn start_recording() -> Result<(), CoreError> {
     let sample_rate = config.sample_rate;
     let channels = config.channels;
 
-    let samples = Arc::new(Mutex::new(Vec::new()));
-    let samples_for_stream = samples.clone();
+    let capture_for_stream = capture.clone();
 
     let err_fn = |err| log::error!("Audio stream error: {err}");
 
     let stream = match sample_format {
         cpal::SampleFormat::F32 => device.build_input_stream(
             &config,
-            move |data: &[f32], _| capture_f32(data, channels, &samples_for_stream),
+            move |data: &[f32], _| capture_f32(data, channels, &capture_for_stream),
             err_fn,
             None,
         ),
         cpal::SampleFormat::I16 => device.build_input_stream(
             &config,
-            move |data: &[i16], _| capture_i16(data, channels, &samples_for_stream),
+            move |data: &[i16], _| capture_i16(data, channels, &capture_for_stream),
             err_fn,
             None,
         ),
         cpal::SampleFormat::U16 => device.build_input_stream(
             &config,
-            move |data: &[u16], _| capture_u16(data, channels, &samples_for_stream),
+            move |data: &[u16], _| capture_u16(data, channels, &capture_for_stream),
             err_fn,
             None,
         ),
@@ -88,69 +385,94 @@ pub(crate) fn start_recording() -> Result<(), CoreError> {
     }
     .map_err(|e| CoreError::AudioCapture(e.to_string()))?;
 
+    capture.meter.start(sample_rate);
     stream
         .play()
         .map_err(|e| CoreError::AudioCapture(e.to_string()))?;
 
-    state.is_recording = true;
-    state.stream = Some(stream);
-    state.samples = samples;
-    state.sample_rate = sample_rate;
-    state.channels = channels;
-
-    Ok(())
+    Ok((stream, sample_rate))
 }
 
-pub(crate) fn stop_recording() -> Result<AudioData, CoreError> {
-    let mut state = RECORDING_STATE
-        .lock()
-        .map_err(|_| CoreError::AudioCapture("Recording lock poisoned".to_string()))?;
+/// Samples at or near full scale, which most likely clipped at the input.
+fn count_clipped(samples: &[f32]) -> usize {
+    samples
+        .iter()
+        .filter(|s| s.abs() >= METER_CLIP_THRESHOLD)
+        .count()
+}
 
-    if !state.is_recording {
-        return Err(CoreError::RecordingNotActive);
-    }
+/// Resample mono samples to 16 kHz, trim and enhance them, and encode to FLAC.
+pub(crate) fn encode_for_transcription(
+    mut captured: Vec<f32>,
+    sample_rate: u32,
+    options: &AudioProcessingOptions,
+) -> Result<AudioData, CoreError> {
+    let duration_seconds = captured.len() as f32 / sample_rate as f32;
+    let input_peak = captured.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+    let input_clipped_samples = count_clipped(&captured);
+    let input_clipped_ratio = if captured.is_empty() {
+        0.0
+    } else {
+        input_clipped_samples as f32 / captured.len() as f32
+    };
 
-    state.is_recording = false;
-    // SAFETY: The stream must be dropped before locking `samples` to avoid deadlock.
-    // The audio callback holds the `samples` lock while writing; if we lock `samples`
-    // first, the callback would block on `samples` while we block on stream teardown.
-    if let Some(stream) = state.stream.take() {
-        drop(stream);
+    if sample_rate != WHISPER_SAMPLE_RATE {
+        captured = if options.fast_resampling {
+            resample_linear(&captured, sample_rate, WHISPER_SAMPLE_RATE)
+        } else {
+            resample_sinc(&captured, sample_rate, WHISPER_SAMPLE_RATE)
+        };
     }
 
-    let samples = state
-        .samples
-        .lock()
-        .map_err(|_| CoreError::AudioCapture("Sample lock poisoned".to_string()))?;
-    if samples.is_empty() {
-        return Err(CoreError::AudioCapture("No audio captured".to_string()));
-    }
-
-    let mut captured = samples.clone();
-    drop(samples);
-
-    let duration_seconds = captured.len() as f32 / state.sample_rate as f32;
-
-    if state.sample_rate != WHISPER_SAMPLE_RATE {
-        captured = resample_linear(&captured, state.sample_rate, WHISPER_SAMPLE_RATE);
-    }
+    // Profile the whole capture: trimming removes most of the pauses between words.
+    let noise_profile = if options.noise_suppression {
+        NoiseProfile::estimate(&captured, WHISPER_SAMPLE_RATE)
+    } else {
+        None
+    };
+    let speech = if options.trim_silence {
+        trim_silence(&captured, WHISPER_SAMPLE_RATE, options.silence_padding_ms)
+            .ok_or(CoreError::NoSpeechDetected)?
+    } else {
+        &captured[..]
+    };
+    let trimmed_duration_seconds = speech.len() as f32 / WHISPER_SAMPLE_RATE as f32;
 
-    let enhanced = enhance_audio(&captured, WHISPER_SAMPLE_RATE);
+    let mut enhanced = enhance_audio(speech, WHISPER_SAMPLE_RATE, noise_profile.as_ref());
+    // Normalization gain can push loud speech past full scale; limit it smoothly rather
+    // than letting the FLAC conversion clamp it into a square wave.
+    let limiter = soft_limit(&mut enhanced, WHISPER_SAMPLE_RATE);
     let bytes = flac_bytes_from_samples(&enhanced)?;
 
     Ok(AudioData {
         bytes,
         duration_seconds,
+        trimmed_duration_seconds,
+        pre_roll_seconds: 0.0,
+        truncated: false,
+        clip_stats: ClipStats {
+            input_clipped_samples: input_clipped_samples as u64,
+            input_clipped_ratio,
+            input_peak_db: amplitude_to_db(input_peak),
+            processing_clipped_samples: limiter.clipped_samples as u64,
+            limited_samples: limiter.limited_samples as u64,
+            max_gain_reduction_db: limiter.max_gain_reduction_db,
+        },
     })
 }
 
-fn capture_f32(data: &[f32], channels: u16, samples: &Arc<Mutex<Vec<f32>>>) {
-    let Ok(mut buffer) = samples.lock() else {
+pub(crate) fn capture_f32(data: &[f32], channels: u16, capture: &CaptureBuffer) {
+    if !capture.accepts_samples() {
+        return;
+    }
+    let Ok(mut buffer) = capture.samples.lock() else {
         return;
     };
+    let start = buffer.len();
 
     if channels == 1 {
         buffer.extend_from_slice(data);
+        capture.after_capture(&mut buffer, start);
         return;
     }
 
@@ -162,16 +484,22 @@ fn capture_f32(data: &[f32], channels: u16, samples: &Arc<Mutex<Vec<f32>>>) {
         }
         buffer.push(sum / channels as f32);
     }
+    capture.after_capture(&mut buffer, start);
 }
 
-fn capture_i16(data: &[i16], channels: u16, samples: &Arc<Mutex<Vec<f32>>>) {
-    let Ok(mut buffer) = samples.lock() else {
+fn capture_i16(data: &[i16], channels: u16, capture: &CaptureBuffer) {
+    if !capture.accepts_samples() {
+        return;
+    }
+    let Ok(mut buffer) = capture.samples.lock() else {
         return;
     };
+    let start = buffer.len();
 
     let scale = f32::from(i16::MAX);
     if channels == 1 {
         buffer.extend(data.iter().map(|s| f32::from(*s) / scale));
+        capture.after_capture(&mut buffer, start);
         return;
     }
 
@@ -183,16 +511,22 @@ fn capture_i16(data: &[i16], channels: u16, samples: &Arc<Mutex<Vec<f32>>>) {
         }
         buffer.push(sum / channels as f32);
     }
+    capture.after_capture(&mut buffer, start);
 }
 
-fn capture_u16(data: &[u16], channels: u16, samples: &Arc<Mutex<Vec<f32>>>) {
-    let Ok(mut buffer) = samples.lock() else {
+fn capture_u16(data: &[u16], channels: u16, capture: &CaptureBuffer) {
+    if !capture.accepts_samples() {
+        return;
+    }
+    let Ok(mut buffer) = capture.samples.lock() else {
         return;
     };
+    let start = buffer.len();
 
     let scale = f32::from(u16::MAX);
     if channels == 1 {
         buffer.extend(data.iter().map(|s| (f32::from(*s) / scale) * 2.0 - 1.0));
+        capture.after_capture(&mut buffer, start);
         return;
     }
 
@@ -204,6 +538,7 @@ fn capture_u16(data: &[u16], channels: u16, samples: &Arc<Mutex<Vec<f32>>>) {
         }
         buffer.push(sum / channels as f32);
     }
+    capture.after_capture(&mut buffer, start);
 }
 
 fn resample_linear(input: &[f32], src_rate: u32, dst_rate: u32) -> Vec<f32> {
@@ -227,11 +562,184 @@ fn resample_linear(input: &[f32], src_rate: u32, dst_rate: u32) -> Vec<f32> {
     output
 }
 
+/// Band-limited resampling with a Kaiser-windowed sinc filter.
+///
+/// The filter cutoff sits just below the lower of the two Nyquist frequencies, so
+/// content that cannot be represented at the destination rate is removed instead of
+/// folding back into the speech band. Filter taps are precomputed for each fractional
+/// input position; rate pairs with more phases than `RESAMPLER_MAX_PHASES` snap to the
+/// nearest precomputed phase.
+fn resample_sinc(input: &[f32], src_rate: u32, dst_rate: u32) -> Vec<f32> {
+    if input.is_empty() || src_rate == dst_rate || src_rate == 0 || dst_rate == 0 {
+        return input.to_vec();
+    }
+
+    let ratio = f64::from(src_rate) / f64::from(dst_rate);
+    let output_len = ((input.len() as f64) / ratio).floor() as usize;
+    let scale = ratio.recip().min(1.0);
+    // Cutoff in cycles per input sample.
+    let cutoff = 0.5 * scale * RESAMPLER_CUTOFF;
+    let half_width = (RESAMPLER_ZERO_CROSSINGS as f64 / (2.0 * cutoff)).ceil() as usize;
+    let taps = 2 * half_width;
+    let phases = (dst_rate / gcd(src_rate, dst_rate)).min(RESAMPLER_MAX_PHASES) as usize;
+
+    // Row `p` holds the taps for an output sample that falls `p / phases` of the way
+    // between two input samples; tap `j` weights input `floor(pos) + 1 - half_width + j`.
+    let mut table = vec![0.0f32; phases * taps];
+    let window_norm = bessel_i0(RESAMPLER_KAISER_BETA);
+    for (phase, row) in table.chunks_exact_mut(taps).enumerate() {
+        let frac = phase as f64 / phases as f64;
+        let mut sum = 0.0;
+        let weights: Vec<f64> = (0..taps)
+            .map(|j| {
+                let t = j as f64 + 1.0 - half_width as f64 - frac;
+                let x = t / half_width as f64;
+                let window =
+                    bessel_i0(RESAMPLER_KAISER_BETA * (1.0 - x * x).max(0.0).sqrt()) / window_norm;
+                let weight = 2.0 * cutoff * sinc(2.0 * cutoff * t) * window;
+                sum += weight;
+                weight
+            })
+            .collect();
+        // Normalize each phase to unity DC gain.
+        for (tap, weight) in row.iter_mut().zip(weights) {
+            *tap = (weight / sum) as f32;
+        }
+    }
+
+    let mut output = Vec::with_capacity(output_len);
+    for i in 0..output_len {
+        let pos = i as f64 * ratio;
+        let mut base = pos.floor() as isize;
+        let mut phase = ((pos - base as f64) * phases as f64).round() as usize;
+        if phase == phases {
+            phase = 0;
+            base += 1;
+        }
+
+        let row = &table[phase * taps..(phase + 1) * taps];
+        let start = base + 1 - half_width as isize;
+        let lo = start.max(0) as usize;
+        let hi = ((start + taps as isize).max(0) as usize).min(input.len());
+        let skip = (lo as isize - start) as usize;
+        let value: f32 = input[lo.min(hi)..hi]
+            .iter()
+            .zip(&row[skip..])
+            .map(|(sample, tap)| sample * tap)
+            .sum();
+        output.push(value);
+    }
+
+    output
+}
+
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-12 {
+        1.0
+    } else {
+        let px = std::f64::consts::PI * x;
+        px.sin() / px
+    }
+}
+
+/// Zeroth-order modified Bessel function of the first kind, used by the Kaiser window.
+fn bessel_i0(x: f64) -> f64 {
+    let half_x_sq = (x / 2.0) * (x / 2.0);
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for k in 1..64 {
+        term *= half_x_sq / f64::from(k * k);
+        sum += term;
+        if term < sum * 1e-12 {
+            break;
+        }
+    }
+    sum
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Locate the first and last speech frames using frame energy and zero-crossing rate.
+///
+/// The speech threshold adapts to the recording's own noise floor (10th percentile of
+/// frame energies) so that quiet rooms and noisy rooms are both handled. Frames slightly
+/// below the threshold still count as speech when their zero-crossing rate is high, which
+/// keeps unvoiced consonants such as "s" and "f" at word boundaries.
+///
+/// Returns the sample range `[start, end)` covering speech, or `None` when no speech is found.
+fn detect_speech_bounds(samples: &[f32], sample_rate: u32) -> Option<(usize, usize)> {
+    const FRICATIVE_MARGIN_DB: f32 = 6.0;
+    const FRICATIVE_MIN_ZCR: f32 = 0.3;
+
+    let frame_len = (sample_rate * VAD_FRAME_MS / 1000).max(1) as usize;
+    let frames: Vec<(f32, f32)> = samples
+        .chunks(frame_len)
+        .map(|frame| {
+            let rms = (frame.iter().map(|s| s * s).sum::<f32>() / frame.len() as f32).sqrt();
+            let crossings = frame
+                .windows(2)
+                .filter(|pair| (pair[0] >= 0.0) != (pair[1] >= 0.0))
+                .count();
+            (
+                20.0 * rms.max(1e-10).log10(),
+                crossings as f32 / frame.len() as f32,
+            )
+        })
+        .collect();
+    if frames.is_empty() {
+        return None;
+    }
+
+    let mut energies: Vec<f32> = frames.iter().map(|(energy, _)| *energy).collect();
+    energies.sort_by(f32::total_cmp);
+    let noise_floor_db = energies[energies.len() / 10];
+    let threshold_db = (noise_floor_db + VAD_THRESHOLD_ABOVE_FLOOR_DB).max(VAD_MIN_SPEECH_DB);
+
+    let is_speech = |(energy, zcr): &(f32, f32)| {
+        *energy >= threshold_db
+            || (*energy >= threshold_db - FRICATIVE_MARGIN_DB
+                && *energy >= VAD_MIN_SPEECH_DB
+                && *zcr >= FRICATIVE_MIN_ZCR)
+    };
+
+    if frames.iter().filter(|frame| is_speech(frame)).count() < VAD_MIN_SPEECH_FRAMES {
+        return None;
+    }
+
+    let first = frames.iter().position(is_speech)?;
+    let last = frames.iter().rposition(is_speech)?;
+    Some((
+        first * frame_len,
+        ((last + 1) * frame_len).min(samples.len()),
+    ))
+}
+
+/// Trim silent edges, keeping `padding_ms` of audio around the detected speech.
+///
+/// Returns `None` when the samples contain no speech.
+fn trim_silence(samples: &[f32], sample_rate: u32, padding_ms: u32) -> Option<&[f32]> {
+    let (start, end) = detect_speech_bounds(samples, sample_rate)?;
+    let padding = (u64::from(sample_rate) * u64::from(padding_ms) / 1000) as usize;
+    let start = start.saturating_sub(padding);
+    let end = end.saturating_add(padding).min(samples.len());
+    Some(&samples[start..end])
+}
+
 /// Optimized audio enhancement for ASR input.
 ///
 /// Applies minimal processing to improve recognition while avoiding
-/// unnecessary gain staging that amplifies noise.
-fn enhance_audio(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+/// unnecessary gain staging that amplifies noise. With a `noise_profile`, spectral
+/// subtraction runs before normalization so the gain lifts speech rather than noise.
+fn enhance_audio(
+    samples: &[f32],
+    sample_rate: u32,
+    noise_profile: Option<&NoiseProfile>,
+) -> Vec<f32> {
     if samples.is_empty() {
         return Vec::new();
     }
@@ -251,7 +759,12 @@ fn enhance_audio(samples: &[f32], sample_rate: u32) -> Vec<f32> {
         }
     }
 
-    // Step 2: RMS normalization to ensure consistent volume
+    // Step 2: Optional noise suppression
+    if let Some(profile) = noise_profile {
+        output = suppress_noise(&output, profile);
+    }
+
+    // Step 3: RMS normalization to ensure consistent volume
     // This is critical for whisper speech recognition - too quiet = poor accuracy
     let rms = (output.iter().map(|s| s * s).sum::<f32>() / output.len() as f32).sqrt();
     if rms > 1e-6 {
@@ -269,7 +782,7 @@ fn enhance_audio(samples: &[f32], sample_rate: u32) -> Vec<f32> {
 ///
 /// FLAC provides ~50-70% compression ratio for speech audio,
 /// significantly reducing upload time for upstream transcription requests.
-fn flac_bytes_from_samples(samples: &[f32]) -> Result<Vec<u8>, CoreError> {
+pub(crate) fn flac_bytes_from_samples(samples: &[f32]) -> Result<Vec<u8>, CoreError> {
     use flacenc::bitsink::ByteSink;
     use flacenc::component::BitRepr;
 
@@ -303,10 +816,107 @@ fn flac_bytes_from_samples(samples: &[f32]) -> Result<Vec<u8>, CoreError> {
     Ok(sink.as_slice().to_vec())
 }
 
+fn decoding_error(error: impl std::fmt::Display) -> CoreError {
+    CoreError::AudioProcessing(format!("Audio decoding error: {error}"))
+}
+
+/// Decode an audio file in any supported container into mono samples.
+///
+/// Returns the samples and their sample rate. Corrupt packets are skipped so a
+/// damaged tail does not lose the rest of the recording.
+fn decode_audio(bytes: &[u8]) -> Result<(Vec<f32>, u32), CoreError> {
+    use symphonia::core::audio::SampleBuffer;
+    use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+    use symphonia::core::errors::Error;
+    use symphonia::core::formats::FormatOptions;
+    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::meta::MetadataOptions;
+    use symphonia::core::probe::Hint;
+
+    let source = MediaSourceStream::new(
+        Box::new(std::io::Cursor::new(bytes.to_vec())),
+        Default::default(),
+    );
+    let probed = symphonia::default::get_probe()
+        .format(
+            &Hint::new(),
+            source,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(decoding_error)?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| decoding_error("no audio track found"))?;
+    let track_id = track.id;
+    let mut sample_rate = track.codec_params.sample_rate;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(decoding_error)?;
+
+    let mut mono = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(Error::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+            Err(e) => return Err(decoding_error(e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(Error::DecodeError(e)) => {
+                log::warn!("Skipping corrupt audio packet: {e}");
+                continue;
+            }
+            Err(e) => return Err(decoding_error(e)),
+        };
+        let spec = *decoded.spec();
+        sample_rate = Some(spec.rate);
+        let channels = spec.channels.count().max(1);
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        mono.extend(
+            buffer
+                .samples()
+                .chunks(channels)
+                .map(|frame| frame.iter().sum::<f32>() / channels as f32),
+        );
+    }
+
+    let sample_rate = sample_rate.ok_or_else(|| decoding_error("unknown sample rate"))?;
+    if mono.is_empty() {
+        return Err(decoding_error("no audio samples decoded"));
+    }
+    Ok((mono, sample_rate))
+}
+
+/// Decode an audio file into 16 kHz mono samples without further processing.
+pub(crate) fn decode_audio_samples(bytes: &[u8]) -> Result<Vec<f32>, CoreError> {
+    let (samples, sample_rate) = decode_audio(bytes)?;
+    Ok(resample_sinc(&samples, sample_rate, WHISPER_SAMPLE_RATE))
+}
+
+/// Decode an audio file (WAV, MP3, OGG, FLAC, M4A, ...) and re-encode it the
+/// same way as a live recording.
+pub(crate) fn prepare_audio_file(
+    bytes: &[u8],
+    options: &AudioProcessingOptions,
+) -> Result<AudioData, CoreError> {
+    let (samples, sample_rate) = decode_audio(bytes)?;
+    encode_for_transcription(samples, sample_rate, options)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::sync::Mutex;
+    use std::f32::consts::PI;
 
     #[test]
     fn resample_linear_empty_input_returns_empty() {
@@ -340,6 +950,93 @@ mod tests {
         assert_eq!(result.len(), 9);
     }
 
+    fn tone(frequency: f32, sample_rate: u32, seconds: f32) -> Vec<f32> {
+        let len = (sample_rate as f32 * seconds) as usize;
+        (0..len)
+            .map(|i| 0.5 * (2.0 * PI * frequency * i as f32 / sample_rate as f32).sin())
+            .collect()
+    }
+
+    /// Amplitude of `frequency` in `samples`, ignoring the filter warm-up at both ends.
+    fn tone_amplitude(samples: &[f32], frequency: f32, sample_rate: u32) -> f32 {
+        let edge = samples.len() / 10;
+        let body = &samples[edge..samples.len() - edge];
+        let omega = 2.0 * PI * frequency / sample_rate as f32;
+        let (re, im) = body
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(re, im), (i, s)| {
+                let phase = omega * i as f32;
+                (re + s * phase.cos(), im - s * phase.sin())
+            });
+        2.0 * (re * re + im * im).sqrt() / body.len() as f32
+    }
+
+    fn db(amplitude: f32) -> f32 {
+        20.0 * amplitude.max(1e-12).log10()
+    }
+
+    #[test]
+    fn resample_sinc_should_suppress_aliasing_of_tones_above_8khz() {
+        for frequency in [9_000.0, 10_000.0, 12_000.0, 15_000.0] {
+            let input = tone(frequency, 48_000, 0.5);
+            let alias = 16_000.0 - frequency;
+
+            let sinc = resample_sinc(&input, 48_000, 16_000);
+            let linear = resample_linear(&input, 48_000, 16_000);
+
+            let sinc_alias_db = db(tone_amplitude(&sinc, alias, 16_000) / 0.5);
+            let linear_alias_db = db(tone_amplitude(&linear, alias, 16_000) / 0.5);
+            assert!(
+                sinc_alias_db < -60.0,
+                "{frequency} Hz aliased to {alias} Hz at {sinc_alias_db:.1} dB"
+            );
+            assert!(
+                linear_alias_db > -30.0,
+                "linear resampling was expected to alias {frequency} Hz, got {linear_alias_db:.1} dB"
+            );
+        }
+    }
+
+    #[test]
+    fn resample_sinc_should_preserve_speech_band_tones() {
+        for frequency in [200.0, 1_000.0, 4_000.0, 7_000.0] {
+            let input = tone(frequency, 48_000, 0.5);
+            let output = resample_sinc(&input, 48_000, 16_000);
+            let gain_db = db(tone_amplitude(&output, frequency, 16_000) / 0.5);
+            assert!(
+                gain_db.abs() < 0.1,
+                "{frequency} Hz changed by {gain_db:.2} dB"
+            );
+        }
+    }
+
+    #[test]
+    fn resample_sinc_should_handle_non_integer_ratios() {
+        let input = tone(1_000.0, 44_100, 0.5);
+        let output = resample_sinc(&input, 44_100, 16_000);
+        assert_eq!(output.len(), 8_000);
+        assert!(db(tone_amplitude(&output, 1_000.0, 16_000) / 0.5).abs() < 0.1);
+
+        let aliased = resample_sinc(&tone(11_025.0, 44_100, 0.5), 44_100, 16_000);
+        assert!(db(tone_amplitude(&aliased, 4_975.0, 16_000) / 0.5) < -60.0);
+    }
+
+    #[test]
+    fn resample_sinc_should_upsample_without_imaging() {
+        let input = tone(3_000.0, 8_000, 0.5);
+        let output = resample_sinc(&input, 8_000, 16_000);
+        assert_eq!(output.len(), 8_000);
+        assert!(db(tone_amplitude(&output, 3_000.0, 16_000) / 0.5).abs() < 0.1);
+        assert!(db(tone_amplitude(&output, 5_000.0, 16_000) / 0.5) < -60.0);
+    }
+
+    #[test]
+    fn resample_sinc_same_rate_or_empty_returns_clone() {
+        assert!(resample_sinc(&[], 48_000, 16_000).is_empty());
+        assert_eq!(resample_sinc(&[0.1, 0.2], 16_000, 16_000), vec![0.1, 0.2]);
+    }
+
     #[test]
     fn resample_linear_interpolation_is_linear() {
         // Linear ramp from 0.0 to 1.0 at 48000 Hz -> 24000 Hz
@@ -355,14 +1052,14 @@ mod tests {
     #[test]
     fn enhance_audio_empty_input_returns_empty() {
         let input: Vec<f32> = vec![];
-        let result = enhance_audio(&input, 16000);
+        let result = enhance_audio(&input, 16000, None);
         assert!(result.is_empty());
     }
 
     #[test]
     fn enhance_audio_preserves_sample_count() {
         let input = vec![0.1, -0.1, 0.2, -0.2, 0.3];
-        let result = enhance_audio(&input, 16000);
+        let result = enhance_audio(&input, 16000, None);
         assert_eq!(result.len(), input.len());
     }
 
@@ -370,7 +1067,7 @@ mod tests {
     fn enhance_audio_applies_rms_normalization() {
         // Very quiet signal should be amplified
         let input = vec![0.001, -0.001, 0.001, -0.001];
-        let result = enhance_audio(&input, 16000);
+        let result = enhance_audio(&input, 16000, None);
         // The RMS should be higher in the output
         let input_rms: f32 = (input.iter().map(|s| s * s).sum::<f32>() / input.len() as f32).sqrt();
         let output_rms: f32 =
@@ -380,31 +1077,31 @@ mod tests {
 
     #[test]
     fn capture_f32_mono_copies_directly() {
-        let samples = Arc::new(Mutex::new(Vec::new()));
+        let capture = CaptureBuffer::new();
         let data = vec![0.5, -0.5, 0.25, -0.25];
-        capture_f32(&data, 1, &samples);
-        let locked = samples.lock().unwrap();
+        capture_f32(&data, 1, &capture);
+        let locked = capture.samples.lock().unwrap();
         assert_eq!(*locked, data);
     }
 
     #[test]
     fn capture_f32_stereo_averages_channels() {
-        let samples = Arc::new(Mutex::new(Vec::new()));
+        let capture = CaptureBuffer::new();
         // Stereo interleaved: [L0, R0, L1, R1, L2, R2]
         let data = vec![0.0, 1.0, 0.5, 0.5, 1.0, 0.0];
-        capture_f32(&data, 2, &samples);
-        let locked = samples.lock().unwrap();
+        capture_f32(&data, 2, &capture);
+        let locked = capture.samples.lock().unwrap();
         // Should average to [0.5, 0.5, 0.5]
         assert_eq!(*locked, vec![0.5, 0.5, 0.5]);
     }
 
     #[test]
     fn capture_f32_quad_averages_four_channels() {
-        let samples = Arc::new(Mutex::new(Vec::new()));
+        let capture = CaptureBuffer::new();
         // Quad interleaved: [C0, C1, C2, C3, C0, C1, C2, C3]
         let data = vec![0.0, 0.4, 0.8, 1.2, 0.2, 0.6, 1.0, 1.4];
-        capture_f32(&data, 4, &samples);
-        let locked = samples.lock().unwrap();
+        capture_f32(&data, 4, &capture);
+        let locked = capture.samples.lock().unwrap();
         // Should average: [(0.0+0.4+0.8+1.2)/4=0.6, (0.2+0.6+1.0+1.4)/4=0.8]
         assert_eq!(locked.len(), 2);
         assert!((locked[0] - 0.6).abs() < f32::EPSILON);
@@ -413,10 +1110,10 @@ mod tests {
 
     #[test]
     fn capture_i16_mono_scales_to_unit_range() {
-        let samples = Arc::new(Mutex::new(Vec::new()));
+        let capture = CaptureBuffer::new();
         let data = vec![i16::MIN, 0, i16::MAX];
-        capture_i16(&data, 1, &samples);
-        let locked = samples.lock().expect("buffer lock should succeed");
+        capture_i16(&data, 1, &capture);
+        let locked = capture.samples.lock().expect("buffer lock should succeed");
         assert_eq!(locked.len(), 3);
         assert!(locked[0] <= -1.0);
         assert_eq!(locked[1], 0.0);
@@ -425,10 +1122,10 @@ mod tests {
 
     #[test]
     fn capture_i16_stereo_averages_channels() {
-        let samples = Arc::new(Mutex::new(Vec::new()));
+        let capture = CaptureBuffer::new();
         let data = vec![i16::MAX, 0, 0, i16::MAX];
-        capture_i16(&data, 2, &samples);
-        let locked = samples.lock().expect("buffer lock should succeed");
+        capture_i16(&data, 2, &capture);
+        let locked = capture.samples.lock().expect("buffer lock should succeed");
         assert_eq!(locked.len(), 2);
         assert!((locked[0] - 0.5).abs() < 0.0001);
         assert!((locked[1] - 0.5).abs() < 0.0001);
@@ -436,10 +1133,10 @@ mod tests {
 
     #[test]
     fn capture_u16_mono_maps_unsigned_samples_to_signed_range() {
-        let samples = Arc::new(Mutex::new(Vec::new()));
+        let capture = CaptureBuffer::new();
         let data = vec![0u16, u16::MAX / 2, u16::MAX];
-        capture_u16(&data, 1, &samples);
-        let locked = samples.lock().expect("buffer lock should succeed");
+        capture_u16(&data, 1, &capture);
+        let locked = capture.samples.lock().expect("buffer lock should succeed");
         assert_eq!(locked.len(), 3);
         assert!((locked[0] + 1.0).abs() < 0.0001);
         assert!(locked[1].abs() < 0.001);
@@ -448,23 +1145,254 @@ mod tests {
 
     #[test]
     fn capture_u16_stereo_averages_channels() {
-        let samples = Arc::new(Mutex::new(Vec::new()));
+        let capture = CaptureBuffer::new();
         let data = vec![0u16, u16::MAX, u16::MAX, 0u16];
-        capture_u16(&data, 2, &samples);
-        let locked = samples.lock().expect("buffer lock should succeed");
+        capture_u16(&data, 2, &capture);
+        let locked = capture.samples.lock().expect("buffer lock should succeed");
         assert_eq!(locked.len(), 2);
         assert!(locked[0].abs() < 0.0001);
         assert!(locked[1].abs() < 0.0001);
     }
 
+    #[test]
+    fn capture_should_feed_downmixed_samples_to_level_meter() {
+        let capture = CaptureBuffer::new();
+        capture.meter().start(48_000);
+        let data = vec![i16::MAX, i16::MAX, 0, 0];
+        capture_i16(&data, 2, &capture);
+
+        let levels = capture.meter().levels();
+        assert!((levels.peak - 1.0).abs() < 0.0001);
+        assert!(levels.is_clipping);
+    }
+
+    #[test]
+    fn capture_should_drop_samples_while_paused() {
+        let capture = CaptureBuffer::new();
+        capture_f32(&[0.1, 0.2], 1, &capture);
+        capture.set_paused(true);
+        capture_f32(&[0.3, 0.4], 1, &capture);
+        capture.set_paused(false);
+        capture_f32(&[0.5], 1, &capture);
+
+        assert_eq!(
+            capture.reset(0).expect("samples should be taken"),
+            vec![0.1, 0.2, 0.5]
+        );
+        assert!(capture
+            .reset(0)
+            .expect("samples should be taken")
+            .is_empty());
+    }
+
+    #[test]
+    fn capture_should_stop_buffering_at_sample_limit_and_signal_once() {
+        let capture = CaptureBuffer::new();
+        let (signal, limit_hit) = std::sync::mpsc::sync_channel(1);
+        capture.set_limit(5, Some(signal));
+
+        capture_f32(&[0.1, 0.2, 0.3], 1, &capture);
+        assert!(!capture.limit_reached());
+        assert!(limit_hit.try_recv().is_err());
+
+        // Stereo frames are downmixed before the limit is applied.
+        capture_f32(&[0.4, 0.4, 0.5, 0.5, 0.6, 0.6], 2, &capture);
+        assert!(capture.limit_reached());
+        assert!(limit_hit.try_recv().is_ok());
+
+        capture_f32(&[0.7, 0.8], 1, &capture);
+        assert!(limit_hit.try_recv().is_err());
+        assert_eq!(
+            capture.reset(0).expect("samples should be taken"),
+            vec![0.1, 0.2, 0.3, 0.4, 0.5]
+        );
+
+        // Resetting clears the limit and drops the signal.
+        assert!(!capture.limit_reached());
+        assert!(matches!(limit_hit.recv(), Err(std::sync::mpsc::RecvError)));
+        capture_f32(&[0.9; 10], 1, &capture);
+        assert_eq!(capture.reset(0).expect("samples should be taken").len(), 10);
+    }
+
+    #[test]
+    fn capture_limit_should_not_apply_to_pre_roll() {
+        let capture = CaptureBuffer::new();
+        capture.reset(4).expect("buffer should arm");
+        capture.set_limit(6, None);
+        capture_f32(&[0.1; 7], 1, &capture);
+        assert!(!capture.limit_reached());
+
+        assert_eq!(
+            capture
+                .begin_from_pre_roll()
+                .expect("recording should begin"),
+            4
+        );
+        capture_f32(&[0.2; 5], 1, &capture);
+        assert!(!capture.limit_reached());
+        capture_f32(&[0.3; 3], 1, &capture);
+        assert!(capture.limit_reached());
+        // The kept pre-roll plus the full limit of recorded audio.
+        let samples = capture.reset(0).expect("samples should be taken");
+        assert_eq!(samples.len(), 10);
+        assert_eq!(samples[..4], [0.1; 4]);
+    }
+
+    #[test]
+    fn capture_should_count_only_kept_clipped_samples() {
+        let capture = CaptureBuffer::new();
+        capture.reset(4).expect("buffer should arm");
+        // The first chunk is trimmed from the pre-roll before recording starts.
+        capture_f32(&[1.0; 6], 1, &capture);
+        capture_f32(&[0.1, 0.1, -1.0], 1, &capture);
+        assert_eq!(
+            capture
+                .begin_from_pre_roll()
+                .expect("recording should begin"),
+            4
+        );
+        capture.set_limit(6, None);
+
+        capture.set_paused(true);
+        capture_f32(&[1.0; 3], 1, &capture);
+        capture.set_paused(false);
+        // Stereo frames are counted after the downmix.
+        capture_f32(&[1.0, 1.0, 0.2, 0.2], 2, &capture);
+        // Only four of these fit under the limit.
+        capture_f32(&[1.0; 6], 1, &capture);
+        assert!(capture.limit_reached());
+
+        let (samples, clipped) = capture.take_recording(0).expect("samples should be taken");
+        assert_eq!(samples.len(), 10);
+        assert_eq!(clipped, 7);
+        assert_eq!(
+            capture.take_recording(0).expect("samples should be taken"),
+            (Vec::new(), 0)
+        );
+    }
+
+    #[test]
+    fn armed_capture_should_keep_only_recent_pre_roll() {
+        let capture = CaptureBuffer::new();
+        capture.reset(4).expect("buffer should arm");
+        for chunk in (0..20).map(|i| i as f32).collect::<Vec<_>>().chunks(3) {
+            capture_f32(chunk, 1, &capture);
+            assert!(capture.samples.lock().unwrap().len() < 8);
+        }
+
+        assert_eq!(
+            capture
+                .begin_from_pre_roll()
+                .expect("recording should begin"),
+            4
+        );
+        capture_f32(&[20.0, 21.0, 22.0, 23.0, 24.0], 1, &capture);
+        assert_eq!(
+            capture.reset(4).expect("samples should be taken"),
+            vec![16.0, 17.0, 18.0, 19.0, 20.0, 21.0, 22.0, 23.0, 24.0]
+        );
+    }
+
+    #[test]
+    fn enhance_audio_should_apply_noise_profile_before_gain() {
+        let sample_rate = 16_000;
+        let mut state = 0x9e37_79b9_u32;
+        let noise: Vec<f32> = (0..sample_rate as usize)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 - 0.5) * 0.04
+            })
+            .collect();
+        let profile = NoiseProfile::estimate(&noise, sample_rate).expect("profile expected");
+
+        let rms = |samples: &[f32]| {
+            (samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32).sqrt()
+        };
+        let plain = enhance_audio(&noise, sample_rate, None);
+        let denoised = enhance_audio(&noise, sample_rate, Some(&profile));
+        // Pure noise is attenuated first, so normalization hits its gain cap.
+        assert!(rms(&denoised) < 0.5 * rms(&plain));
+    }
+
     #[test]
     fn enhance_audio_zero_signal_should_not_produce_nan() {
         let input = vec![0.0; 256];
-        let output = enhance_audio(&input, 16000);
+        let output = enhance_audio(&input, 16000, None);
         assert_eq!(output.len(), input.len());
         assert!(output.iter().all(|sample| sample.is_finite()));
     }
 
+    fn speech_with_silent_edges(sample_rate: u32) -> Vec<f32> {
+        let silence = (sample_rate / 2) as usize;
+        let speech = sample_rate as usize;
+        let mut samples = Vec::with_capacity(silence * 2 + speech);
+        samples.extend((0..silence).map(|i| if i % 2 == 0 { 0.0005 } else { -0.0005 }));
+        samples.extend((0..speech).map(|i| {
+            let t = i as f32 / sample_rate as f32;
+            0.3 * (2.0 * std::f32::consts::PI * 220.0 * t).sin()
+        }));
+        samples.extend((0..silence).map(|i| if i % 2 == 0 { 0.0005 } else { -0.0005 }));
+        samples
+    }
+
+    #[test]
+    fn detect_speech_bounds_should_locate_speech_between_silent_edges() {
+        let samples = speech_with_silent_edges(16_000);
+        let (start, end) = detect_speech_bounds(&samples, 16_000).expect("speech expected");
+        let frame = 480;
+        assert!(start.abs_diff(8_000) <= frame);
+        assert!(end.abs_diff(24_000) <= frame);
+    }
+
+    #[test]
+    fn detect_speech_bounds_should_reject_pure_silence() {
+        let samples = vec![0.0; 16_000];
+        assert!(detect_speech_bounds(&samples, 16_000).is_none());
+    }
+
+    #[test]
+    fn detect_speech_bounds_should_reject_low_level_noise() {
+        let samples: Vec<f32> = (0..16_000)
+            .map(|i| if i % 3 == 0 { 0.0008 } else { -0.0004 })
+            .collect();
+        assert!(detect_speech_bounds(&samples, 16_000).is_none());
+    }
+
+    #[test]
+    fn detect_speech_bounds_should_ignore_isolated_click() {
+        let mut samples = vec![0.0; 16_000];
+        samples[8_000] = 0.9;
+        assert!(detect_speech_bounds(&samples, 16_000).is_none());
+    }
+
+    #[test]
+    fn trim_silence_should_keep_requested_padding() {
+        let samples = speech_with_silent_edges(16_000);
+        let trimmed = trim_silence(&samples, 16_000, 100).expect("speech expected");
+        let expected = 16_000 + 2 * 1_600;
+        assert!(trimmed.len().abs_diff(expected) <= 2 * 480);
+    }
+
+    #[test]
+    fn trim_silence_should_clamp_padding_to_input_bounds() {
+        let samples = speech_with_silent_edges(16_000);
+        let trimmed = trim_silence(&samples, 16_000, 5_000).expect("speech expected");
+        assert_eq!(trimmed.len(), samples.len());
+    }
+
+    #[test]
+    fn sample_rates_within_should_keep_common_rates_inside_ranges() {
+        let rates = sample_rates_within(&[(44_100, 48_000), (16_000, 16_000)]);
+        assert_eq!(rates, vec![16_000, 44_100, 48_000]);
+    }
+
+    #[test]
+    fn sample_rates_within_should_be_empty_without_ranges() {
+        assert!(sample_rates_within(&[]).is_empty());
+    }
+
     #[test]
     fn flac_bytes_from_samples_should_start_with_flac_magic() {
         let input = vec![0.0f32; 1024];
@@ -472,4 +1400,135 @@ mod tests {
         assert!(bytes.len() > 4);
         assert_eq!(&bytes[0..4], b"fLaC");
     }
+
+    /// Minimal 16-bit PCM WAV file with interleaved channels.
+    fn wav_bytes(samples: &[i16], sample_rate: u32, channels: u16) -> Vec<u8> {
+        let data_len = (samples.len() * 2) as u32;
+        let block_align = channels * 2;
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&channels.to_le_bytes());
+        bytes.extend_from_slice(&sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&(sample_rate * u32::from(block_align)).to_le_bytes());
+        bytes.extend_from_slice(&block_align.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        for sample in samples {
+            bytes.extend_from_slice(&sample.to_le_bytes());
+        }
+        bytes
+    }
+
+    #[test]
+    fn decode_audio_samples_should_round_trip_encoded_flac() {
+        let input: Vec<f32> = (0..4_000).map(|i| 0.5 * (i as f32 * 0.05).sin()).collect();
+        let bytes = flac_bytes_from_samples(&input).expect("flac encoding should succeed");
+        let decoded = decode_audio_samples(&bytes).expect("flac decoding should succeed");
+        // The encoder pads the final block with silence.
+        assert!(decoded.len() >= input.len());
+        assert!(input
+            .iter()
+            .zip(&decoded)
+            .all(|(a, b)| (a - b).abs() < 1e-3));
+    }
+
+    #[test]
+    fn decode_audio_samples_should_downmix_and_resample_wav() {
+        // One second of 48 kHz stereo with opposite channels cancelling out.
+        let frames: Vec<i16> = (0..48_000).flat_map(|_| [8_000, -8_000]).collect();
+        let decoded =
+            decode_audio_samples(&wav_bytes(&frames, 48_000, 2)).expect("wav should decode");
+        assert_eq!(decoded.len(), 16_000);
+        assert!(decoded.iter().all(|sample| sample.abs() < 1e-4));
+    }
+
+    #[test]
+    fn decode_audio_samples_should_reject_unknown_data() {
+        let result = decode_audio_samples(b"definitely not audio");
+        assert!(matches!(result, Err(CoreError::AudioProcessing(_))));
+    }
+
+    #[test]
+    fn prepare_audio_file_should_encode_wav_speech_as_flac() {
+        let sample_rate = 44_100;
+        let silence = vec![0i16; sample_rate as usize / 2];
+        let tone = (0..sample_rate).map(|i| {
+            let t = i as f32 / sample_rate as f32;
+            (8_000.0 * (2.0 * std::f32::consts::PI * 220.0 * t).sin()) as i16
+        });
+        let samples: Vec<i16> = silence
+            .iter()
+            .copied()
+            .chain(tone)
+            .chain(silence.iter().copied())
+            .collect();
+
+        let audio = prepare_audio_file(
+            &wav_bytes(&samples, sample_rate, 1),
+            &AudioProcessingOptions::default(),
+        )
+        .expect("wav should be prepared");
+
+        assert_eq!(&audio.bytes[0..4], b"fLaC");
+        assert!((audio.duration_seconds - 2.0).abs() < 0.01);
+        assert!(audio.trimmed_duration_seconds < audio.duration_seconds);
+    }
+
+    #[test]
+    fn encode_for_transcription_should_report_input_clipping_and_limit_peaks() {
+        // A tone driven past full scale and clipped at the microphone.
+        let clipped: Vec<f32> = (0..16_000)
+            .map(|i| {
+                (1.5 * (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16_000.0).sin())
+                    .clamp(-1.0, 1.0)
+            })
+            .collect();
+        let options = AudioProcessingOptions {
+            trim_silence: false,
+            ..AudioProcessingOptions::default()
+        };
+
+        let audio = encode_for_transcription(clipped.clone(), 16_000, &options)
+            .expect("audio should encode");
+        let stats = &audio.clip_stats;
+        let expected = clipped
+            .iter()
+            .filter(|s| s.abs() >= METER_CLIP_THRESHOLD)
+            .count();
+        assert_eq!(stats.input_clipped_samples, expected as u64);
+        assert!((stats.input_clipped_ratio - expected as f32 / 16_000.0).abs() < 1e-6);
+        assert_eq!(stats.input_peak_db, 0.0);
+
+        let decoded = decode_audio_samples(&audio.bytes).expect("flac should decode");
+        let ceiling = 10f32.powf(crate::config::LIMITER_CEILING_DB / 20.0);
+        assert!(decoded.iter().all(|s| s.abs() <= ceiling + 1e-3));
+    }
+
+    #[test]
+    fn encode_for_transcription_should_count_samples_pushed_over_full_scale() {
+        // Peaky and quiet: normalization gain drives the clicks past full scale.
+        let mut samples: Vec<f32> = (0..16_000)
+            .map(|i| 0.01 * (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16_000.0).sin())
+            .collect();
+        for click in samples.iter_mut().step_by(1_000) {
+            *click = 0.5;
+        }
+        let options = AudioProcessingOptions {
+            trim_silence: false,
+            ..AudioProcessingOptions::default()
+        };
+
+        let stats = encode_for_transcription(samples, 16_000, &options)
+            .expect("audio should encode")
+            .clip_stats;
+        assert_eq!(stats.input_clipped_samples, 0);
+        assert!(stats.processing_clipped_samples > 0);
+        assert!(stats.limited_samples >= stats.processing_clipped_samples);
+        assert!(stats.max_gain_reduction_db > 0.0);
+    }
 }
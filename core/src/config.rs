@@ -1,23 +1,211 @@
+use crate::error::CoreError;
+use crate::retry::RetryPolicy;
+use std::sync::{LazyLock, RwLock};
+
 pub(crate) const WHISPER_SAMPLE_RATE: u32 = 16_000;
 pub(crate) const WHISPER_CHANNELS: u16 = 1;
 
-pub(crate) const GROQ_TRANSCRIBE_URL: &str = "https://api.groq.com/openai/v1/audio/transcriptions";
+pub(crate) const GROQ_API_URL: &str = "https://api.groq.com/openai/v1";
 pub(crate) const GROQ_WHISPER_MODEL: &str = "whisper-large-v3-turbo";
+pub(crate) const OPENAI_WHISPER_MODEL: &str = "whisper-1";
 
 pub(crate) const GEMINI_MODEL: &str = "gemini-3.1-flash-lite-preview";
 pub(crate) const GEMINI_API_URL: &str = "https://generativelanguage.googleapis.com/v1beta/models";
 pub(crate) const OPENAI_MODEL: &str = "gpt-5.4-nano";
 pub(crate) const OPENAI_API_URL: &str = "https://api.openai.com/v1";
+pub(crate) const ANTHROPIC_MODEL: &str = "claude-haiku-4-5";
+pub(crate) const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1";
+pub(crate) const ANTHROPIC_VERSION: &str = "2023-06-01";
+/// Anthropic requires an explicit output budget on every request.
+pub(crate) const ANTHROPIC_MAX_TOKENS: u32 = 4096;
 
 pub(crate) const HIGHPASS_FREQ_HZ: f32 = 80.0;
 pub(crate) const TARGET_RMS_DB: f32 = -18.0;
 
+/// Analysis frame of the noise suppressor, rounded up to a power of two samples.
+pub(crate) const DENOISE_FRAME_MS: u32 = 32;
+/// Share of the quietest frames averaged into the noise profile.
+pub(crate) const DENOISE_NOISE_FRACTION: f32 = 0.1;
+/// Multiple of the noise estimate removed from each bin; above 1 trades some speech
+/// detail for fewer residual noise bursts.
+pub(crate) const DENOISE_OVER_SUBTRACTION: f32 = 2.0;
+/// Lowest gain applied to a bin, so residual noise stays smooth instead of "musical".
+pub(crate) const DENOISE_FLOOR_DB: f32 = -20.0;
+
+/// Level the post-normalization limiter keeps peaks under, in dBFS.
+pub(crate) const LIMITER_CEILING_DB: f32 = -1.0;
+/// How far ahead the limiter starts turning down ahead of a peak.
+pub(crate) const LIMITER_LOOKAHEAD_MS: u32 = 5;
+/// Time constant of the limiter's gain recovery after a peak.
+pub(crate) const LIMITER_RELEASE_MS: u32 = 80;
+
+/// Passband edge of the resampling filter as a fraction of the lower Nyquist frequency.
+pub(crate) const RESAMPLER_CUTOFF: f64 = 0.95;
+/// Filter half-width measured in zero crossings of the band-limited sinc.
+pub(crate) const RESAMPLER_ZERO_CROSSINGS: usize = 40;
+pub(crate) const RESAMPLER_KAISER_BETA: f64 = 8.0;
+/// Upper bound on precomputed filter phases for awkward rate ratios.
+pub(crate) const RESAMPLER_MAX_PHASES: u32 = 1_024;
+
+/// Audio kept from an armed microphone and prepended when recording starts.
+pub(crate) const PRE_ROLL_MS: u32 = 500;
+/// Default cap on one recording: 30 minutes.
+pub(crate) const MAX_RECORDING_SECONDS: u32 = 30 * 60;
+/// Default cap on buffered capture samples: 512 MiB of `f32`.
+pub(crate) const MAX_RECORDING_BUFFER_BYTES: u64 = 512 * 1024 * 1024;
+
+pub(crate) const METER_FLOOR_DB: f32 = -100.0;
+pub(crate) const METER_CLIP_THRESHOLD: f32 = 0.99;
+pub(crate) const METER_CLIP_HOLD_MS: u32 = 500;
+pub(crate) const METER_WAVEFORM_BINS: usize = 32;
+pub(crate) const METER_WAVEFORM_BIN_MS: u32 = 50;
+
+pub(crate) const VAD_FRAME_MS: u32 = 30;
+pub(crate) const VAD_DEFAULT_PADDING_MS: u32 = 250;
+pub(crate) const VAD_THRESHOLD_ABOVE_FLOOR_DB: f32 = 10.0;
+pub(crate) const VAD_MIN_SPEECH_DB: f32 = -55.0;
+pub(crate) const VAD_MIN_SPEECH_FRAMES: usize = 3;
+
+pub(crate) const LONG_AUDIO_CHUNK_SECONDS: u32 = 300;
+pub(crate) const LONG_AUDIO_SPLIT_SEARCH_SECONDS: u32 = 20;
+pub(crate) const LONG_AUDIO_OVERLAP_MS: u32 = 1_000;
+pub(crate) const LONG_AUDIO_MAX_PARALLEL_REQUESTS: u32 = 3;
+
+/// Whisper's own silence heuristic: a segment is dropped when the model thinks
+/// it is probably not speech and is also unsure of the words it produced.
+pub(crate) const NO_SPEECH_PROB_THRESHOLD: f64 = 0.6;
+pub(crate) const LOW_CONFIDENCE_LOGPROB: f64 = -1.0;
+/// Segments whose text compresses better than this are almost always repetition loops.
+pub(crate) const MAX_COMPRESSION_RATIO: f64 = 2.4;
+/// A phrase repeated this many times in a row is collapsed to a single occurrence.
+pub(crate) const REPETITION_LIMIT: usize = 4;
+/// Longest phrase, in words, checked for repetition.
+pub(crate) const MAX_REPEATED_PHRASE_WORDS: usize = 8;
+
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+/// Runtime endpoint and model configuration for remote providers.
+///
+/// URLs are API base URLs without a trailing endpoint path; the core appends
+/// `/audio/transcriptions`, `/chat/completions`, `/messages`,
+/// `/{model}:generateContent` and `/models` as needed.
+pub struct CoreConfig {
+    /// Groq OpenAI-compatible API base URL.
+    pub groq_api_url: String,
+    /// Whisper model used for Groq transcription.
+    pub groq_whisper_model: String,
+    /// OpenAI API base URL.
+    pub openai_api_url: String,
+    /// Chat model used for OpenAI polishing and prompt processing.
+    pub openai_model: String,
+    /// Transcription model used for OpenAI transcription.
+    pub openai_whisper_model: String,
+    /// Gemini `models` collection URL.
+    pub gemini_api_url: String,
+    /// Gemini model used for polishing and prompt processing.
+    pub gemini_model: String,
+    /// Anthropic API base URL.
+    pub anthropic_api_url: String,
+    /// Claude model used for Anthropic polishing and prompt processing.
+    pub anthropic_model: String,
+    /// Retry schedule shared by transcription, polish and prompt processing.
+    pub retry_policy: RetryPolicy,
+}
+
+impl Default for CoreConfig {
+    fn default() -> Self {
+        Self {
+            groq_api_url: GROQ_API_URL.to_string(),
+            groq_whisper_model: GROQ_WHISPER_MODEL.to_string(),
+            openai_api_url: OPENAI_API_URL.to_string(),
+            openai_model: OPENAI_MODEL.to_string(),
+            openai_whisper_model: OPENAI_WHISPER_MODEL.to_string(),
+            gemini_api_url: GEMINI_API_URL.to_string(),
+            gemini_model: GEMINI_MODEL.to_string(),
+            anthropic_api_url: ANTHROPIC_API_URL.to_string(),
+            anthropic_model: ANTHROPIC_MODEL.to_string(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+}
+
+impl CoreConfig {
+    fn validate(&self) -> Result<(), CoreError> {
+        for (name, url) in [
+            ("groq_api_url", &self.groq_api_url),
+            ("openai_api_url", &self.openai_api_url),
+            ("gemini_api_url", &self.gemini_api_url),
+            ("anthropic_api_url", &self.anthropic_api_url),
+        ] {
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                return Err(CoreError::Config(format!(
+                    "{name} must be an http(s) URL, got '{url}'"
+                )));
+            }
+        }
+
+        for (name, model) in [
+            ("groq_whisper_model", &self.groq_whisper_model),
+            ("openai_model", &self.openai_model),
+            ("openai_whisper_model", &self.openai_whisper_model),
+            ("gemini_model", &self.gemini_model),
+            ("anthropic_model", &self.anthropic_model),
+        ] {
+            if model.trim().is_empty() {
+                return Err(CoreError::Config(format!("{name} must not be empty")));
+            }
+        }
+
+        if self.retry_policy.max_attempts == 0 {
+            return Err(CoreError::Config(
+                "retry_policy.max_attempts must be at least 1".to_string(),
+            ));
+        }
+        if self.retry_policy.base_delay_ms > self.retry_policy.max_delay_ms {
+            return Err(CoreError::Config(
+                "retry_policy.base_delay_ms must not exceed max_delay_ms".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Strip trailing slashes so endpoint paths can be appended uniformly.
+pub(crate) fn base_url(url: &str) -> &str {
+    url.trim_end_matches('/')
+}
+
+static CORE_CONFIG: LazyLock<RwLock<CoreConfig>> =
+    LazyLock::new(|| RwLock::new(CoreConfig::default()));
+
+/// Snapshot of the active runtime configuration.
+pub(crate) fn core_config() -> CoreConfig {
+    match CORE_CONFIG.read() {
+        Ok(config) => config.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Validate and replace the active runtime configuration.
+pub(crate) fn set_core_config(config: CoreConfig) -> Result<(), CoreError> {
+    config.validate()?;
+    let mut current = CORE_CONFIG
+        .write()
+        .map_err(|_| CoreError::Config("Configuration lock poisoned".to_string()))?;
+    *current = config;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
-        GEMINI_API_URL, GROQ_TRANSCRIBE_URL, HIGHPASS_FREQ_HZ, OPENAI_API_URL, TARGET_RMS_DB,
-        WHISPER_CHANNELS, WHISPER_SAMPLE_RATE,
+        base_url, CoreConfig, ANTHROPIC_API_URL, GEMINI_API_URL, GROQ_API_URL, HIGHPASS_FREQ_HZ,
+        OPENAI_API_URL, RESAMPLER_CUTOFF, RESAMPLER_KAISER_BETA, RESAMPLER_MAX_PHASES,
+        RESAMPLER_ZERO_CROSSINGS, TARGET_RMS_DB, VAD_FRAME_MS, VAD_MIN_SPEECH_DB,
+        VAD_THRESHOLD_ABOVE_FLOOR_DB, WHISPER_CHANNELS, WHISPER_SAMPLE_RATE,
     };
+    use crate::error::CoreError;
+    use crate::retry::RetryPolicy;
     use std::hint::black_box;
 
     #[test]
@@ -28,9 +216,10 @@ mod tests {
 
     #[test]
     fn api_urls_should_use_https() {
-        assert!(GROQ_TRANSCRIBE_URL.starts_with("https://"));
+        assert!(GROQ_API_URL.starts_with("https://"));
         assert!(GEMINI_API_URL.starts_with("https://"));
         assert!(OPENAI_API_URL.starts_with("https://"));
+        assert!(ANTHROPIC_API_URL.starts_with("https://"));
     }
 
     #[test]
@@ -38,4 +227,64 @@ mod tests {
         assert!(black_box(HIGHPASS_FREQ_HZ) > 0.0);
         assert!(black_box(TARGET_RMS_DB) < 0.0);
     }
+
+    #[test]
+    fn resampler_constants_should_be_in_sane_range() {
+        assert!(black_box(RESAMPLER_CUTOFF) > 0.5 && black_box(RESAMPLER_CUTOFF) < 1.0);
+        assert!(black_box(RESAMPLER_ZERO_CROSSINGS) >= 8);
+        assert!(black_box(RESAMPLER_KAISER_BETA) > 0.0);
+        assert!(black_box(RESAMPLER_MAX_PHASES) > 0);
+    }
+
+    #[test]
+    fn vad_constants_should_be_in_sane_range() {
+        assert!(black_box(VAD_FRAME_MS) > 0);
+        assert!(black_box(VAD_THRESHOLD_ABOVE_FLOOR_DB) > 0.0);
+        assert!(black_box(VAD_MIN_SPEECH_DB) < 0.0);
+    }
+
+    #[test]
+    fn default_core_config_should_be_valid() {
+        assert!(CoreConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn core_config_should_reject_non_http_urls() {
+        let config = CoreConfig {
+            openai_api_url: "localhost:8080/v1".to_string(),
+            ..CoreConfig::default()
+        };
+        assert!(
+            matches!(config.validate(), Err(CoreError::Config(message)) if message.contains("openai_api_url"))
+        );
+    }
+
+    #[test]
+    fn core_config_should_reject_blank_models() {
+        let config = CoreConfig {
+            gemini_model: "  ".to_string(),
+            ..CoreConfig::default()
+        };
+        assert!(
+            matches!(config.validate(), Err(CoreError::Config(message)) if message == "gemini_model must not be empty")
+        );
+    }
+
+    #[test]
+    fn core_config_should_reject_zero_retry_attempts() {
+        let config = CoreConfig {
+            retry_policy: RetryPolicy {
+                max_attempts: 0,
+                ..RetryPolicy::default()
+            },
+            ..CoreConfig::default()
+        };
+        assert!(matches!(config.validate(), Err(CoreError::Config(_))));
+    }
+
+    #[test]
+    fn base_url_should_strip_trailing_slashes() {
+        assert_eq!(base_url("http://localhost/v1//"), "http://localhost/v1");
+        assert_eq!(base_url("http://localhost/v1"), "http://localhost/v1");
+    }
 }
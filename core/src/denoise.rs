@@ -0,0 +1,241 @@
+use crate::config::{
+    DENOISE_FLOOR_DB, DENOISE_FRAME_MS, DENOISE_NOISE_FRACTION, DENOISE_OVER_SUBTRACTION,
+};
+use symphonia::core::dsp::complex::Complex;
+use symphonia::core::dsp::fft::Fft;
+
+/// Average noise power per frequency bin, estimated from the quietest frames of a
+/// recording.
+pub(crate) struct NoiseProfile {
+    frame_len: usize,
+    power: Vec<f32>,
+}
+
+fn frame_len(sample_rate: u32) -> usize {
+    let len = (u64::from(sample_rate) * u64::from(DENOISE_FRAME_MS) / 1000) as usize;
+    len.max(2).next_power_of_two().min(Fft::MAX_SIZE)
+}
+
+/// Square root of a periodic Hann window. Applied at analysis and synthesis, the
+/// squared windows of half-overlapping frames sum to one, so unmodified frames
+/// reconstruct the input exactly.
+fn sqrt_hann(len: usize) -> Vec<f32> {
+    (0..len)
+        .map(|n| {
+            let phase = 2.0 * std::f32::consts::PI * n as f32 / len as f32;
+            (0.5 - 0.5 * phase.cos()).sqrt()
+        })
+        .collect()
+}
+
+/// Windowed spectrum of `frame`, zero-padded to the window length.
+fn spectrum(fft: &Fft, window: &[f32], frame: &[f32]) -> Vec<Complex> {
+    let mut bins: Vec<Complex> = window
+        .iter()
+        .enumerate()
+        .map(|(i, w)| Complex::new(frame.get(i).map_or(0.0, |s| s * w), 0.0))
+        .collect();
+    fft.fft_inplace(&mut bins);
+    bins
+}
+
+fn power(bin: Complex) -> f32 {
+    bin.re * bin.re + bin.im * bin.im
+}
+
+impl NoiseProfile {
+    /// Estimate the noise floor of `samples`. Returns `None` when the input is
+    /// shorter than one analysis frame.
+    pub(crate) fn estimate(samples: &[f32], sample_rate: u32) -> Option<Self> {
+        let frame_len = frame_len(sample_rate);
+        if samples.len() < frame_len {
+            return None;
+        }
+        let fft = Fft::new(frame_len);
+        let window = sqrt_hann(frame_len);
+        let bins = frame_len / 2 + 1;
+
+        // Only whole frames: zero padding would pass for silence.
+        let spectra: Vec<Vec<f32>> = samples
+            .windows(frame_len)
+            .step_by(frame_len / 2)
+            .map(|frame| {
+                spectrum(&fft, &window, frame)[..bins]
+                    .iter()
+                    .copied()
+                    .map(power)
+                    .collect()
+            })
+            .collect();
+
+        let mut by_energy: Vec<(f32, usize)> = spectra
+            .iter()
+            .enumerate()
+            .map(|(index, frame)| (frame.iter().sum(), index))
+            .collect();
+        by_energy.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let quiet_count = ((spectra.len() as f32 * DENOISE_NOISE_FRACTION) as usize).max(1);
+
+        let mut power = vec![0.0f32; bins];
+        for &(_, index) in &by_energy[..quiet_count] {
+            for (total, frame_power) in power.iter_mut().zip(&spectra[index]) {
+                *total += frame_power;
+            }
+        }
+        for total in &mut power {
+            *total /= quiet_count as f32;
+        }
+        Some(Self { frame_len, power })
+    }
+}
+
+/// Spectral subtraction: attenuate every frequency bin by how much of its power the
+/// noise profile accounts for. Returns a signal of the same length.
+pub(crate) fn suppress_noise(samples: &[f32], profile: &NoiseProfile) -> Vec<f32> {
+    let frame_len = profile.frame_len;
+    let hop = frame_len / 2;
+    if samples.is_empty() {
+        return Vec::new();
+    }
+
+    let fft = Fft::new(frame_len);
+    let window = sqrt_hann(frame_len);
+    let floor = 10f32.powf(DENOISE_FLOOR_DB / 10.0);
+
+    // Lead with one hop of zeros so the first samples are covered by two frames.
+    let mut padded = vec![0.0f32; hop];
+    padded.extend_from_slice(samples);
+    let frames = samples.len().div_ceil(hop) + 1;
+    padded.resize((frames + 1) * hop, 0.0);
+    let mut output = vec![0.0f32; padded.len()];
+
+    for start in (0..frames).map(|frame| frame * hop) {
+        let mut bins = spectrum(&fft, &window, &padded[start..start + frame_len]);
+        for (index, bin) in bins.iter_mut().enumerate() {
+            // Bins above Nyquist mirror those below it.
+            let noise = profile.power[index.min(frame_len - index)];
+            let bin_power = power(*bin);
+            let remaining = if bin_power > 0.0 {
+                1.0 - DENOISE_OVER_SUBTRACTION * noise / bin_power
+            } else {
+                0.0
+            };
+            *bin = bin.scale(remaining.max(floor).sqrt());
+        }
+        fft.ifft_inplace(&mut bins);
+        for ((out, bin), w) in output[start..start + frame_len]
+            .iter_mut()
+            .zip(&bins)
+            .zip(&window)
+        {
+            *out += bin.re * w;
+        }
+    }
+
+    output.drain(..hop);
+    output.truncate(samples.len());
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    /// Deterministic white noise in [-amplitude, amplitude).
+    fn white_noise(len: usize, amplitude: f32) -> Vec<f32> {
+        let mut state = 0x2545_f491_u32;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 * 2.0 - 1.0) * amplitude
+            })
+            .collect()
+    }
+
+    /// Tone bursts of 300 ms separated by 200 ms of silence, like words with pauses.
+    fn tone_bursts(len: usize, sample_rate: u32) -> Vec<f32> {
+        (0..len)
+            .map(|i| {
+                let t = i as f32 / sample_rate as f32;
+                if t % 0.5 < 0.3 {
+                    0.3 * (2.0 * PI * 440.0 * t).sin() + 0.15 * (2.0 * PI * 1_250.0 * t).sin()
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    fn snr_db(clean: &[f32], signal: &[f32]) -> f32 {
+        let signal_power: f32 = clean.iter().map(|s| s * s).sum();
+        let noise_power: f32 = clean
+            .iter()
+            .zip(signal)
+            .map(|(c, s)| (s - c) * (s - c))
+            .sum();
+        10.0 * (signal_power / noise_power).log10()
+    }
+
+    #[test]
+    fn suppress_noise_should_improve_snr_of_noisy_tones() {
+        let sample_rate = 16_000;
+        let clean = tone_bursts(3 * sample_rate as usize, sample_rate);
+        let noisy: Vec<f32> = clean
+            .iter()
+            .zip(white_noise(clean.len(), 0.08))
+            .map(|(c, n)| c + n)
+            .collect();
+
+        let profile = NoiseProfile::estimate(&noisy, sample_rate).expect("profile expected");
+        let denoised = suppress_noise(&noisy, &profile);
+
+        let before = snr_db(&clean, &noisy);
+        let after = snr_db(&clean, &denoised);
+        assert_eq!(denoised.len(), noisy.len());
+        assert!(
+            after - before >= 6.0,
+            "expected at least 6 dB SNR improvement, got {before:.1} dB before and {after:.1} dB after"
+        );
+    }
+
+    #[test]
+    fn noise_profile_should_come_from_quietest_frames() {
+        let sample_rate = 16_000;
+        let clean = tone_bursts(2 * sample_rate as usize, sample_rate);
+        let noise = white_noise(clean.len(), 0.01);
+        let noisy: Vec<f32> = clean.iter().zip(&noise).map(|(c, n)| c + n).collect();
+
+        let from_noisy = NoiseProfile::estimate(&noisy, sample_rate).expect("profile expected");
+        let from_noise = NoiseProfile::estimate(&noise, sample_rate).expect("profile expected");
+        let total = |profile: &NoiseProfile| profile.power.iter().sum::<f32>();
+        // The tones must not leak into the estimate.
+        assert!(total(&from_noisy) < 2.0 * total(&from_noise));
+    }
+
+    #[test]
+    fn suppress_noise_should_reconstruct_clean_input() {
+        let sample_rate = 16_000;
+        let clean = tone_bursts(sample_rate as usize, sample_rate);
+        let profile = NoiseProfile::estimate(&clean, sample_rate).expect("profile expected");
+        let output = suppress_noise(&clean, &profile);
+        assert!(snr_db(&clean, &output) > 60.0);
+    }
+
+    #[test]
+    fn estimate_should_need_one_full_frame() {
+        assert!(NoiseProfile::estimate(&[0.1; 100], 16_000).is_none());
+        assert_eq!(frame_len(16_000), 512);
+    }
+
+    #[test]
+    fn suppress_noise_should_handle_silence_and_short_input() {
+        let profile = NoiseProfile::estimate(&[0.0; 2_048], 16_000).expect("profile expected");
+        let output = suppress_noise(&[0.0; 2_048], &profile);
+        assert!(output.iter().all(|sample| *sample == 0.0));
+        assert_eq!(suppress_noise(&[0.2; 10], &profile).len(), 10);
+        assert!(suppress_noise(&[], &profile).is_empty());
+    }
+}
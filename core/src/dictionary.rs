@@ -0,0 +1,383 @@
+use crate::error::CoreError;
+use crate::transcribe::Transcript;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, RwLock};
+
+/// Whisper reads at most 224 prompt tokens; this keeps the vocabulary hint
+/// comfortably inside that for typical names and product terms.
+const MAX_PROMPT_CHARS: usize = 600;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+/// One replacement applied to every transcript before polish.
+pub struct DictionaryEntry {
+    /// Word or phrase to find, or a regular expression when `is_regex` is set.
+    pub pattern: String,
+    /// Text substituted for each match; regex entries may refer to groups as `$1`.
+    pub replacement: String,
+    /// Match letter case exactly. Otherwise matching ignores case, and a match
+    /// that starts with a capital letter gets a capitalized replacement.
+    #[serde(default)]
+    #[uniffi(default = false)]
+    pub case_sensitive: bool,
+    /// Treat `pattern` as a regular expression instead of a whole word or phrase.
+    #[serde(default)]
+    #[uniffi(default = false)]
+    pub is_regex: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, uniffi::Record)]
+/// User vocabulary and transcript replacements.
+pub struct Dictionary {
+    /// Names and terms sent to the transcription model as a spelling hint.
+    #[serde(default)]
+    pub vocabulary: Vec<String>,
+    /// Replacements applied in order to each transcript.
+    #[serde(default)]
+    pub entries: Vec<DictionaryEntry>,
+}
+
+impl DictionaryEntry {
+    fn matcher(&self) -> Result<Regex, CoreError> {
+        let pattern = if self.is_regex {
+            self.pattern.clone()
+        } else {
+            regex::escape(self.pattern.trim())
+        };
+        RegexBuilder::new(&pattern)
+            .case_insensitive(!self.case_sensitive)
+            .build()
+            .map_err(|e| {
+                CoreError::Config(format!(
+                    "Invalid dictionary pattern '{}': {e}",
+                    self.pattern
+                ))
+            })
+    }
+
+    fn replace_in(&self, text: &str, matcher: &Regex) -> String {
+        if self.is_regex {
+            return matcher
+                .replace_all(text, self.replacement.as_str())
+                .into_owned();
+        }
+
+        let mut output = String::with_capacity(text.len());
+        let mut last = 0;
+        for found in matcher.find_iter(text) {
+            if !is_whole_word(text, found.start(), found.end()) {
+                continue;
+            }
+            output.push_str(&text[last..found.start()]);
+            output.push_str(&self.replacement_for(found.as_str()));
+            last = found.end();
+        }
+        output.push_str(&text[last..]);
+        output
+    }
+
+    fn replacement_for(&self, matched: &str) -> String {
+        let capitalized = matched.chars().next().is_some_and(char::is_uppercase);
+        let mut chars = self.replacement.chars();
+        match chars.next() {
+            Some(first) if !self.case_sensitive && capitalized && first.is_lowercase() => {
+                first.to_uppercase().chain(chars).collect()
+            }
+            _ => self.replacement.clone(),
+        }
+    }
+}
+
+/// Whether a character can glue onto a neighbouring word. Scripts written
+/// without spaces (CJK, Thai, ...) have no word boundaries to respect, so only
+/// letters and digits below U+0800 count.
+fn joins_word(c: char) -> bool {
+    c.is_alphanumeric() && c.len_utf8() <= 2
+}
+
+/// Whether `text[start..end]` is not part of a longer word.
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    let matched = &text[start..end];
+    let glued_before = text[..start].chars().next_back().is_some_and(joins_word)
+        && matched.chars().next().is_some_and(joins_word);
+    let glued_after = text[end..].chars().next().is_some_and(joins_word)
+        && matched.chars().next_back().is_some_and(joins_word);
+    !glued_before && !glued_after
+}
+
+impl Dictionary {
+    fn validate(&self) -> Result<(), CoreError> {
+        for entry in &self.entries {
+            if entry.pattern.trim().is_empty() {
+                return Err(CoreError::Config(
+                    "Dictionary patterns must not be empty".to_string(),
+                ));
+            }
+            entry.matcher()?;
+        }
+        Ok(())
+    }
+
+    /// Vocabulary joined into a Whisper `prompt`, or `None` when there is none.
+    pub(crate) fn transcription_prompt(&self) -> Option<String> {
+        let mut prompt = String::new();
+        for term in self.vocabulary.iter().map(|term| term.trim()) {
+            if term.is_empty() {
+                continue;
+            }
+            let separator = if prompt.is_empty() { "" } else { ", " };
+            if prompt.chars().count() + separator.len() + term.chars().count() > MAX_PROMPT_CHARS {
+                log::warn!(
+                    "Vocabulary exceeds the transcription prompt budget; extra terms ignored"
+                );
+                break;
+            }
+            prompt.push_str(separator);
+            prompt.push_str(term);
+        }
+        (!prompt.is_empty()).then_some(prompt)
+    }
+
+    /// Apply every entry in order to `text`.
+    pub(crate) fn apply(&self, text: &str) -> String {
+        let mut output = text.to_string();
+        for entry in &self.entries {
+            match entry.matcher() {
+                Ok(matcher) => output = entry.replace_in(&output, &matcher),
+                Err(error) => log::warn!("Skipping dictionary entry: {error}"),
+            }
+        }
+        output
+    }
+
+    /// Apply the replacements to the transcript text and each segment.
+    pub(crate) fn apply_to_transcript(&self, mut transcript: Transcript) -> Transcript {
+        if self.entries.is_empty() {
+            return transcript;
+        }
+        transcript.text = self.apply(&transcript.text);
+        for segment in &mut transcript.segments {
+            segment.text = self.apply(&segment.text);
+        }
+        transcript
+    }
+}
+
+static DICTIONARY: LazyLock<RwLock<Dictionary>> =
+    LazyLock::new(|| RwLock::new(Dictionary::default()));
+
+/// Snapshot of the active dictionary.
+pub(crate) fn dictionary() -> Dictionary {
+    match DICTIONARY.read() {
+        Ok(dictionary) => dictionary.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+/// Validate and replace the active dictionary.
+pub(crate) fn set_dictionary(dictionary: Dictionary) -> Result<(), CoreError> {
+    update_dictionary(|current| *current = dictionary)
+}
+
+/// Edit the active dictionary, keeping the previous one if the result is invalid.
+pub(crate) fn update_dictionary<T>(
+    edit: impl FnOnce(&mut Dictionary) -> T,
+) -> Result<T, CoreError> {
+    let mut current = DICTIONARY
+        .write()
+        .map_err(|_| CoreError::Config("Dictionary lock poisoned".to_string()))?;
+    let mut updated = current.clone();
+    let result = edit(&mut updated);
+    updated.validate()?;
+    *current = updated;
+    Ok(result)
+}
+
+/// Add a vocabulary term unless it is already present, ignoring case.
+pub(crate) fn add_vocabulary_term(term: &str) -> Result<(), CoreError> {
+    let term = term.trim();
+    if term.is_empty() {
+        return Err(CoreError::Config(
+            "Vocabulary terms must not be empty".to_string(),
+        ));
+    }
+    update_dictionary(|dictionary| {
+        if !dictionary
+            .vocabulary
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(term))
+        {
+            dictionary.vocabulary.push(term.to_string());
+        }
+    })
+}
+
+/// Remove a vocabulary term, ignoring case; returns whether it was present.
+pub(crate) fn remove_vocabulary_term(term: &str) -> Result<bool, CoreError> {
+    let term = term.trim();
+    update_dictionary(|dictionary| {
+        let before = dictionary.vocabulary.len();
+        dictionary
+            .vocabulary
+            .retain(|existing| !existing.eq_ignore_ascii_case(term));
+        dictionary.vocabulary.len() < before
+    })
+}
+
+/// Add a replacement, or update the existing entry with the same pattern.
+pub(crate) fn add_dictionary_entry(entry: DictionaryEntry) -> Result<(), CoreError> {
+    update_dictionary(|dictionary| {
+        match dictionary
+            .entries
+            .iter_mut()
+            .find(|existing| existing.pattern == entry.pattern)
+        {
+            Some(existing) => *existing = entry,
+            None => dictionary.entries.push(entry),
+        }
+    })
+}
+
+/// Remove the replacement with exactly this pattern; returns whether it was present.
+pub(crate) fn remove_dictionary_entry(pattern: &str) -> Result<bool, CoreError> {
+    update_dictionary(|dictionary| {
+        let before = dictionary.entries.len();
+        dictionary
+            .entries
+            .retain(|existing| existing.pattern != pattern);
+        dictionary.entries.len() < before
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_whole_word, Dictionary, DictionaryEntry, MAX_PROMPT_CHARS};
+    use crate::error::CoreError;
+    use crate::transcribe::{Transcript, TranscriptSegment};
+
+    fn entry(pattern: &str, replacement: &str) -> DictionaryEntry {
+        DictionaryEntry {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+            case_sensitive: false,
+            is_regex: false,
+        }
+    }
+
+    fn dictionary(entries: Vec<DictionaryEntry>) -> Dictionary {
+        Dictionary {
+            vocabulary: Vec::new(),
+            entries,
+        }
+    }
+
+    #[test]
+    fn apply_should_replace_whole_words_only() {
+        let dictionary = dictionary(vec![entry("cube", "Kube")]);
+        assert_eq!(
+            dictionary.apply("the cube cluster and a cuber"),
+            "the Kube cluster and a cuber"
+        );
+    }
+
+    #[test]
+    fn apply_should_capitalize_replacement_for_capitalized_match() {
+        let dictionary = dictionary(vec![entry("jon doe", "jane doe")]);
+        assert_eq!(
+            dictionary.apply("Jon Doe met jon doe."),
+            "Jane doe met jane doe."
+        );
+    }
+
+    #[test]
+    fn apply_should_respect_case_sensitive_entries() {
+        let dictionary = dictionary(vec![DictionaryEntry {
+            case_sensitive: true,
+            ..entry("Mark", "Marc")
+        }]);
+        assert_eq!(dictionary.apply("Mark will mark it"), "Marc will mark it");
+    }
+
+    #[test]
+    fn apply_should_expand_regex_groups() {
+        let dictionary = dictionary(vec![DictionaryEntry {
+            is_regex: true,
+            ..entry(r"ticket (\d+)", "JIRA-$1")
+        }]);
+        assert_eq!(
+            dictionary.apply("Close Ticket 42 today"),
+            "Close JIRA-42 today"
+        );
+    }
+
+    #[test]
+    fn apply_should_not_treat_dollar_signs_as_groups_in_plain_entries() {
+        let dictionary = dictionary(vec![entry("five bucks", "$5")]);
+        assert_eq!(dictionary.apply("costs five bucks"), "costs $5");
+    }
+
+    #[test]
+    fn is_whole_word_should_allow_matches_inside_cjk_text() {
+        let text = "我们用飞书开会";
+        let start = text.find("飞书").expect("term present");
+        assert!(is_whole_word(text, start, start + "飞书".len()));
+        assert!(!is_whole_word("recube", 2, 6));
+    }
+
+    #[test]
+    fn validate_should_reject_invalid_regex_and_empty_patterns() {
+        let invalid = dictionary(vec![DictionaryEntry {
+            is_regex: true,
+            ..entry("(unclosed", "x")
+        }]);
+        assert!(matches!(invalid.validate(), Err(CoreError::Config(_))));
+        assert!(matches!(
+            dictionary(vec![entry("  ", "x")]).validate(),
+            Err(CoreError::Config(_))
+        ));
+    }
+
+    #[test]
+    fn transcription_prompt_should_join_terms_within_budget() {
+        let mut dictionary = Dictionary {
+            vocabulary: vec![
+                " Kubernetes ".to_string(),
+                String::new(),
+                "Anya".to_string(),
+            ],
+            entries: Vec::new(),
+        };
+        assert_eq!(
+            dictionary.transcription_prompt().as_deref(),
+            Some("Kubernetes, Anya")
+        );
+
+        dictionary.vocabulary = vec!["x".repeat(MAX_PROMPT_CHARS), "overflow".to_string()];
+        assert_eq!(
+            dictionary.transcription_prompt().map(|prompt| prompt.len()),
+            Some(MAX_PROMPT_CHARS)
+        );
+        assert_eq!(Dictionary::default().transcription_prompt(), None);
+    }
+
+    #[test]
+    fn apply_to_transcript_should_update_text_and_segments() {
+        let transcript = Transcript {
+            text: "Ask cooper netties.".to_string(),
+            language: None,
+            duration_seconds: None,
+            segments: vec![TranscriptSegment {
+                start_seconds: 0.0,
+                end_seconds: 1.0,
+                text: "Ask cooper netties.".to_string(),
+                avg_logprob: None,
+                no_speech_prob: None,
+            }],
+            words: Vec::new(),
+        };
+        let updated =
+            dictionary(vec![entry("cooper netties", "Kubernetes")]).apply_to_transcript(transcript);
+        assert_eq!(updated.text, "Ask Kubernetes.");
+        assert_eq!(updated.segments[0].text, "Ask Kubernetes.");
+    }
+}
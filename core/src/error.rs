@@ -1,5 +1,10 @@
+use reqwest::StatusCode;
+use std::time::Duration;
 use thiserror::Error;
 
+/// Provider error bodies are kept for diagnostics, capped to this many bytes.
+const MAX_ERROR_BODY_BYTES: usize = 2048;
+
 #[derive(Debug, Error, uniffi::Error)]
 /// Unified error type returned by core operations.
 pub enum CoreError {
@@ -9,24 +14,94 @@ pub enum CoreError {
     /// No default input audio device is available.
     #[error("Audio device not available")]
     AudioDeviceUnavailable,
+    /// Requested input audio device does not exist.
+    #[error("Audio device not found: {0}")]
+    AudioDeviceNotFound(String),
     /// Recording was started while another capture session is active.
     #[error("Recording already active")]
     RecordingAlreadyActive,
     /// Recording was stopped without an active capture session.
     #[error("Recording not active")]
     RecordingNotActive,
+    /// Recording was paused while already paused.
+    #[error("Recording already paused")]
+    RecordingAlreadyPaused,
+    /// Recording was resumed while not paused.
+    #[error("Recording not paused")]
+    RecordingNotPaused,
     /// Audio capture failed.
     #[error("Audio capture failed: {0}")]
     AudioCapture(String),
+    /// Recording contained no detectable speech.
+    #[error("No speech detected")]
+    NoSpeechDetected,
     /// Audio processing failed.
     #[error("Audio processing failed: {0}")]
     AudioProcessing(String),
     /// HTTP transport failed.
     #[error("HTTP error: {0}")]
     Http(String),
-    /// Remote API returned a non-success response.
+    /// Remote API returned a response that could not be used.
     #[error("API error: {0}")]
     Api(String),
+    /// Provider rejected the API key (HTTP 401/403, or a key error reported as 400).
+    #[error("{provider} rejected the API key (HTTP {status})")]
+    Unauthorized {
+        /// Provider display name, e.g. `Gemini`.
+        provider: String,
+        /// HTTP status code.
+        status: u16,
+        /// Provider error body, truncated.
+        body: String,
+    },
+    /// Provider is throttling requests (HTTP 429).
+    #[error("{provider} rate limit exceeded")]
+    RateLimited {
+        /// Provider display name.
+        provider: String,
+        /// Server-suggested wait before retrying, in whole seconds.
+        retry_after_secs: Option<u64>,
+        /// Provider error body, truncated.
+        body: String,
+    },
+    /// Account has run out of quota or credit.
+    #[error("{provider} quota exceeded (HTTP {status})")]
+    QuotaExceeded {
+        /// Provider display name.
+        provider: String,
+        /// HTTP status code.
+        status: u16,
+        /// Provider error body, truncated.
+        body: String,
+    },
+    /// Provider refused to generate content for safety or policy reasons.
+    #[error("{provider} blocked the content: {reason}")]
+    ContentBlocked {
+        /// Provider display name.
+        provider: String,
+        /// Provider-reported block or finish reason.
+        reason: String,
+    },
+    /// Request timed out, either locally or at a gateway (HTTP 408/504).
+    #[error("{provider} request timed out")]
+    Timeout {
+        /// Provider display name.
+        provider: String,
+        /// HTTP status code, or `None` when no response arrived in time.
+        status: Option<u16>,
+        /// Provider error body, truncated; empty for local timeouts.
+        body: String,
+    },
+    /// Provider returned any other non-success HTTP status.
+    #[error("{provider} error: HTTP {status}")]
+    ApiStatus {
+        /// Provider display name.
+        provider: String,
+        /// HTTP status code.
+        status: u16,
+        /// Provider error body, truncated.
+        body: String,
+    },
     /// Serialization or deserialization failed.
     #[error("Serialization error: {0}")]
     Serialization(String),
@@ -41,6 +116,116 @@ pub enum CoreError {
     Config(String),
 }
 
+#[uniffi::export]
+impl CoreError {
+    /// Whether repeating the same request later may succeed.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            CoreError::Http(_) | CoreError::RateLimited { .. } | CoreError::Timeout { .. } => true,
+            CoreError::ApiStatus { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// HTTP status code returned by the provider, if the error came from one.
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            CoreError::Unauthorized { status, .. }
+            | CoreError::QuotaExceeded { status, .. }
+            | CoreError::ApiStatus { status, .. } => Some(*status),
+            CoreError::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS.as_u16()),
+            CoreError::Timeout { status, .. } => *status,
+            _ => None,
+        }
+    }
+}
+
+impl CoreError {
+    /// Classify a non-success provider response.
+    pub(crate) fn from_status(
+        provider: &str,
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        body: &str,
+    ) -> Self {
+        let provider = provider.to_string();
+        let code = status.as_u16();
+        let body = truncate_body(body);
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => CoreError::Unauthorized {
+                provider,
+                status: code,
+                body,
+            },
+            // Gemini reports malformed or revoked keys as 400 INVALID_ARGUMENT.
+            StatusCode::BAD_REQUEST if body.contains("API_KEY_INVALID") => {
+                CoreError::Unauthorized {
+                    provider,
+                    status: code,
+                    body,
+                }
+            }
+            StatusCode::PAYMENT_REQUIRED => CoreError::QuotaExceeded {
+                provider,
+                status: code,
+                body,
+            },
+            // OpenAI uses 429 for both throttling and exhausted credit.
+            StatusCode::TOO_MANY_REQUESTS if body.contains("insufficient_quota") => {
+                CoreError::QuotaExceeded {
+                    provider,
+                    status: code,
+                    body,
+                }
+            }
+            StatusCode::TOO_MANY_REQUESTS => CoreError::RateLimited {
+                provider,
+                retry_after_secs: retry_after.map(whole_seconds),
+                body,
+            },
+            StatusCode::REQUEST_TIMEOUT | StatusCode::GATEWAY_TIMEOUT => CoreError::Timeout {
+                provider,
+                status: Some(code),
+                body,
+            },
+            _ => CoreError::ApiStatus {
+                provider,
+                status: code,
+                body,
+            },
+        }
+    }
+
+    /// Classify a request that failed before a response arrived.
+    pub(crate) fn from_transport(provider: &str, err: &reqwest::Error) -> Self {
+        if err.is_timeout() {
+            CoreError::Timeout {
+                provider: provider.to_string(),
+                status: None,
+                body: String::new(),
+            }
+        } else {
+            CoreError::Http(format!("{provider}: {err}"))
+        }
+    }
+}
+
+fn whole_seconds(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+fn truncate_body(body: &str) -> String {
+    let body = body.trim();
+    if body.len() <= MAX_ERROR_BODY_BYTES {
+        return body.to_string();
+    }
+    let mut end = MAX_ERROR_BODY_BYTES;
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    body[..end].to_string()
+}
+
 impl From<reqwest::Error> for CoreError {
     fn from(err: reqwest::Error) -> Self {
         CoreError::Http(err.to_string())
@@ -55,7 +240,9 @@ impl From<serde_json::Error> for CoreError {
 
 #[cfg(test)]
 mod tests {
-    use super::CoreError;
+    use super::{CoreError, MAX_ERROR_BODY_BYTES};
+    use reqwest::StatusCode;
+    use std::time::Duration;
 
     #[test]
     fn core_error_display_messages_should_match_contract() {
@@ -64,6 +251,10 @@ mod tests {
             CoreError::AudioDeviceUnavailable.to_string(),
             "Audio device not available"
         );
+        assert_eq!(
+            CoreError::AudioDeviceNotFound("x".to_string()).to_string(),
+            "Audio device not found: x"
+        );
         assert_eq!(
             CoreError::RecordingAlreadyActive.to_string(),
             "Recording already active"
@@ -72,10 +263,22 @@ mod tests {
             CoreError::RecordingNotActive.to_string(),
             "Recording not active"
         );
+        assert_eq!(
+            CoreError::RecordingAlreadyPaused.to_string(),
+            "Recording already paused"
+        );
+        assert_eq!(
+            CoreError::RecordingNotPaused.to_string(),
+            "Recording not paused"
+        );
         assert_eq!(
             CoreError::AudioCapture("x".to_string()).to_string(),
             "Audio capture failed: x"
         );
+        assert_eq!(
+            CoreError::NoSpeechDetected.to_string(),
+            "No speech detected"
+        );
         assert_eq!(
             CoreError::AudioProcessing("x".to_string()).to_string(),
             "Audio processing failed: x"
@@ -101,6 +304,129 @@ mod tests {
             CoreError::Config("x".to_string()).to_string(),
             "Configuration error: x"
         );
+        assert_eq!(
+            CoreError::Unauthorized {
+                provider: "Gemini".to_string(),
+                status: 401,
+                body: String::new(),
+            }
+            .to_string(),
+            "Gemini rejected the API key (HTTP 401)"
+        );
+        assert_eq!(
+            CoreError::ContentBlocked {
+                provider: "Gemini".to_string(),
+                reason: "SAFETY".to_string(),
+            }
+            .to_string(),
+            "Gemini blocked the content: SAFETY"
+        );
+    }
+
+    #[test]
+    fn from_status_should_map_auth_failures_to_unauthorized() {
+        for status in [StatusCode::UNAUTHORIZED, StatusCode::FORBIDDEN] {
+            let error = CoreError::from_status("Groq", status, None, "bad key");
+            assert!(matches!(
+                error,
+                CoreError::Unauthorized { ref provider, status: code, ref body }
+                    if provider == "Groq" && code == status.as_u16() && body == "bad key"
+            ));
+        }
+    }
+
+    #[test]
+    fn from_status_should_detect_gemini_invalid_key_reported_as_bad_request() {
+        let body = r#"{"error":{"code":400,"status":"INVALID_ARGUMENT","details":[{"reason":"API_KEY_INVALID"}]}}"#;
+        let error = CoreError::from_status("Gemini", StatusCode::BAD_REQUEST, None, body);
+        assert!(matches!(error, CoreError::Unauthorized { status: 400, .. }));
+
+        let other = CoreError::from_status("Gemini", StatusCode::BAD_REQUEST, None, "{}");
+        assert!(matches!(other, CoreError::ApiStatus { status: 400, .. }));
+    }
+
+    #[test]
+    fn from_status_should_split_rate_limits_from_exhausted_quota() {
+        let limited = CoreError::from_status(
+            "OpenAI",
+            StatusCode::TOO_MANY_REQUESTS,
+            Some(Duration::from_millis(1_200)),
+            r#"{"error":{"code":"rate_limit_exceeded"}}"#,
+        );
+        assert!(matches!(
+            limited,
+            CoreError::RateLimited {
+                retry_after_secs: Some(2),
+                ..
+            }
+        ));
+
+        let quota = CoreError::from_status(
+            "OpenAI",
+            StatusCode::TOO_MANY_REQUESTS,
+            None,
+            r#"{"error":{"code":"insufficient_quota"}}"#,
+        );
+        assert!(matches!(
+            quota,
+            CoreError::QuotaExceeded { status: 429, .. }
+        ));
+    }
+
+    #[test]
+    fn from_status_should_map_gateway_timeouts_and_keep_other_statuses() {
+        let timeout =
+            CoreError::from_status("Groq", StatusCode::GATEWAY_TIMEOUT, None, "upstream slow");
+        assert!(matches!(
+            timeout,
+            CoreError::Timeout { ref provider, status: Some(504), ref body }
+                if provider == "Groq" && body == "upstream slow"
+        ));
+        assert_eq!(timeout.status_code(), Some(504));
+
+        let unavailable =
+            CoreError::from_status("Groq", StatusCode::SERVICE_UNAVAILABLE, None, "down");
+        assert!(matches!(
+            unavailable,
+            CoreError::ApiStatus { status: 503, ref body, .. } if body == "down"
+        ));
+    }
+
+    #[test]
+    fn from_status_should_truncate_long_bodies_on_char_boundary() {
+        let body = "é".repeat(MAX_ERROR_BODY_BYTES);
+        let error = CoreError::from_status("Groq", StatusCode::BAD_GATEWAY, None, &body);
+        let CoreError::ApiStatus { body, .. } = error else {
+            panic!("expected ApiStatus");
+        };
+        assert_eq!(body.len(), MAX_ERROR_BODY_BYTES);
+    }
+
+    #[test]
+    fn is_retryable_should_follow_error_kind() {
+        let status = |code: StatusCode| CoreError::from_status("Groq", code, None, "");
+        assert!(status(StatusCode::TOO_MANY_REQUESTS).is_retryable());
+        assert!(status(StatusCode::INTERNAL_SERVER_ERROR).is_retryable());
+        assert!(status(StatusCode::GATEWAY_TIMEOUT).is_retryable());
+        assert!(CoreError::Http("reset".to_string()).is_retryable());
+
+        assert!(!status(StatusCode::UNAUTHORIZED).is_retryable());
+        assert!(!status(StatusCode::BAD_REQUEST).is_retryable());
+        assert!(!status(StatusCode::PAYMENT_REQUIRED).is_retryable());
+        assert!(!CoreError::Cancelled.is_retryable());
+    }
+
+    #[test]
+    fn status_code_should_expose_provider_status() {
+        let error = CoreError::from_status("Groq", StatusCode::NOT_FOUND, None, "");
+        assert_eq!(error.status_code(), Some(404));
+        assert_eq!(CoreError::EmptyResponse.status_code(), None);
+        let local_timeout = CoreError::Timeout {
+            provider: "Groq".to_string(),
+            status: None,
+            body: String::new(),
+        };
+        assert_eq!(local_timeout.status_code(), None);
     }
 
     #[test]
@@ -1,22 +1,28 @@
-use crate::config::{GEMINI_API_URL, OPENAI_API_URL};
+use crate::config::{base_url, core_config, CoreConfig};
 use crate::error::CoreError;
 use crate::LlmProvider;
 use reqwest::blocking::Client;
 use std::sync::OnceLock;
 use std::time::Duration;
 
-const GROQ_MODELS_URL: &str = "https://api.groq.com/openai/v1/models";
-
 /// Global HTTP client with connection pooling
 /// Initialized lazily on first use
 static HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
 
-fn gemini_models_url() -> String {
-    format!("{GEMINI_API_URL}/models")
+fn groq_models_url(config: &CoreConfig) -> String {
+    format!("{}/models", base_url(&config.groq_api_url))
+}
+
+fn gemini_models_url(config: &CoreConfig) -> String {
+    format!("{}/models", base_url(&config.gemini_api_url))
 }
 
-fn openai_models_url() -> String {
-    format!("{OPENAI_API_URL}/models")
+fn openai_models_url(config: &CoreConfig) -> String {
+    format!("{}/models", base_url(&config.openai_api_url))
+}
+
+fn anthropic_models_url(config: &CoreConfig) -> String {
+    format!("{}/models", base_url(&config.anthropic_api_url))
 }
 
 fn warmup_error(target: &str, detail: impl std::fmt::Display) -> CoreError {
@@ -74,7 +80,7 @@ pub(crate) fn get_http_client() -> &'static Client {
 /// - A previous API call failed with a connection error
 /// - The app has been backgrounded and resumed
 pub(crate) fn warmup_groq_connection() -> Result<(), CoreError> {
-    warmup_connection_with_label(GROQ_MODELS_URL, "Groq")
+    warmup_connection_with_label(&groq_models_url(&core_config()), "Groq")
 }
 
 /// Warm up the TLS connection to Gemini API
@@ -112,17 +118,25 @@ pub(crate) fn warmup_groq_connection() -> Result<(), CoreError> {
 /// - A previous API call failed with a connection error
 /// - You want to ensure minimal latency for a critical operation
 pub(crate) fn warmup_gemini_connection() -> Result<(), CoreError> {
-    warmup_connection_with_label(&gemini_models_url(), "Gemini")
+    warmup_connection_with_label(&gemini_models_url(&core_config()), "Gemini")
 }
 
 pub(crate) fn warmup_openai_connection() -> Result<(), CoreError> {
-    warmup_connection_with_label(&openai_models_url(), "OpenAI")
+    warmup_connection_with_label(&openai_models_url(&core_config()), "OpenAI")
+}
+
+pub(crate) fn warmup_anthropic_connection() -> Result<(), CoreError> {
+    warmup_connection_with_label(&anthropic_models_url(&core_config()), "Anthropic")
 }
 
-pub(crate) fn warmup_llm_connection(provider: LlmProvider) -> Result<(), CoreError> {
+pub(crate) fn warmup_llm_connection(provider: &LlmProvider) -> Result<(), CoreError> {
     match provider {
         LlmProvider::GoogleAiStudio => warmup_gemini_connection(),
         LlmProvider::Openai => warmup_openai_connection(),
+        LlmProvider::Anthropic => warmup_anthropic_connection(),
+        LlmProvider::OpenAiCompatible { base_url: url, .. } => {
+            warmup_connection_with_label(&format!("{}/models", base_url(url)), "LLM server")
+        }
     }
 }
 
@@ -147,27 +161,47 @@ fn warmup_connection_with_label(url: &str, label: &str) -> Result<(), CoreError>
 
 #[cfg(test)]
 mod tests {
-    use super::{gemini_models_url, openai_models_url, warmup_error, GROQ_MODELS_URL};
-    use crate::config::{GEMINI_API_URL, OPENAI_API_URL};
+    use super::{
+        anthropic_models_url, gemini_models_url, groq_models_url, openai_models_url, warmup_error,
+    };
+    use crate::config::{CoreConfig, ANTHROPIC_API_URL, GEMINI_API_URL, OPENAI_API_URL};
     use crate::error::CoreError;
 
     #[test]
     fn gemini_models_url_should_append_models_suffix() {
-        let url = gemini_models_url();
+        let url = gemini_models_url(&CoreConfig::default());
         assert_eq!(url, format!("{GEMINI_API_URL}/models"));
     }
 
     #[test]
     fn groq_models_url_should_match_expected_endpoint() {
-        assert_eq!(GROQ_MODELS_URL, "https://api.groq.com/openai/v1/models");
+        assert_eq!(
+            groq_models_url(&CoreConfig::default()),
+            "https://api.groq.com/openai/v1/models"
+        );
     }
 
     #[test]
     fn openai_models_url_should_append_models_suffix() {
-        let url = openai_models_url();
+        let url = openai_models_url(&CoreConfig::default());
         assert_eq!(url, format!("{OPENAI_API_URL}/models"));
     }
 
+    #[test]
+    fn anthropic_models_url_should_append_models_suffix() {
+        let url = anthropic_models_url(&CoreConfig::default());
+        assert_eq!(url, format!("{ANTHROPIC_API_URL}/models"));
+    }
+
+    #[test]
+    fn models_urls_should_follow_configured_base_urls() {
+        let config = CoreConfig {
+            groq_api_url: "http://127.0.0.1:8080/v1/".to_string(),
+            ..CoreConfig::default()
+        };
+        assert_eq!(groq_models_url(&config), "http://127.0.0.1:8080/v1/models");
+    }
+
     #[test]
     fn warmup_error_should_return_http_variant_with_label() {
         let error = warmup_error("Gemini", "connection refused");
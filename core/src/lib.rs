@@ -5,28 +5,146 @@
 mod audio;
 mod cancellation;
 mod config;
+mod denoise;
+mod dictionary;
 mod error;
 mod http_client;
-mod llm_processor;
+mod limiter;
+mod llm_client;
+mod long_audio;
+mod meter;
 mod pipeline;
 mod polish;
 mod retry;
+mod session;
+mod streaming;
 mod transcribe;
 
-pub use audio::AudioData;
+pub use audio::{AudioData, AudioProcessingOptions, ClipStats, InputDeviceInfo};
 pub use cancellation::CancellationToken;
+pub use config::CoreConfig;
+pub use dictionary::{Dictionary, DictionaryEntry};
 pub use error::CoreError;
+pub use long_audio::LongAudioOptions;
+pub use meter::AudioLevels;
+pub use pipeline::{PipelineOptions, PipelineResult};
+pub use retry::RetryPolicy;
+pub use session::{RecordingEventListener, RecordingLimits, RecordingSession};
+pub use streaming::LlmStreamListener;
+pub use transcribe::{Transcript, TranscriptSegment, TranscriptWord};
 
+use llm_client::LlmRequest;
 use secrecy::SecretString;
 use std::sync::Arc;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Enum)]
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Enum)]
 /// Supported LLM providers for polish and generic text-processing flows.
 pub enum LlmProvider {
     /// Google AI Studio Gemini API.
     GoogleAiStudio,
     /// OpenAI Chat Completions API.
     Openai,
+    /// Anthropic Messages API.
+    Anthropic,
+    /// Any server implementing the OpenAI `chat/completions` endpoint, such as
+    /// Ollama or the llama.cpp server. The API key may be empty.
+    OpenAiCompatible {
+        /// API base URL including the version prefix, e.g. `http://localhost:11434/v1`.
+        base_url: String,
+        /// Model name sent with each request.
+        model: String,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Enum)]
+/// Supported speech-to-text providers.
+pub enum TranscriptionProvider {
+    /// Groq Whisper API.
+    Groq,
+    /// OpenAI audio transcriptions API.
+    Openai,
+    /// Any server implementing the OpenAI `audio/transcriptions` endpoint.
+    OpenAiCompatible {
+        /// API base URL including the version prefix, e.g. `http://localhost:8000/v1`.
+        base_url: String,
+        /// Model name sent with each transcription request.
+        model: String,
+    },
+}
+
+#[uniffi::export]
+/// Return the built-in provider URLs and model names.
+pub fn default_core_config() -> CoreConfig {
+    CoreConfig::default()
+}
+
+#[uniffi::export]
+/// Return the provider URLs and model names currently used by the core.
+pub fn get_core_config() -> CoreConfig {
+    config::core_config()
+}
+
+#[uniffi::export]
+/// Replace the provider URLs and model names used by subsequent requests.
+///
+/// Returns `CoreError::Config` if a URL is not http(s) or a model name is empty.
+pub fn set_core_config(config: CoreConfig) -> Result<(), CoreError> {
+    config::set_core_config(config)
+}
+
+#[uniffi::export]
+/// Return the vocabulary and replacements currently applied to transcripts.
+pub fn get_dictionary() -> Dictionary {
+    dictionary::dictionary()
+}
+
+#[uniffi::export]
+/// Replace the whole dictionary, e.g. after loading it from storage.
+///
+/// Returns `CoreError::Config` if a pattern is empty or an invalid regular expression.
+pub fn set_dictionary(dictionary: Dictionary) -> Result<(), CoreError> {
+    dictionary::set_dictionary(dictionary)
+}
+
+#[uniffi::export]
+/// Add a term to the vocabulary sent to the transcription model as a spelling hint.
+pub fn add_vocabulary_term(term: String) -> Result<(), CoreError> {
+    dictionary::add_vocabulary_term(&term)
+}
+
+#[uniffi::export]
+/// Remove a vocabulary term, ignoring case. Returns whether the term was present.
+pub fn remove_vocabulary_term(term: String) -> Result<bool, CoreError> {
+    dictionary::remove_vocabulary_term(&term)
+}
+
+#[uniffi::export]
+/// Add a transcript replacement, or update the entry with the same pattern.
+///
+/// Returns `CoreError::Config` if the pattern is empty or an invalid regular expression.
+pub fn add_dictionary_entry(entry: DictionaryEntry) -> Result<(), CoreError> {
+    dictionary::add_dictionary_entry(entry)
+}
+
+#[uniffi::export]
+/// Remove the transcript replacement with this pattern. Returns whether it was present.
+pub fn remove_dictionary_entry(pattern: String) -> Result<bool, CoreError> {
+    dictionary::remove_dictionary_entry(&pattern)
+}
+
+#[uniffi::export]
+/// Apply the dictionary replacements to arbitrary text.
+pub fn apply_dictionary(text: String) -> String {
+    dictionary::dictionary().apply(&text)
+}
+
+#[uniffi::export]
+/// Process-wide recording session used by the free recording functions.
+///
+/// Hosts that need more than one capture, or want to own the session lifetime, can
+/// create their own with [`RecordingSession::new`] instead.
+pub fn default_recording_session() -> Arc<RecordingSession> {
+    session::default_session()
 }
 
 #[uniffi::export]
@@ -34,15 +152,137 @@ pub enum LlmProvider {
 ///
 /// Returns an error if input audio device is unavailable or recording is already active.
 pub fn start_recording() -> Result<(), CoreError> {
-    audio::start_recording()
+    session::default_session().start_on(None)
+}
+
+#[uniffi::export]
+/// Start microphone capture on a specific input device.
+///
+/// `device_id` is an identifier (or exact name) reported by [`list_input_devices`].
+pub fn start_recording_with_device(device_id: String) -> Result<(), CoreError> {
+    session::default_session().start_on(Some(&device_id))
+}
+
+#[uniffi::export]
+/// Keep the default input device open and buffer the last 500 ms of audio, so the
+/// next [`start_recording`] includes speech that began just before it was called.
+///
+/// The microphone stays in use until [`disarm_microphone`].
+pub fn arm_microphone() -> Result<(), CoreError> {
+    session::default_session().arm(None)
+}
+
+#[uniffi::export]
+/// Arm a specific input device for pre-roll; pair with [`start_recording_with_device`].
+pub fn arm_microphone_with_device(device_id: String) -> Result<(), CoreError> {
+    session::default_session().arm(Some(device_id))
+}
+
+#[uniffi::export]
+/// Stop buffering pre-roll and release the microphone when not recording.
+pub fn disarm_microphone() -> Result<(), CoreError> {
+    session::default_session().disarm()
+}
+
+#[uniffi::export]
+/// Pause the active recording without closing the input stream.
+///
+/// Audio captured while paused is discarded. Fails with
+/// [`CoreError::RecordingNotActive`] when idle and [`CoreError::RecordingAlreadyPaused`]
+/// when already paused.
+pub fn pause_recording() -> Result<(), CoreError> {
+    session::default_session().pause()
+}
+
+#[uniffi::export]
+/// Resume a recording paused with [`pause_recording`].
+///
+/// Fails with [`CoreError::RecordingNotActive`] when idle and
+/// [`CoreError::RecordingNotPaused`] when not paused.
+pub fn resume_recording() -> Result<(), CoreError> {
+    session::default_session().resume()
+}
+
+#[uniffi::export]
+/// Cap the duration and buffer size of recordings started after this call.
+///
+/// A recording that reaches either limit stops capturing and releases the microphone;
+/// [`stop_recording`] still returns the audio, with `truncated` set.
+pub fn set_recording_limits(limits: RecordingLimits) -> Result<(), CoreError> {
+    session::default_session().set_limits(limits)
+}
+
+#[uniffi::export]
+/// Limits applied to new recordings; 30 minutes and 512 MiB unless changed.
+pub fn get_recording_limits() -> RecordingLimits {
+    session::default_session().limits()
+}
+
+#[uniffi::export]
+/// Register the listener notified when a recording reaches its limits.
+pub fn set_recording_event_listener(
+    listener: Arc<dyn RecordingEventListener>,
+) -> Result<(), CoreError> {
+    session::default_session().set_event_listener(Some(listener))
+}
+
+#[uniffi::export]
+/// Remove the listener registered with [`set_recording_event_listener`].
+pub fn clear_recording_event_listener() -> Result<(), CoreError> {
+    session::default_session().set_event_listener(None)
+}
+
+#[uniffi::export]
+/// List available input audio devices with their default and supported configurations.
+pub fn list_input_devices() -> Result<Vec<InputDeviceInfo>, CoreError> {
+    audio::list_input_devices()
 }
 
 #[uniffi::export]
 /// Stop microphone capture and return FLAC-encoded audio.
 ///
-/// The returned payload is optimized for transcription upload.
+/// The returned payload is optimized for transcription upload: leading and trailing
+/// silence is trimmed, and recordings without speech fail with
+/// [`CoreError::NoSpeechDetected`].
 pub fn stop_recording() -> Result<AudioData, CoreError> {
-    audio::stop_recording()
+    session::default_session().stop_with(&AudioProcessingOptions::default())
+}
+
+#[uniffi::export]
+/// Stop microphone capture and return FLAC-encoded audio using custom post-processing options.
+pub fn stop_recording_with_options(
+    options: AudioProcessingOptions,
+) -> Result<AudioData, CoreError> {
+    session::default_session().stop_with(&options)
+}
+
+#[uniffi::export]
+/// Live RMS, peak, clipping and waveform levels of the active recording.
+///
+/// Cheap enough to poll from a UI timer: levels are computed in the capture callback
+/// and read without locking. Returns silent levels when no recording is active.
+pub fn get_current_levels() -> AudioLevels {
+    session::default_session().levels()
+}
+
+#[uniffi::export]
+/// Decode an audio file (WAV, MP3, OGG Vorbis, FLAC or M4A/AAC/ALAC) and return
+/// FLAC-encoded audio ready for transcription.
+///
+/// The file is downmixed to mono, resampled to 16 kHz and processed exactly like a
+/// recording returned by [`stop_recording`]. Unsupported or corrupt files fail with
+/// [`CoreError::AudioProcessing`].
+pub fn prepare_audio_file(audio_bytes: Vec<u8>) -> Result<AudioData, CoreError> {
+    audio::prepare_audio_file(&audio_bytes, &AudioProcessingOptions::default())
+}
+
+#[uniffi::export]
+/// Decode an audio file and return FLAC-encoded audio using custom post-processing options.
+pub fn prepare_audio_file_with_options(
+    audio_bytes: Vec<u8>,
+    options: AudioProcessingOptions,
+) -> Result<AudioData, CoreError> {
+    audio::prepare_audio_file(&audio_bytes, &options)
 }
 
 #[uniffi::export]
@@ -53,6 +293,25 @@ pub fn transcribe_audio_bytes(
     language: Option<String>,
 ) -> Result<String, CoreError> {
     transcribe::transcribe_audio_bytes(
+        &TranscriptionProvider::Groq,
+        &SecretString::from(api_key),
+        &audio_bytes,
+        language.as_deref(),
+    )
+}
+
+#[uniffi::export]
+/// Transcribe encoded audio bytes with the selected transcription provider.
+///
+/// `api_key` may be empty for OpenAI-compatible servers that do not require authentication.
+pub fn transcribe_audio_bytes_with_provider(
+    provider: TranscriptionProvider,
+    api_key: String,
+    audio_bytes: Vec<u8>,
+    language: Option<String>,
+) -> Result<String, CoreError> {
+    transcribe::transcribe_audio_bytes(
+        &provider,
         &SecretString::from(api_key),
         &audio_bytes,
         language.as_deref(),
@@ -70,9 +329,102 @@ pub fn transcribe_audio_bytes_cancellable(
     cancellation_token: Arc<CancellationToken>,
 ) -> Result<String, CoreError> {
     transcribe::transcribe_audio_bytes_with_cancellation(
+        &TranscriptionProvider::Groq,
+        &SecretString::from(api_key),
+        &audio_bytes,
+        language.as_deref(),
+        Some(cancellation_token.as_ref()),
+    )
+}
+
+#[uniffi::export]
+/// Transcribe encoded audio bytes with the selected transcription provider.
+///
+/// Supports cooperative cancellation using a shared cancellation token.
+pub fn transcribe_audio_bytes_with_provider_cancellable(
+    provider: TranscriptionProvider,
+    api_key: String,
+    audio_bytes: Vec<u8>,
+    language: Option<String>,
+    cancellation_token: Arc<CancellationToken>,
+) -> Result<String, CoreError> {
+    transcribe::transcribe_audio_bytes_with_cancellation(
+        &provider,
+        &SecretString::from(api_key),
+        &audio_bytes,
+        language.as_deref(),
+        Some(cancellation_token.as_ref()),
+    )
+}
+
+#[uniffi::export]
+/// Transcribe encoded audio bytes and return segment timings, confidence and the
+/// detected language.
+///
+/// Set `word_timestamps` to also request per-word timings; not every provider or
+/// model supports them.
+pub fn transcribe_audio_detailed(
+    provider: TranscriptionProvider,
+    api_key: String,
+    audio_bytes: Vec<u8>,
+    language: Option<String>,
+    word_timestamps: bool,
+) -> Result<Transcript, CoreError> {
+    transcribe::transcribe_audio_detailed(
+        &provider,
+        &SecretString::from(api_key),
+        &audio_bytes,
+        language.as_deref(),
+        word_timestamps,
+        None,
+    )
+}
+
+#[uniffi::export]
+/// Transcribe encoded audio bytes with segment timings.
+///
+/// Supports cooperative cancellation using a shared cancellation token.
+pub fn transcribe_audio_detailed_cancellable(
+    provider: TranscriptionProvider,
+    api_key: String,
+    audio_bytes: Vec<u8>,
+    language: Option<String>,
+    word_timestamps: bool,
+    cancellation_token: Arc<CancellationToken>,
+) -> Result<Transcript, CoreError> {
+    transcribe::transcribe_audio_detailed(
+        &provider,
         &SecretString::from(api_key),
         &audio_bytes,
         language.as_deref(),
+        word_timestamps,
+        Some(cancellation_token.as_ref()),
+    )
+}
+
+#[uniffi::export]
+/// Transcribe a recording of any length by splitting it into chunks that
+/// stay under provider upload limits.
+///
+/// Each cut is placed at the quietest point shortly before the target chunk
+/// length, chunks are transcribed with up to `options.max_parallel_requests`
+/// concurrent requests, and words repeated across chunk overlaps are removed
+/// when the text is joined. Accepts any format supported by [`prepare_audio_file`];
+/// FLAC recordings shorter than one chunk are uploaded unchanged.
+pub fn transcribe_long_audio(
+    provider: TranscriptionProvider,
+    api_key: String,
+    audio_bytes: Vec<u8>,
+    language: Option<String>,
+    options: LongAudioOptions,
+    cancellation_token: Arc<CancellationToken>,
+) -> Result<String, CoreError> {
+    long_audio::transcribe_long_audio(
+        &provider,
+        &SecretString::from(api_key),
+        &audio_bytes,
+        language.as_deref(),
+        &options,
         Some(cancellation_token.as_ref()),
     )
 }
@@ -86,7 +438,7 @@ pub fn polish_text(
     context: Option<String>,
 ) -> Result<String, CoreError> {
     polish::polish_text(
-        provider,
+        &provider,
         &SecretString::from(api_key),
         &raw_text,
         context.as_deref(),
@@ -105,7 +457,7 @@ pub fn polish_text_cancellable(
     cancellation_token: Arc<CancellationToken>,
 ) -> Result<String, CoreError> {
     polish::polish_text_with_cancellation(
-        provider,
+        &provider,
         &SecretString::from(api_key),
         &raw_text,
         context.as_deref(),
@@ -113,6 +465,42 @@ pub fn polish_text_cancellable(
     )
 }
 
+#[uniffi::export]
+/// Polish raw transcript text, delivering generated text to `listener` as it streams in.
+///
+/// Returns the complete polished text. Cancelling the token stops the stream mid-response.
+pub fn polish_text_streaming(
+    provider: LlmProvider,
+    api_key: String,
+    raw_text: String,
+    context: Option<String>,
+    listener: Arc<dyn LlmStreamListener>,
+    cancellation_token: Arc<CancellationToken>,
+) -> Result<String, CoreError> {
+    polish::polish_text_streaming(
+        &provider,
+        &SecretString::from(api_key),
+        &raw_text,
+        context.as_deref(),
+        listener.as_ref(),
+        Some(cancellation_token.as_ref()),
+    )
+}
+
+#[uniffi::export]
+/// Transcribe and polish encoded audio in one call.
+///
+/// Both stages share `cancellation_token`. If polish fails for any reason other than
+/// cancellation, the result falls back to the raw transcript and records the failure in
+/// `polish_error`.
+pub fn run_pipeline(
+    audio_bytes: Vec<u8>,
+    options: PipelineOptions,
+    cancellation_token: Arc<CancellationToken>,
+) -> Result<PipelineResult, CoreError> {
+    pipeline::run_pipeline(&audio_bytes, &options, Some(cancellation_token.as_ref()))
+}
+
 /// Warm up TLS connection to Groq API
 ///
 /// Call this at the start of recording to eliminate TLS handshake latency.
@@ -139,7 +527,7 @@ pub fn warmup_groq_connection() -> Result<(), CoreError> {
 #[uniffi::export]
 /// Warm up TLS connection to the selected LLM provider.
 pub fn warmup_llm_connection(provider: LlmProvider) -> Result<(), CoreError> {
-    http_client::warmup_llm_connection(provider)
+    http_client::warmup_llm_connection(&provider)
 }
 
 /// Process text with the selected LLM provider.
@@ -153,12 +541,15 @@ pub fn process_text_with_llm(
     system_instruction: Option<String>,
     temperature: Option<f32>,
 ) -> Result<String, CoreError> {
-    llm_processor::process_text_with_llm(
-        provider,
+    llm_client::complete(
+        &provider,
         &SecretString::from(api_key),
-        &prompt,
-        system_instruction.as_deref(),
-        temperature,
+        &LlmRequest {
+            prompt: &prompt,
+            system_instruction: system_instruction.as_deref(),
+            temperature,
+        },
+        None,
     )
 }
 
@@ -174,12 +565,41 @@ pub fn process_text_with_llm_cancellable(
     temperature: Option<f32>,
     cancellation_token: Arc<CancellationToken>,
 ) -> Result<String, CoreError> {
-    llm_processor::process_text_with_llm_with_cancellation(
-        provider,
+    llm_client::complete(
+        &provider,
+        &SecretString::from(api_key),
+        &LlmRequest {
+            prompt: &prompt,
+            system_instruction: system_instruction.as_deref(),
+            temperature,
+        },
+        Some(cancellation_token.as_ref()),
+    )
+}
+
+#[uniffi::export]
+/// Process arbitrary text with the selected LLM provider, delivering generated text to
+/// `listener` as it streams in.
+///
+/// Returns the complete response. Cancelling the token stops the stream mid-response.
+pub fn process_text_with_llm_streaming(
+    provider: LlmProvider,
+    api_key: String,
+    prompt: String,
+    system_instruction: Option<String>,
+    temperature: Option<f32>,
+    listener: Arc<dyn LlmStreamListener>,
+    cancellation_token: Arc<CancellationToken>,
+) -> Result<String, CoreError> {
+    llm_client::complete_streaming(
+        &provider,
         &SecretString::from(api_key),
-        &prompt,
-        system_instruction.as_deref(),
-        temperature,
+        &LlmRequest {
+            prompt: &prompt,
+            system_instruction: system_instruction.as_deref(),
+            temperature,
+        },
+        listener.as_ref(),
         Some(cancellation_token.as_ref()),
     )
 }
@@ -0,0 +1,157 @@
+use crate::config::{LIMITER_CEILING_DB, LIMITER_LOOKAHEAD_MS, LIMITER_RELEASE_MS};
+use std::collections::VecDeque;
+
+/// What the limiter did to one signal.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct LimiterStats {
+    /// Samples above full scale on entry, which a hard clamp would have clipped.
+    pub(crate) clipped_samples: usize,
+    /// Samples whose gain was reduced.
+    pub(crate) limited_samples: usize,
+    /// Largest gain reduction applied, in dB.
+    pub(crate) max_gain_reduction_db: f32,
+}
+
+/// Minimum of `values` over each position and the `lookahead` positions after it.
+fn upcoming_min(values: &[f32], lookahead: usize) -> Vec<f32> {
+    let mut window: VecDeque<usize> = VecDeque::new();
+    let mut result = vec![0.0; values.len()];
+    // Walk backwards so the window always holds `i..=i + lookahead`.
+    for i in (0..values.len()).rev() {
+        while window.back().is_some_and(|&j| values[j] >= values[i]) {
+            window.pop_back();
+        }
+        window.push_back(i);
+        while window.front().is_some_and(|&j| j > i + lookahead) {
+            window.pop_front();
+        }
+        result[i] = values[window[0]];
+    }
+    result
+}
+
+/// Look-ahead peak limiter: keep every sample under the ceiling by smoothly lowering
+/// the gain just before a peak and letting it recover afterwards, instead of
+/// squaring off the waveform the way a hard clamp does.
+pub(crate) fn soft_limit(samples: &mut [f32], sample_rate: u32) -> LimiterStats {
+    let ceiling = 10f32.powf(LIMITER_CEILING_DB / 20.0);
+    let clipped_samples = samples.iter().filter(|s| s.abs() > 1.0).count();
+    let required: Vec<f32> = samples
+        .iter()
+        .map(|s| {
+            let amplitude = s.abs();
+            if amplitude > ceiling {
+                ceiling / amplitude
+            } else {
+                1.0
+            }
+        })
+        .collect();
+    if required.iter().all(|gain| *gain >= 1.0) {
+        return LimiterStats {
+            clipped_samples,
+            ..LimiterStats::default()
+        };
+    }
+
+    let lookahead =
+        (u64::from(sample_rate) * u64::from(LIMITER_LOOKAHEAD_MS) / 1000).max(1) as usize;
+    let release_samples = (sample_rate as f32 * LIMITER_RELEASE_MS as f32 / 1000.0).max(1.0);
+    let release = 1.0 - (-1.0 / release_samples).exp();
+
+    // Averaging the windowed minimum over the preceding `lookahead` samples turns each
+    // drop into a ramp that still reaches the required gain by the time the peak
+    // arrives, since every value in the average is at most that gain.
+    let minimum = upcoming_min(&required, lookahead);
+    let mut sum = f64::from(minimum[0]) * (lookahead + 1) as f64;
+    let mut gain = 1.0f32;
+    let mut min_gain = 1.0f32;
+    let mut limited_samples = 0;
+    for i in 0..samples.len() {
+        if i > 0 {
+            sum += f64::from(minimum[i]) - f64::from(minimum[i.saturating_sub(lookahead + 1)]);
+        }
+        let target = ((sum / (lookahead + 1) as f64) as f32).min(required[i]);
+        gain = if target < gain {
+            target
+        } else {
+            gain + (target - gain) * release
+        };
+        if gain < 1.0 {
+            samples[i] *= gain;
+            limited_samples += 1;
+            min_gain = min_gain.min(gain);
+        }
+    }
+
+    LimiterStats {
+        clipped_samples,
+        limited_samples,
+        max_gain_reduction_db: -20.0 * min_gain.log10(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::PI;
+
+    fn ceiling() -> f32 {
+        10f32.powf(LIMITER_CEILING_DB / 20.0)
+    }
+
+    #[test]
+    fn upcoming_min_should_cover_lookahead_window() {
+        let values = [5.0, 3.0, 4.0, 1.0, 6.0, 7.0, 2.0];
+        assert_eq!(
+            upcoming_min(&values, 2),
+            vec![3.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0]
+        );
+        assert_eq!(upcoming_min(&values, 0), values.to_vec());
+    }
+
+    #[test]
+    fn soft_limit_should_keep_loud_sine_under_ceiling() {
+        let mut samples: Vec<f32> = (0..16_000)
+            .map(|i| 2.5 * (2.0 * PI * 220.0 * i as f32 / 16_000.0).sin())
+            .collect();
+        let over_full_scale = samples.iter().filter(|s| s.abs() > 1.0).count();
+
+        let stats = soft_limit(&mut samples, 16_000);
+        assert!(samples.iter().all(|s| s.abs() <= ceiling() + 1e-6));
+        assert_eq!(stats.clipped_samples, over_full_scale);
+        assert!(stats.limited_samples > 0);
+        assert!((stats.max_gain_reduction_db - (20.0 * (2.5 / ceiling()).log10())).abs() < 0.1);
+    }
+
+    #[test]
+    fn soft_limit_should_ramp_gain_ahead_of_a_peak() {
+        let (quiet, loud) = (0.5f32, 2.0f32);
+        let mut samples = vec![quiet; 1_000];
+        samples.extend(vec![loud; 200]);
+        samples.extend(vec![quiet; 8_000]);
+        let input = samples.clone();
+        soft_limit(&mut samples, 16_000);
+
+        let gains: Vec<f32> = samples.iter().zip(&input).map(|(o, i)| o / i).collect();
+        let lookahead = (16_000 * LIMITER_LOOKAHEAD_MS / 1000) as usize;
+        assert_eq!(gains[1_000 - lookahead - 1], 1.0);
+        assert!(gains[1_000 - lookahead / 2] < 1.0);
+        assert!(gains[1_000] <= ceiling() / loud + 1e-6);
+        // No step larger than the look-ahead ramp, on the way down or back up.
+        let max_step = (1.0 - ceiling() / loud) / lookahead as f32 + 1e-4;
+        assert!(gains
+            .windows(2)
+            .all(|pair| (pair[1] - pair[0]).abs() <= max_step));
+        assert!(gains[gains.len() - 1] > 0.99);
+    }
+
+    #[test]
+    fn soft_limit_should_leave_quiet_signal_untouched() {
+        let input: Vec<f32> = (0..1_000).map(|i| 0.8 * (i as f32 * 0.05).sin()).collect();
+        let mut samples = input.clone();
+        assert_eq!(soft_limit(&mut samples, 16_000), LimiterStats::default());
+        assert_eq!(samples, input);
+        assert_eq!(soft_limit(&mut [], 16_000), LimiterStats::default());
+    }
+}
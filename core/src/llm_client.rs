@@ -0,0 +1,1297 @@
+use crate::cancellation::{
+    cancellation_requested, run_with_cancellation, worker_disconnected_message,
+    CancellableOperationError, CancellationToken,
+};
+use crate::config::{base_url, core_config, CoreConfig, ANTHROPIC_MAX_TOKENS, ANTHROPIC_VERSION};
+use crate::error::CoreError;
+use crate::http_client::get_http_client;
+use crate::retry::{
+    check_response_with, transport_error, with_retry, with_retry_cancellable, HttpResult,
+    RetryPolicy,
+};
+use crate::streaming::{execute_streaming_request, LlmStreamListener};
+use crate::LlmProvider;
+use reqwest::blocking::RequestBuilder;
+use reqwest::StatusCode;
+use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Gemini finish reasons that mean the output was withheld by policy.
+const GEMINI_BLOCKING_FINISH_REASONS: &[&str] = &[
+    "SAFETY",
+    "RECITATION",
+    "BLOCKLIST",
+    "PROHIBITED_CONTENT",
+    "SPII",
+];
+const GEMINI_MAX_OUTPUT_TOKENS: u32 = 4096;
+const OPENAI_CONTENT_FILTER_FINISH_REASON: &str = "content_filter";
+const ANTHROPIC_REFUSAL_STOP_REASON: &str = "refusal";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiResponse {
+    #[serde(default)]
+    candidates: Vec<GeminiCandidate>,
+    prompt_feedback: Option<GeminiPromptFeedback>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiPromptFeedback {
+    block_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiCandidate {
+    content: Option<GeminiContent>,
+    finish_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GeminiContent {
+    #[serde(default)]
+    parts: Vec<GeminiPart>,
+}
+
+#[derive(Deserialize)]
+struct GeminiPart {
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiResponse {
+    choices: Vec<OpenAiChoice>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiChoice {
+    message: OpenAiMessage,
+    finish_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiMessage {
+    content: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiStreamChunk {
+    #[serde(default)]
+    choices: Vec<OpenAiStreamChoice>,
+}
+
+#[derive(Deserialize)]
+struct OpenAiStreamChoice {
+    delta: Option<OpenAiMessage>,
+    finish_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicResponse {
+    #[serde(default)]
+    content: Vec<AnthropicContentBlock>,
+    stop_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicContentBlock {
+    #[serde(rename = "type")]
+    kind: String,
+    text: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicStreamEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    delta: Option<AnthropicStreamDelta>,
+    error: Option<AnthropicStreamError>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicStreamDelta {
+    text: Option<String>,
+    stop_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AnthropicStreamError {
+    message: String,
+}
+
+/// Provider-independent inputs of one LLM call.
+pub(crate) struct LlmRequest<'a> {
+    pub(crate) prompt: &'a str,
+    pub(crate) system_instruction: Option<&'a str>,
+    pub(crate) temperature: Option<f32>,
+}
+
+/// Wire format of one LLM provider: endpoint, authentication, request body,
+/// response parsing and status classification.
+pub(crate) trait LlmClient: Send + Sync {
+    /// Short provider name used in error messages.
+    fn name(&self) -> &'static str;
+
+    /// Endpoint for a regular or a streaming (Server-Sent Events) request.
+    fn endpoint(&self, stream: bool) -> String;
+
+    /// Attach the provider's authentication headers.
+    fn authenticate(&self, request: RequestBuilder, api_key: &str) -> RequestBuilder;
+
+    /// JSON request body; `stream` asks for a Server-Sent Events response.
+    fn build_body(&self, request: &LlmRequest<'_>, stream: bool) -> serde_json::Value;
+
+    /// Extract the generated text from a successful response body.
+    fn parse_response(&self, body: &[u8]) -> HttpResult<String>;
+
+    /// Extract the text carried by one streamed event payload, if any.
+    fn parse_stream_event(&self, data: &str) -> Result<Option<String>, CoreError>;
+
+    /// Map a non-200 response to an error.
+    fn classify_status(
+        &self,
+        status: StatusCode,
+        retry_after: Option<Duration>,
+        body: &str,
+    ) -> CoreError {
+        CoreError::from_status(self.name(), status, retry_after, body)
+    }
+}
+
+/// Resolve the client for `provider` against the given configuration.
+pub(crate) fn client_for(provider: &LlmProvider, config: &CoreConfig) -> Arc<dyn LlmClient> {
+    match provider {
+        LlmProvider::GoogleAiStudio => Arc::new(GeminiClient {
+            api_url: config.gemini_api_url.clone(),
+            model: config.gemini_model.clone(),
+        }),
+        LlmProvider::Openai => Arc::new(OpenAiClient {
+            name: "OpenAI",
+            api_url: config.openai_api_url.clone(),
+            model: config.openai_model.clone(),
+        }),
+        LlmProvider::Anthropic => Arc::new(AnthropicClient {
+            api_url: config.anthropic_api_url.clone(),
+            model: config.anthropic_model.clone(),
+        }),
+        LlmProvider::OpenAiCompatible { base_url, model } => Arc::new(OpenAiClient {
+            name: "LLM server",
+            api_url: base_url.clone(),
+            model: model.clone(),
+        }),
+    }
+}
+
+fn content_blocked<T>(provider: &str, reason: String) -> HttpResult<T> {
+    HttpResult::NonRetryable(CoreError::ContentBlocked {
+        provider: provider.to_string(),
+        reason,
+    })
+}
+
+fn trimmed_text(text: Option<String>) -> HttpResult<String> {
+    match text {
+        Some(value) => {
+            let trimmed = value.trim();
+            if trimmed.is_empty() {
+                HttpResult::NonRetryable(CoreError::EmptyResponse)
+            } else {
+                HttpResult::Success(trimmed.to_string())
+            }
+        }
+        None => HttpResult::NonRetryable(CoreError::EmptyResponse),
+    }
+}
+
+fn parse_json<T: serde::de::DeserializeOwned>(body: &[u8]) -> HttpResult<T> {
+    match serde_json::from_slice(body) {
+        Ok(payload) => HttpResult::Success(payload),
+        Err(e) => HttpResult::NonRetryable(e.into()),
+    }
+}
+
+/// Google AI Studio `generateContent`.
+struct GeminiClient {
+    api_url: String,
+    model: String,
+}
+
+impl LlmClient for GeminiClient {
+    fn name(&self) -> &'static str {
+        "Gemini"
+    }
+
+    fn endpoint(&self, stream: bool) -> String {
+        let method = if stream {
+            "streamGenerateContent?alt=sse"
+        } else {
+            "generateContent"
+        };
+        format!("{}/{}:{method}", base_url(&self.api_url), self.model)
+    }
+
+    fn authenticate(&self, request: RequestBuilder, api_key: &str) -> RequestBuilder {
+        request.header("x-goog-api-key", api_key)
+    }
+
+    // Gemini selects streaming through the endpoint, so the body is the same.
+    fn build_body(&self, request: &LlmRequest<'_>, _stream: bool) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "contents": [
+                {
+                    "role": "user",
+                    "parts": [{"text": request.prompt}],
+                }
+            ]
+        });
+
+        if let Some(instruction) = request.system_instruction {
+            body["systemInstruction"] = serde_json::json!({
+                "parts": [{"text": instruction}]
+            });
+        }
+
+        let mut generation_config = serde_json::Map::new();
+        if let Some(temp) = request.temperature {
+            generation_config.insert("temperature".to_string(), serde_json::json!(temp));
+        }
+        generation_config.insert(
+            "maxOutputTokens".to_string(),
+            serde_json::json!(GEMINI_MAX_OUTPUT_TOKENS),
+        );
+        body["generationConfig"] = serde_json::Value::Object(generation_config);
+        body
+    }
+
+    fn parse_response(&self, body: &[u8]) -> HttpResult<String> {
+        parse_json(body).and_then(extract_gemini_text)
+    }
+
+    fn parse_stream_event(&self, data: &str) -> Result<Option<String>, CoreError> {
+        let payload = serde_json::from_str::<GeminiResponse>(data)?;
+        if let Some(reason) = gemini_block_reason(&payload) {
+            return Err(CoreError::ContentBlocked {
+                provider: self.name().to_string(),
+                reason,
+            });
+        }
+        Ok(payload
+            .candidates
+            .into_iter()
+            .next()
+            .and_then(|candidate| candidate.content)
+            .map(|content| {
+                content
+                    .parts
+                    .into_iter()
+                    .filter_map(|part| part.text)
+                    .collect()
+            }))
+    }
+}
+
+/// Why Gemini withheld output: a blocked prompt or a policy finish reason.
+fn gemini_block_reason(payload: &GeminiResponse) -> Option<String> {
+    if let Some(reason) = payload
+        .prompt_feedback
+        .as_ref()
+        .and_then(|f| f.block_reason.clone())
+    {
+        return Some(reason);
+    }
+    payload
+        .candidates
+        .first()
+        .and_then(|c| c.finish_reason.as_deref())
+        .filter(|reason| GEMINI_BLOCKING_FINISH_REASONS.contains(reason))
+        .map(str::to_string)
+}
+
+fn extract_gemini_text(payload: GeminiResponse) -> HttpResult<String> {
+    if let Some(reason) = gemini_block_reason(&payload) {
+        return content_blocked("Gemini", reason);
+    }
+
+    let candidate = payload.candidates.first();
+    trimmed_text(
+        candidate
+            .and_then(|c| c.content.as_ref())
+            .and_then(|c| c.parts.first())
+            .and_then(|p| p.text.clone()),
+    )
+}
+
+/// OpenAI Chat Completions, also spoken by local servers such as Ollama and
+/// llama.cpp.
+struct OpenAiClient {
+    name: &'static str,
+    api_url: String,
+    model: String,
+}
+
+impl LlmClient for OpenAiClient {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn endpoint(&self, _stream: bool) -> String {
+        format!("{}/chat/completions", base_url(&self.api_url))
+    }
+
+    // Local servers usually run without authentication.
+    fn authenticate(&self, request: RequestBuilder, api_key: &str) -> RequestBuilder {
+        if api_key.is_empty() {
+            request
+        } else {
+            request.bearer_auth(api_key)
+        }
+    }
+
+    fn build_body(&self, request: &LlmRequest<'_>, stream: bool) -> serde_json::Value {
+        let mut messages = Vec::new();
+        if let Some(instruction) = request.system_instruction {
+            messages.push(serde_json::json!({
+                "role": "system",
+                "content": instruction,
+            }));
+        }
+        messages.push(serde_json::json!({
+            "role": "user",
+            "content": request.prompt,
+        }));
+
+        let mut body = serde_json::json!({
+            "model": self.model,
+            "messages": messages,
+        });
+
+        if let Some(temp) = request.temperature {
+            body["temperature"] = serde_json::json!(temp);
+        }
+        if stream {
+            body["stream"] = serde_json::json!(true);
+        }
+
+        body
+    }
+
+    fn parse_response(&self, body: &[u8]) -> HttpResult<String> {
+        parse_json(body).and_then(|payload| extract_openai_text(self.name, payload))
+    }
+
+    fn parse_stream_event(&self, data: &str) -> Result<Option<String>, CoreError> {
+        let Some(choice) = serde_json::from_str::<OpenAiStreamChunk>(data)?
+            .choices
+            .into_iter()
+            .next()
+        else {
+            return Ok(None);
+        };
+        if choice.finish_reason.as_deref() == Some(OPENAI_CONTENT_FILTER_FINISH_REASON) {
+            return Err(CoreError::ContentBlocked {
+                provider: self.name.to_string(),
+                reason: OPENAI_CONTENT_FILTER_FINISH_REASON.to_string(),
+            });
+        }
+        Ok(choice.delta.and_then(|delta| delta.content))
+    }
+}
+
+fn extract_openai_text(provider: &str, payload: OpenAiResponse) -> HttpResult<String> {
+    let choice = payload.choices.first();
+    if let Some(reason) = choice
+        .and_then(|choice| choice.finish_reason.as_deref())
+        .filter(|reason| *reason == OPENAI_CONTENT_FILTER_FINISH_REASON)
+    {
+        return content_blocked(provider, reason.to_string());
+    }
+
+    trimmed_text(choice.and_then(|choice| choice.message.content.clone()))
+}
+
+/// Anthropic Messages API.
+struct AnthropicClient {
+    api_url: String,
+    model: String,
+}
+
+impl LlmClient for AnthropicClient {
+    fn name(&self) -> &'static str {
+        "Anthropic"
+    }
+
+    fn endpoint(&self, _stream: bool) -> String {
+        format!("{}/messages", base_url(&self.api_url))
+    }
+
+    fn authenticate(&self, request: RequestBuilder, api_key: &str) -> RequestBuilder {
+        request
+            .header("x-api-key", api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+    }
+
+    fn build_body(&self, request: &LlmRequest<'_>, stream: bool) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "model": self.model,
+            "max_tokens": ANTHROPIC_MAX_TOKENS,
+            "messages": [
+                {
+                    "role": "user",
+                    "content": request.prompt,
+                }
+            ],
+        });
+
+        if let Some(instruction) = request.system_instruction {
+            body["system"] = serde_json::json!(instruction);
+        }
+        if let Some(temp) = request.temperature {
+            body["temperature"] = serde_json::json!(temp);
+        }
+        if stream {
+            body["stream"] = serde_json::json!(true);
+        }
+
+        body
+    }
+
+    fn parse_response(&self, body: &[u8]) -> HttpResult<String> {
+        parse_json(body).and_then(extract_anthropic_text)
+    }
+
+    /// Anthropic sends typed events; only `content_block_delta` carries text, and
+    /// errors or refusals can arrive after the stream has started.
+    fn parse_stream_event(&self, data: &str) -> Result<Option<String>, CoreError> {
+        let event = serde_json::from_str::<AnthropicStreamEvent>(data)?;
+        match event.kind.as_str() {
+            "content_block_delta" => Ok(event.delta.and_then(|delta| delta.text)),
+            "message_delta"
+                if event.delta.and_then(|delta| delta.stop_reason).as_deref()
+                    == Some(ANTHROPIC_REFUSAL_STOP_REASON) =>
+            {
+                Err(CoreError::ContentBlocked {
+                    provider: self.name().to_string(),
+                    reason: ANTHROPIC_REFUSAL_STOP_REASON.to_string(),
+                })
+            }
+            "error" => Err(CoreError::Api(format!(
+                "Anthropic stream error: {}",
+                event.error.map(|error| error.message).unwrap_or_default()
+            ))),
+            _ => Ok(None),
+        }
+    }
+}
+
+fn extract_anthropic_text(payload: AnthropicResponse) -> HttpResult<String> {
+    if payload.stop_reason.as_deref() == Some(ANTHROPIC_REFUSAL_STOP_REASON) {
+        return content_blocked("Anthropic", ANTHROPIC_REFUSAL_STOP_REASON.to_string());
+    }
+
+    // Responses are a list of content blocks; only `text` blocks carry output.
+    let text: String = payload
+        .content
+        .into_iter()
+        .filter(|block| block.kind == "text")
+        .filter_map(|block| block.text)
+        .collect();
+
+    trimmed_text(Some(text))
+}
+
+fn run_llm_with_retry(
+    policy: &RetryPolicy,
+    operation: impl FnMut() -> HttpResult<String>,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<String, CoreError> {
+    if let Some(token) = cancellation_token {
+        with_retry_cancellable(policy, operation, || token.is_cancelled())
+    } else {
+        with_retry(policy, operation)
+    }
+}
+
+/// Start an authenticated POST request to the client's endpoint.
+pub(crate) fn llm_request(
+    client: &dyn LlmClient,
+    http: &reqwest::blocking::Client,
+    url: &str,
+    api_key: &str,
+) -> RequestBuilder {
+    client.authenticate(http.post(url), api_key)
+}
+
+fn execute_llm_request(
+    client: &dyn LlmClient,
+    http: &reqwest::blocking::Client,
+    url: &str,
+    api_key: &str,
+    body: &serde_json::Value,
+) -> HttpResult<String> {
+    let response = llm_request(client, http, url, api_key).json(body).send();
+
+    match response {
+        Ok(resp) => check_response_with(resp, |status, delay, body| {
+            client.classify_status(status, delay, body)
+        })
+        .and_then(|resp| match resp.bytes() {
+            Ok(bytes) => client.parse_response(&bytes),
+            Err(e) => transport_error(client.name(), &e),
+        }),
+        Err(e) => transport_error(client.name(), &e),
+    }
+}
+
+fn execute_llm_request_cancellable(
+    client: &Arc<dyn LlmClient>,
+    http: &reqwest::blocking::Client,
+    url: &str,
+    api_key: &SecretString,
+    body: &serde_json::Value,
+    cancellation_token: Option<&CancellationToken>,
+) -> HttpResult<String> {
+    if cancellation_requested(cancellation_token) {
+        return HttpResult::NonRetryable(CoreError::Cancelled);
+    }
+
+    if cancellation_token.is_none() {
+        return execute_llm_request(client.as_ref(), http, url, api_key.expose_secret(), body);
+    }
+
+    let worker_client = Arc::clone(client);
+    let worker_http = http.clone();
+    let worker_url = url.to_string();
+    let worker_api_key = api_key.expose_secret().to_string();
+    let worker_body = body.clone();
+
+    match run_with_cancellation(cancellation_token, move || {
+        execute_llm_request(
+            worker_client.as_ref(),
+            &worker_http,
+            &worker_url,
+            &worker_api_key,
+            &worker_body,
+        )
+    }) {
+        Ok(result) => result,
+        Err(CancellableOperationError::Cancelled) => HttpResult::NonRetryable(CoreError::Cancelled),
+        Err(CancellableOperationError::WorkerDisconnected) => {
+            HttpResult::NonRetryable(CoreError::Http(worker_disconnected_message().to_string()))
+        }
+    }
+}
+
+/// Send `request` to `provider` and return the generated text.
+///
+/// Rate limits, server errors and network failures are retried according to
+/// the configured [`RetryPolicy`].
+pub(crate) fn complete(
+    provider: &LlmProvider,
+    api_key: &SecretString,
+    request: &LlmRequest<'_>,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<String, CoreError> {
+    if cancellation_requested(cancellation_token) {
+        return Err(CoreError::Cancelled);
+    }
+
+    let config = core_config();
+    let client = client_for(provider, &config);
+    let http = get_http_client();
+    let url = client.endpoint(false);
+    let body = client.build_body(request, false);
+
+    run_llm_with_retry(
+        &config.retry_policy,
+        || execute_llm_request_cancellable(&client, http, &url, api_key, &body, cancellation_token),
+        cancellation_token,
+    )
+}
+
+/// Send `request` to `provider`, streaming generated deltas to `listener`.
+///
+/// Returns the complete response once the stream ends. Retries only happen
+/// before the first delta arrives, so the listener never sees duplicated output.
+pub(crate) fn complete_streaming(
+    provider: &LlmProvider,
+    api_key: &SecretString,
+    request: &LlmRequest<'_>,
+    listener: &dyn LlmStreamListener,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<String, CoreError> {
+    if cancellation_requested(cancellation_token) {
+        return Err(CoreError::Cancelled);
+    }
+
+    let config = core_config();
+    let client = client_for(provider, &config);
+    let http = get_http_client();
+    let url = client.endpoint(true);
+    let body = client.build_body(request, true);
+
+    run_llm_with_retry(
+        &config.retry_policy,
+        || {
+            let request =
+                llm_request(client.as_ref(), http, &url, api_key.expose_secret()).json(&body);
+            execute_streaming_request(&client, request, listener, cancellation_token)
+        },
+        cancellation_token,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        client_for, complete, extract_anthropic_text, extract_gemini_text, extract_openai_text,
+        llm_request, run_llm_with_retry, AnthropicResponse, GeminiResponse, LlmClient, LlmRequest,
+        OpenAiResponse,
+    };
+    use crate::cancellation::CancellationToken;
+    use crate::config::CoreConfig;
+    use crate::error::CoreError;
+    use crate::retry::{HttpResult, RetryPolicy};
+    use crate::LlmProvider;
+    use secrecy::SecretString;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    fn fast_retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            base_delay_ms: 1,
+            max_delay_ms: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
+    fn client(provider: &LlmProvider) -> Arc<dyn LlmClient> {
+        client_for(provider, &CoreConfig::default())
+    }
+
+    fn compatible() -> LlmProvider {
+        LlmProvider::OpenAiCompatible {
+            base_url: "http://localhost:11434/v1/".to_string(),
+            model: "llama3.2".to_string(),
+        }
+    }
+
+    fn request<'a>(
+        prompt: &'a str,
+        system_instruction: Option<&'a str>,
+        temperature: Option<f32>,
+    ) -> LlmRequest<'a> {
+        LlmRequest {
+            prompt,
+            system_instruction,
+            temperature,
+        }
+    }
+
+    fn build_body(
+        provider: &LlmProvider,
+        prompt: &str,
+        system_instruction: Option<&str>,
+        temperature: Option<f32>,
+    ) -> serde_json::Value {
+        client(provider).build_body(&request(prompt, system_instruction, temperature), false)
+    }
+
+    #[test]
+    fn endpoint_should_follow_configured_base_urls() {
+        let config = CoreConfig {
+            gemini_api_url: "http://localhost/v1beta/models/".to_string(),
+            gemini_model: "gemini-test".to_string(),
+            openai_api_url: "http://localhost/v1/".to_string(),
+            anthropic_api_url: "http://localhost/anthropic/v1".to_string(),
+            ..CoreConfig::default()
+        };
+
+        let gemini = client_for(&LlmProvider::GoogleAiStudio, &config);
+        assert_eq!(
+            gemini.endpoint(false),
+            "http://localhost/v1beta/models/gemini-test:generateContent"
+        );
+        assert_eq!(
+            gemini.endpoint(true),
+            "http://localhost/v1beta/models/gemini-test:streamGenerateContent?alt=sse"
+        );
+
+        let openai = client_for(&LlmProvider::Openai, &config);
+        assert_eq!(
+            openai.endpoint(false),
+            "http://localhost/v1/chat/completions"
+        );
+        assert_eq!(openai.endpoint(true), openai.endpoint(false));
+
+        let anthropic = client_for(&LlmProvider::Anthropic, &config);
+        assert_eq!(
+            anthropic.endpoint(false),
+            "http://localhost/anthropic/v1/messages"
+        );
+    }
+
+    #[test]
+    fn endpoint_should_use_compatible_base_url() {
+        let compatible = client(&compatible());
+        assert_eq!(
+            compatible.endpoint(false),
+            "http://localhost:11434/v1/chat/completions"
+        );
+        assert_eq!(compatible.endpoint(true), compatible.endpoint(false));
+        assert_eq!(compatible.name(), "LLM server");
+    }
+
+    #[test]
+    fn llm_request_should_send_anthropic_key_and_version_headers() {
+        let http = reqwest::blocking::Client::new();
+        let request = llm_request(
+            client(&LlmProvider::Anthropic).as_ref(),
+            &http,
+            "http://localhost/v1/messages",
+            "secret",
+        )
+        .build()
+        .expect("request should build");
+
+        assert_eq!(request.headers()["x-api-key"], "secret");
+        assert_eq!(request.headers()["anthropic-version"], "2023-06-01");
+        assert!(request.headers().get("authorization").is_none());
+    }
+
+    #[test]
+    fn llm_request_should_skip_auth_for_keyless_compatible_server() {
+        let http = reqwest::blocking::Client::new();
+        let compatible = client(&compatible());
+        let keyless = llm_request(compatible.as_ref(), &http, "http://localhost/", "")
+            .build()
+            .expect("request should build");
+        let keyed = llm_request(compatible.as_ref(), &http, "http://localhost/", "secret")
+            .build()
+            .expect("request should build");
+
+        assert!(keyless.headers().get("authorization").is_none());
+        assert_eq!(keyed.headers()["authorization"], "Bearer secret");
+    }
+
+    #[test]
+    fn llm_request_should_send_gemini_key_header() {
+        let http = reqwest::blocking::Client::new();
+        let request = llm_request(
+            client(&LlmProvider::GoogleAiStudio).as_ref(),
+            &http,
+            "http://localhost/",
+            "secret",
+        )
+        .build()
+        .expect("request should build");
+
+        assert_eq!(request.headers()["x-goog-api-key"], "secret");
+    }
+
+    #[test]
+    fn build_body_should_include_required_sections() {
+        let body = build_body(&LlmProvider::GoogleAiStudio, "prompt", None, None);
+        assert_eq!(body["contents"][0]["role"], "user");
+        assert_eq!(body["contents"][0]["parts"][0]["text"], "prompt");
+        assert_eq!(body["generationConfig"]["maxOutputTokens"], 4096);
+    }
+
+    #[test]
+    fn build_body_should_include_system_instruction_when_provided() {
+        let body = build_body(
+            &LlmProvider::GoogleAiStudio,
+            "prompt",
+            Some("be concise"),
+            None,
+        );
+        assert_eq!(body["systemInstruction"]["parts"][0]["text"], "be concise");
+    }
+
+    #[test]
+    fn build_body_should_include_temperature_when_provided() {
+        let body = build_body(&LlmProvider::GoogleAiStudio, "prompt", None, Some(0.3));
+        let temperature = body["generationConfig"]["temperature"]
+            .as_f64()
+            .expect("temperature should be a number");
+        assert!((temperature - 0.3).abs() < 0.000_001);
+    }
+
+    #[test]
+    fn build_body_should_support_openai_provider() {
+        let body = build_body(
+            &LlmProvider::Openai,
+            "prompt",
+            Some("be concise"),
+            Some(0.7),
+        );
+
+        assert_eq!(body["model"], "gpt-5.4-nano");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][0]["content"], "be concise");
+        assert_eq!(body["messages"][1]["role"], "user");
+        assert_eq!(body["messages"][1]["content"], "prompt");
+        let temperature = body["temperature"]
+            .as_f64()
+            .expect("temperature should be a number");
+        assert!((temperature - 0.7).abs() < 0.000_001);
+    }
+
+    #[test]
+    fn build_body_should_use_openai_shape_for_compatible_server() {
+        let body = build_body(&compatible(), "hello", Some("be concise"), None);
+
+        assert_eq!(body["model"], "llama3.2");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][1]["content"], "hello");
+    }
+
+    #[test]
+    fn build_body_should_support_anthropic_provider() {
+        let body = build_body(
+            &LlmProvider::Anthropic,
+            "prompt",
+            Some("be concise"),
+            Some(0.2),
+        );
+
+        assert_eq!(body["model"], "claude-haiku-4-5");
+        assert_eq!(body["max_tokens"], 4096);
+        assert_eq!(body["system"], "be concise");
+        assert_eq!(body["messages"][0]["role"], "user");
+        assert_eq!(body["messages"][0]["content"], "prompt");
+        assert_eq!(body["messages"].as_array().map(Vec::len), Some(1));
+        let temperature = body["temperature"]
+            .as_f64()
+            .expect("temperature should be a number");
+        assert!((temperature - 0.2).abs() < 0.000_001);
+    }
+
+    #[test]
+    fn build_body_should_omit_anthropic_system_when_missing() {
+        let body = build_body(&LlmProvider::Anthropic, "prompt", None, None);
+        assert!(body.get("system").is_none());
+        assert!(body.get("temperature").is_none());
+    }
+
+    #[test]
+    fn build_body_should_skip_temperature_when_missing() {
+        let body = build_body(&LlmProvider::GoogleAiStudio, "prompt", None, None);
+        assert!(body["generationConfig"].get("temperature").is_none());
+    }
+
+    #[test]
+    fn build_body_should_flag_streaming_for_openai_and_anthropic_only() {
+        let prompt = request("prompt", None, None);
+        let openai = client(&LlmProvider::Openai).build_body(&prompt, true);
+        let anthropic = client(&LlmProvider::Anthropic).build_body(&prompt, true);
+        let gemini = client(&LlmProvider::GoogleAiStudio).build_body(&prompt, true);
+        let not_streaming = client(&LlmProvider::Openai).build_body(&prompt, false);
+
+        assert_eq!(openai["stream"], true);
+        assert_eq!(anthropic["stream"], true);
+        assert!(gemini.get("stream").is_none());
+        assert!(not_streaming.get("stream").is_none());
+    }
+
+    #[test]
+    fn parse_response_should_map_malformed_json_to_serialization_error() {
+        let result = client(&LlmProvider::Openai).parse_response(b"{not json");
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::Serialization(_))
+        ));
+    }
+
+    #[test]
+    fn parse_response_should_name_compatible_server_when_blocked() {
+        let body =
+            br#"{"choices":[{"message":{"content":null},"finish_reason":"content_filter"}]}"#;
+        let result = client(&compatible()).parse_response(body);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::ContentBlocked { provider, .. }) if provider == "LLM server"
+        ));
+    }
+
+    #[test]
+    fn classify_status_should_tag_errors_with_provider_name() {
+        let error = client(&LlmProvider::Anthropic).classify_status(
+            reqwest::StatusCode::UNAUTHORIZED,
+            None,
+            "bad key",
+        );
+        assert!(matches!(
+            error,
+            CoreError::Unauthorized { provider, status: 401, .. } if provider == "Anthropic"
+        ));
+    }
+
+    #[test]
+    fn extract_gemini_text_should_return_trimmed_value() {
+        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
+            "candidates": [{"content": {"parts": [{"text": "  result  "}]}}]
+        }))
+        .expect("valid payload");
+        let result = extract_gemini_text(payload);
+        assert!(matches!(result, HttpResult::Success(text) if text == "result"));
+    }
+
+    #[test]
+    fn extract_gemini_text_should_fail_when_text_missing() {
+        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
+            "candidates": [{"content": {"parts": [{"text": null}]}}]
+        }))
+        .expect("valid payload");
+        let result = extract_gemini_text(payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn extract_gemini_text_should_fail_when_text_blank() {
+        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
+            "candidates": [{"content": {"parts": [{"text": "   "} ]}}]
+        }))
+        .expect("valid payload");
+        let result = extract_gemini_text(payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn extract_gemini_text_should_return_empty_response_when_candidates_are_missing() {
+        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
+            "candidates": []
+        }))
+        .expect("valid payload");
+        let result = extract_gemini_text(payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn extract_gemini_text_should_report_blocked_prompt() {
+        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
+            "promptFeedback": {"blockReason": "SAFETY"}
+        }))
+        .expect("valid payload");
+        let result = extract_gemini_text(payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::ContentBlocked { provider, reason })
+                if provider == "Gemini" && reason == "SAFETY"
+        ));
+    }
+
+    #[test]
+    fn extract_gemini_text_should_report_safety_finish_reason() {
+        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
+            "candidates": [{"finishReason": "SAFETY"}]
+        }))
+        .expect("valid payload");
+        let result = extract_gemini_text(payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::ContentBlocked { reason, .. }) if reason == "SAFETY"
+        ));
+    }
+
+    #[test]
+    fn extract_gemini_text_should_treat_normal_stop_without_text_as_empty() {
+        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
+            "candidates": [{"content": {"parts": []}, "finishReason": "STOP"}]
+        }))
+        .expect("valid payload");
+        let result = extract_gemini_text(payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn extract_openai_text_should_return_trimmed_value() {
+        let payload: OpenAiResponse = serde_json::from_value(serde_json::json!({
+            "choices": [{"message": {"content": "  result  "}}]
+        }))
+        .expect("valid payload");
+        let result = extract_openai_text("OpenAI", payload);
+        assert!(matches!(result, HttpResult::Success(text) if text == "result"));
+    }
+
+    #[test]
+    fn extract_openai_text_should_fail_when_text_missing() {
+        let payload: OpenAiResponse = serde_json::from_value(serde_json::json!({
+            "choices": [{"message": {"content": null}}]
+        }))
+        .expect("valid payload");
+        let result = extract_openai_text("OpenAI", payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn extract_openai_text_should_return_empty_response_when_choices_are_missing() {
+        let payload: OpenAiResponse = serde_json::from_value(serde_json::json!({
+            "choices": []
+        }))
+        .expect("valid payload");
+        let result = extract_openai_text("OpenAI", payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn extract_openai_text_should_report_content_filter() {
+        let payload: OpenAiResponse = serde_json::from_value(serde_json::json!({
+            "choices": [{"message": {"content": null}, "finish_reason": "content_filter"}]
+        }))
+        .expect("valid payload");
+        let result = extract_openai_text("OpenAI", payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::ContentBlocked { provider, .. }) if provider == "OpenAI"
+        ));
+    }
+
+    #[test]
+    fn extract_anthropic_text_should_join_text_blocks() {
+        let payload: AnthropicResponse = serde_json::from_value(serde_json::json!({
+            "content": [
+                {"type": "text", "text": "  first "},
+                {"type": "tool_use", "id": "x", "name": "noop", "input": {}},
+                {"type": "text", "text": "second  "}
+            ],
+            "stop_reason": "end_turn"
+        }))
+        .expect("valid payload");
+        let result = extract_anthropic_text(payload);
+        assert!(matches!(result, HttpResult::Success(text) if text == "first second"));
+    }
+
+    #[test]
+    fn extract_anthropic_text_should_fail_when_content_empty() {
+        let payload: AnthropicResponse = serde_json::from_value(serde_json::json!({
+            "content": [],
+            "stop_reason": "end_turn"
+        }))
+        .expect("valid payload");
+        let result = extract_anthropic_text(payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn extract_anthropic_text_should_report_refusal() {
+        let payload: AnthropicResponse = serde_json::from_value(serde_json::json!({
+            "content": [],
+            "stop_reason": "refusal"
+        }))
+        .expect("valid payload");
+        let result = extract_anthropic_text(payload);
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::ContentBlocked { provider, reason })
+                if provider == "Anthropic" && reason == "refusal"
+        ));
+    }
+
+    #[test]
+    fn parse_stream_event_should_join_gemini_parts() {
+        let data = r#"{"candidates":[{"content":{"parts":[{"text":"Hel"},{"text":"lo"}],"role":"model"}}]}"#;
+        let delta = client(&LlmProvider::GoogleAiStudio)
+            .parse_stream_event(data)
+            .expect("valid chunk");
+        assert_eq!(delta.as_deref(), Some("Hello"));
+    }
+
+    #[test]
+    fn parse_stream_event_should_skip_gemini_chunks_without_content() {
+        let data = r#"{"candidates":[{"finishReason":"STOP"}],"usageMetadata":{}}"#;
+        let delta = client(&LlmProvider::GoogleAiStudio)
+            .parse_stream_event(data)
+            .expect("valid chunk");
+        assert_eq!(delta, None);
+    }
+
+    #[test]
+    fn parse_stream_event_should_report_gemini_blocking_finish_reason() {
+        let gemini = client(&LlmProvider::GoogleAiStudio);
+        let blocked =
+            r#"{"candidates":[{"content":{"parts":[{"text":"Par"}]},"finishReason":"SAFETY"}]}"#;
+        assert!(matches!(
+            gemini.parse_stream_event(blocked),
+            Err(CoreError::ContentBlocked { provider, reason })
+                if provider == "Gemini" && reason == "SAFETY"
+        ));
+
+        let prompt_blocked = r#"{"promptFeedback":{"blockReason":"OTHER"}}"#;
+        assert!(matches!(
+            gemini.parse_stream_event(prompt_blocked),
+            Err(CoreError::ContentBlocked { reason, .. }) if reason == "OTHER"
+        ));
+    }
+
+    #[test]
+    fn parse_stream_event_should_report_openai_content_filter() {
+        let data = r#"{"choices":[{"index":0,"delta":{},"finish_reason":"content_filter"}]}"#;
+        assert!(matches!(
+            client(&LlmProvider::Openai).parse_stream_event(data),
+            Err(CoreError::ContentBlocked { provider, reason })
+                if provider == "OpenAI" && reason == "content_filter"
+        ));
+
+        let done = r#"{"choices":[{"index":0,"delta":{},"finish_reason":"stop"}]}"#;
+        assert!(matches!(
+            client(&LlmProvider::Openai).parse_stream_event(done),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn parse_stream_event_should_read_openai_delta_content() {
+        let data = r#"{"choices":[{"index":0,"delta":{"content":"Hi"}}]}"#;
+        let delta = client(&LlmProvider::Openai)
+            .parse_stream_event(data)
+            .expect("valid chunk");
+        assert_eq!(delta.as_deref(), Some("Hi"));
+    }
+
+    #[test]
+    fn parse_stream_event_should_reject_malformed_json() {
+        assert!(matches!(
+            client(&LlmProvider::Openai).parse_stream_event("{not json"),
+            Err(CoreError::Serialization(_))
+        ));
+    }
+
+    #[test]
+    fn parse_stream_event_should_read_anthropic_text_deltas_only() {
+        let anthropic = client(&LlmProvider::Anthropic);
+        let delta =
+            r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hi"}}"#;
+        let ping = r#"{"type":"ping"}"#;
+        let stop = r#"{"type":"message_delta","delta":{"stop_reason":"end_turn"},"usage":{}}"#;
+        let text = anthropic.parse_stream_event(delta).expect("valid event");
+        assert_eq!(text.as_deref(), Some("Hi"));
+        assert!(matches!(anthropic.parse_stream_event(ping), Ok(None)));
+        assert!(matches!(anthropic.parse_stream_event(stop), Ok(None)));
+    }
+
+    #[test]
+    fn parse_stream_event_should_surface_anthropic_errors_and_refusals() {
+        let anthropic = client(&LlmProvider::Anthropic);
+        let error =
+            r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#;
+        let refusal = r#"{"type":"message_delta","delta":{"stop_reason":"refusal"}}"#;
+        assert!(matches!(
+            anthropic.parse_stream_event(error),
+            Err(CoreError::Api(message)) if message.contains("Overloaded")
+        ));
+        assert!(matches!(
+            anthropic.parse_stream_event(refusal),
+            Err(CoreError::ContentBlocked { .. })
+        ));
+    }
+
+    #[test]
+    fn run_llm_with_retry_should_retry_until_third_attempt_success() {
+        let attempts = AtomicU32::new(0);
+
+        let result = run_llm_with_retry(
+            &fast_retry_policy(),
+            || {
+                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+                if attempt < 2 {
+                    HttpResult::Retryable {
+                        error: CoreError::Http("connection reset".to_string()),
+                        delay: None,
+                    }
+                } else {
+                    HttpResult::Success("ok".to_string())
+                }
+            },
+            None,
+        );
+
+        assert!(matches!(result, Ok(value) if value == "ok"));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn run_llm_with_retry_should_fail_after_retry_budget_exhausted() {
+        let attempts = AtomicU32::new(0);
+
+        let result = run_llm_with_retry(
+            &fast_retry_policy(),
+            || {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                HttpResult::Retryable {
+                    error: CoreError::ApiStatus {
+                        provider: "Gemini".to_string(),
+                        status: 503,
+                        body: String::new(),
+                    },
+                    delay: None,
+                }
+            },
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            Err(CoreError::ApiStatus { status: 503, .. })
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn run_llm_with_retry_should_return_cancelled_before_first_attempt() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let attempts = AtomicU32::new(0);
+
+        let result = run_llm_with_retry(
+            &fast_retry_policy(),
+            || {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                HttpResult::Success("ok".to_string())
+            },
+            Some(token.as_ref()),
+        );
+
+        assert!(matches!(result, Err(CoreError::Cancelled)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn complete_should_fail_fast_when_cancelled() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = complete(
+            &LlmProvider::GoogleAiStudio,
+            &SecretString::from("test-key".to_string()),
+            &request("prompt", None, None),
+            Some(token.as_ref()),
+        );
+
+        assert!(matches!(result, Err(CoreError::Cancelled)));
+    }
+}
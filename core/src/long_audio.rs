@@ -0,0 +1,473 @@
+use crate::audio::{decode_audio_samples, flac_bytes_from_samples};
+use crate::cancellation::{cancellation_requested, worker_disconnected_message, CancellationToken};
+use crate::config::{
+    LONG_AUDIO_CHUNK_SECONDS, LONG_AUDIO_MAX_PARALLEL_REQUESTS, LONG_AUDIO_OVERLAP_MS,
+    LONG_AUDIO_SPLIT_SEARCH_SECONDS, VAD_FRAME_MS, WHISPER_SAMPLE_RATE,
+};
+use crate::error::CoreError;
+use crate::transcribe::{self, comparable_word};
+use crate::TranscriptionProvider;
+use secrecy::SecretString;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+
+/// Longest run of words compared when removing text repeated across a chunk overlap.
+const MAX_OVERLAP_WORDS: usize = 12;
+
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+/// Chunking options for [`crate::transcribe_long_audio`].
+pub struct LongAudioOptions {
+    /// Target chunk length in seconds. Keep it well under the provider's upload
+    /// limit; five minutes of 16 kHz FLAC is roughly 6 MB.
+    #[uniffi(default = 300)]
+    pub chunk_seconds: u32,
+    /// How far before each target boundary to look for the quietest cut point, in seconds.
+    #[uniffi(default = 20)]
+    pub split_search_seconds: u32,
+    /// Audio repeated at the start of each chunk so a word cut at the boundary is
+    /// heard whole at least once, in milliseconds.
+    #[uniffi(default = 1000)]
+    pub overlap_ms: u32,
+    /// Maximum number of chunks transcribed at the same time; 1 transcribes in order.
+    #[uniffi(default = 3)]
+    pub max_parallel_requests: u32,
+}
+
+impl Default for LongAudioOptions {
+    fn default() -> Self {
+        Self {
+            chunk_seconds: LONG_AUDIO_CHUNK_SECONDS,
+            split_search_seconds: LONG_AUDIO_SPLIT_SEARCH_SECONDS,
+            overlap_ms: LONG_AUDIO_OVERLAP_MS,
+            max_parallel_requests: LONG_AUDIO_MAX_PARALLEL_REQUESTS,
+        }
+    }
+}
+
+impl LongAudioOptions {
+    fn validate(&self) -> Result<(), CoreError> {
+        if self.chunk_seconds == 0 {
+            return Err(CoreError::Config(
+                "chunk_seconds must be greater than 0".to_string(),
+            ));
+        }
+        if u64::from(self.overlap_ms) >= u64::from(self.chunk_seconds) * 1000 / 2 {
+            return Err(CoreError::Config(
+                "overlap_ms must be less than half of chunk_seconds".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Sample ranges to transcribe, in order.
+///
+/// Each boundary is placed at the quietest frame within the search window that
+/// ends at the target chunk length, so chunks never exceed the target length
+/// plus the overlap. Every chunk after the first starts `overlap_ms` before the
+/// previous cut.
+fn plan_chunks(samples: &[f32], sample_rate: u32, options: &LongAudioOptions) -> Vec<Range<usize>> {
+    let rate = u64::from(sample_rate);
+    let chunk_len = (rate * u64::from(options.chunk_seconds)) as usize;
+    let search_len = ((rate * u64::from(options.split_search_seconds)) as usize).min(chunk_len / 2);
+    let overlap = (rate * u64::from(options.overlap_ms) / 1000) as usize;
+    let frame_len = (sample_rate * VAD_FRAME_MS / 1000).max(1) as usize;
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut cut = 0;
+    while samples.len() - cut > chunk_len {
+        let target = cut + chunk_len;
+        let next_cut = quietest_point(samples, target - search_len..target, frame_len);
+        chunks.push(start..next_cut);
+        start = next_cut.saturating_sub(overlap);
+        cut = next_cut;
+    }
+    chunks.push(start..samples.len());
+    chunks
+}
+
+/// Centre of the lowest-energy frame inside `window`, preferring the latest
+/// one on ties, or the window end when the window is shorter than one frame.
+fn quietest_point(samples: &[f32], window: Range<usize>, frame_len: usize) -> usize {
+    samples[window.clone()]
+        .chunks_exact(frame_len)
+        .enumerate()
+        .rev()
+        .map(|(index, frame)| (index, frame.iter().map(|s| s * s).sum::<f32>()))
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(window.end, |(index, _)| {
+            window.start + index * frame_len + frame_len / 2
+        })
+}
+
+/// Run `transcribe_chunk` for every chunk index with at most `parallelism`
+/// workers, returning the results in chunk order.
+///
+/// Workers stop picking up new chunks after the first failure or once
+/// cancellation is requested; the error for the earliest failed chunk is returned.
+fn transcribe_chunks<T: Send>(
+    count: usize,
+    parallelism: usize,
+    cancellation_token: Option<&CancellationToken>,
+    transcribe_chunk: impl Fn(usize) -> Result<T, CoreError> + Sync,
+) -> Result<Vec<T>, CoreError> {
+    let next = AtomicUsize::new(0);
+    let failed = AtomicBool::new(false);
+
+    let mut results: Vec<Option<Result<T, CoreError>>> = (0..count).map(|_| None).collect();
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..parallelism.clamp(1, count.max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut finished = Vec::new();
+                    loop {
+                        if failed.load(Ordering::SeqCst)
+                            || cancellation_requested(cancellation_token)
+                        {
+                            break;
+                        }
+                        let index = next.fetch_add(1, Ordering::SeqCst);
+                        if index >= count {
+                            break;
+                        }
+                        let result = transcribe_chunk(index);
+                        if result.is_err() {
+                            failed.store(true, Ordering::SeqCst);
+                        }
+                        finished.push((index, result));
+                    }
+                    finished
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            if let Ok(finished) = worker.join() {
+                for (index, result) in finished {
+                    results[index] = Some(result);
+                }
+            }
+        }
+    });
+
+    if cancellation_requested(cancellation_token) {
+        return Err(CoreError::Cancelled);
+    }
+    results
+        .into_iter()
+        .map(|result| {
+            result
+                .unwrap_or_else(|| Err(CoreError::Http(worker_disconnected_message().to_string())))
+        })
+        .collect()
+}
+
+/// Number of leading words of `next` that repeat the trailing words of `previous`.
+fn overlap_word_count(previous: &str, next: &str) -> usize {
+    let mut tail: Vec<String> = previous
+        .split_whitespace()
+        .rev()
+        .take(MAX_OVERLAP_WORDS)
+        .map(comparable_word)
+        .collect();
+    tail.reverse();
+    let head: Vec<String> = next
+        .split_whitespace()
+        .take(MAX_OVERLAP_WORDS)
+        .map(comparable_word)
+        .collect();
+
+    (1..=tail.len().min(head.len()))
+        .rev()
+        .find(|&n| tail[tail.len() - n..] == head[..n])
+        .unwrap_or(0)
+}
+
+/// Join chunk transcripts, dropping words a chunk repeats from the end of the
+/// previous one.
+fn stitch_transcripts(texts: &[String]) -> String {
+    let mut merged = String::new();
+    for text in texts.iter().map(|text| text.trim()) {
+        if text.is_empty() {
+            continue;
+        }
+        let skip = overlap_word_count(&merged, text);
+        for word in text.split_whitespace().skip(skip) {
+            if !merged.is_empty() {
+                merged.push(' ');
+            }
+            merged.push_str(word);
+        }
+    }
+    merged
+}
+
+pub(crate) fn transcribe_long_audio(
+    provider: &TranscriptionProvider,
+    api_key: &SecretString,
+    audio_bytes: &[u8],
+    language: Option<&str>,
+    options: &LongAudioOptions,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<String, CoreError> {
+    options.validate()?;
+    if cancellation_requested(cancellation_token) {
+        return Err(CoreError::Cancelled);
+    }
+
+    let samples = decode_audio_samples(audio_bytes)?;
+    let is_flac = audio_bytes.starts_with(b"fLaC");
+    transcribe_samples_with(&samples, options, cancellation_token, |chunk| {
+        let encoded;
+        let bytes = match chunk {
+            Some(chunk) => {
+                encoded = flac_bytes_from_samples(chunk)?;
+                &encoded[..]
+            }
+            None if is_flac => audio_bytes,
+            None => {
+                encoded = flac_bytes_from_samples(&samples)?;
+                &encoded[..]
+            }
+        };
+        transcribe::transcribe_audio_bytes_with_cancellation(
+            provider,
+            api_key,
+            bytes,
+            language,
+            cancellation_token,
+        )
+    })
+}
+
+/// Chunking control flow with the provider call injected, so splitting and
+/// stitching can be tested without network access.
+///
+/// `transcribe_chunk` receives `None` when the recording fits in one chunk and
+/// the original bytes can be uploaded unchanged.
+fn transcribe_samples_with(
+    samples: &[f32],
+    options: &LongAudioOptions,
+    cancellation_token: Option<&CancellationToken>,
+    transcribe_chunk: impl Fn(Option<&[f32]>) -> Result<String, CoreError> + Sync,
+) -> Result<String, CoreError> {
+    let chunks = plan_chunks(samples, WHISPER_SAMPLE_RATE, options);
+    if chunks.len() == 1 {
+        return transcribe_chunk(None);
+    }
+
+    log::debug!(
+        "Transcribing {} chunks with up to {} parallel requests",
+        chunks.len(),
+        options.max_parallel_requests
+    );
+    let texts = transcribe_chunks(
+        chunks.len(),
+        options.max_parallel_requests as usize,
+        cancellation_token,
+        |index| match transcribe_chunk(Some(&samples[chunks[index].clone()])) {
+            // A chunk of pure silence is not an error for the recording as a whole.
+            Err(CoreError::EmptyResponse | CoreError::NoSpeechDetected) => Ok(String::new()),
+            result => result,
+        },
+    )?;
+
+    let text = stitch_transcripts(&texts);
+    if text.is_empty() {
+        return Err(CoreError::NoSpeechDetected);
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        overlap_word_count, plan_chunks, stitch_transcripts, transcribe_chunks,
+        transcribe_samples_with, LongAudioOptions,
+    };
+    use crate::cancellation::CancellationToken;
+    use crate::error::CoreError;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    const RATE: u32 = 1_000;
+
+    fn options(chunk_seconds: u32, overlap_ms: u32) -> LongAudioOptions {
+        LongAudioOptions {
+            chunk_seconds,
+            split_search_seconds: 2,
+            overlap_ms,
+            max_parallel_requests: 2,
+        }
+    }
+
+    /// Loud tone with silent gaps starting at each offset in `gaps` (in samples).
+    fn tone_with_gaps(len: usize, gaps: &[usize]) -> Vec<f32> {
+        (0..len)
+            .map(|i| {
+                if gaps.iter().any(|gap| (*gap..*gap + 100).contains(&i)) {
+                    0.0
+                } else if i % 2 == 0 {
+                    0.5
+                } else {
+                    -0.5
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn plan_chunks_should_return_single_chunk_for_short_audio() {
+        let samples = vec![0.1; 4_000];
+        let chunks = plan_chunks(&samples, RATE, &options(5, 0));
+        assert_eq!(chunks, vec![0..4_000]);
+    }
+
+    #[test]
+    fn plan_chunks_should_cut_at_quiet_points_before_target_length() {
+        let samples = tone_with_gaps(12_000, &[4_200, 8_500]);
+        let chunks = plan_chunks(&samples, RATE, &options(5, 0));
+
+        assert_eq!(chunks.len(), 3);
+        assert!((4_200..4_300).contains(&chunks[0].end));
+        assert!((8_500..8_600).contains(&chunks[1].end));
+        assert_eq!(chunks[1].start, chunks[0].end);
+        assert_eq!(chunks[2].end, 12_000);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= 5_000));
+    }
+
+    #[test]
+    fn plan_chunks_should_start_later_chunks_inside_previous_overlap() {
+        let samples = tone_with_gaps(9_000, &[4_200]);
+        let chunks = plan_chunks(&samples, RATE, &options(5, 500));
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[1].start, chunks[0].end - 500);
+    }
+
+    #[test]
+    fn overlap_word_count_should_ignore_case_and_punctuation() {
+        assert_eq!(
+            overlap_word_count("we should ship it today.", "Today, we ship"),
+            1
+        );
+        assert_eq!(overlap_word_count("see you at the", "at the station"), 2);
+        assert_eq!(overlap_word_count("nothing shared", "here at all"), 0);
+        assert_eq!(overlap_word_count("", "anything"), 0);
+    }
+
+    #[test]
+    fn stitch_transcripts_should_drop_repeated_overlap_and_skip_empty_chunks() {
+        let texts = vec![
+            "The quarterly numbers look".to_string(),
+            "numbers look good overall.".to_string(),
+            "   ".to_string(),
+            "Next item.".to_string(),
+        ];
+        assert_eq!(
+            stitch_transcripts(&texts),
+            "The quarterly numbers look good overall. Next item."
+        );
+    }
+
+    #[test]
+    fn transcribe_chunks_should_return_results_in_chunk_order() {
+        let active = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let results = transcribe_chunks(6, 2, None, |index| {
+            let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(((6 - index) * 3) as u64));
+            active.fetch_sub(1, Ordering::SeqCst);
+            Ok(index)
+        })
+        .expect("all chunks should succeed");
+
+        assert_eq!(results, vec![0, 1, 2, 3, 4, 5]);
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+
+    #[test]
+    fn transcribe_chunks_should_stop_after_first_failure() {
+        let calls = AtomicUsize::new(0);
+        let result: Result<Vec<()>, CoreError> = transcribe_chunks(10, 1, None, |index| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            if index == 2 {
+                Err(CoreError::Transcription("boom".to_string()))
+            } else {
+                Ok(())
+            }
+        });
+
+        assert!(matches!(result, Err(CoreError::Transcription(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn transcribe_chunks_should_report_cancellation() {
+        let token = CancellationToken::new();
+        let result = transcribe_chunks(4, 1, Some(token.as_ref()), |_| {
+            token.cancel();
+            Ok(())
+        });
+        assert!(matches!(result, Err(CoreError::Cancelled)));
+    }
+
+    #[test]
+    fn transcribe_samples_with_should_upload_original_bytes_for_short_audio() {
+        let samples = vec![0.1; 16_000];
+        let text = transcribe_samples_with(&samples, &LongAudioOptions::default(), None, |chunk| {
+            assert!(chunk.is_none());
+            Ok("short".to_string())
+        })
+        .expect("short audio should transcribe");
+        assert_eq!(text, "short");
+    }
+
+    #[test]
+    fn transcribe_samples_with_should_stitch_chunks_and_tolerate_silent_chunks() {
+        let samples = vec![0.1; 16_000 * 7];
+        let options = LongAudioOptions {
+            max_parallel_requests: 1,
+            ..options(3, 500)
+        };
+        let calls = AtomicUsize::new(0);
+        let text = transcribe_samples_with(&samples, &options, None, |chunk| {
+            assert!(chunk.is_some_and(|chunk| chunk.len() <= 16_000 * 3 + 8_000));
+            match calls.fetch_add(1, Ordering::SeqCst) {
+                0 => Ok("first part".to_string()),
+                1 => Err(CoreError::NoSpeechDetected),
+                _ => Ok("Part again.".to_string()),
+            }
+        })
+        .expect("chunked audio should transcribe");
+
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(text, "first part again.");
+    }
+
+    #[test]
+    fn transcribe_samples_with_should_fail_when_every_chunk_is_silent() {
+        let samples = vec![0.1; 16_000 * 7];
+        let result = transcribe_samples_with(&samples, &options(3, 500), None, |_| {
+            Err(CoreError::EmptyResponse)
+        });
+        assert!(matches!(result, Err(CoreError::NoSpeechDetected)));
+    }
+
+    #[test]
+    fn validate_should_reject_zero_chunk_length_and_oversized_overlap() {
+        assert!(matches!(
+            options(0, 0).validate(),
+            Err(CoreError::Config(_))
+        ));
+        assert!(matches!(
+            options(2, 1_000).validate(),
+            Err(CoreError::Config(_))
+        ));
+        assert!(LongAudioOptions::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,268 @@
+use crate::config::{
+    METER_CLIP_HOLD_MS, METER_CLIP_THRESHOLD, METER_FLOOR_DB, METER_WAVEFORM_BINS,
+    METER_WAVEFORM_BIN_MS,
+};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+
+#[derive(Clone, Debug, PartialEq, uniffi::Record)]
+/// Live signal levels of the active recording.
+pub struct AudioLevels {
+    /// Whether a recording is currently capturing audio.
+    pub is_recording: bool,
+    /// RMS amplitude of the latest capture buffer, from 0.0 to 1.0.
+    pub rms: f32,
+    /// Peak amplitude of the latest capture buffer, from 0.0 to 1.0.
+    pub peak: f32,
+    /// RMS level in dBFS, floored at -100 dB for silence.
+    pub rms_db: f32,
+    /// Peak level in dBFS, floored at -100 dB for silence.
+    pub peak_db: f32,
+    /// Whether a sample reached full scale within the last half second.
+    pub is_clipping: bool,
+    /// Peak amplitude per 50 ms bin over the last 1.6 seconds, oldest first.
+    pub waveform: Vec<f32>,
+}
+
+impl AudioLevels {
+    pub(crate) fn silent() -> Self {
+        Self {
+            is_recording: false,
+            rms: 0.0,
+            peak: 0.0,
+            rms_db: METER_FLOOR_DB,
+            peak_db: METER_FLOOR_DB,
+            is_clipping: false,
+            waveform: vec![0.0; METER_WAVEFORM_BINS],
+        }
+    }
+}
+
+/// Level statistics written by the audio callback and read by UI polling.
+///
+/// Every field is an atomic so the real-time capture thread never blocks on a
+/// reader. Only the capture callback writes, so fields are updated with relaxed
+/// stores; a reader may observe values from two adjacent buffers, which is harmless
+/// for metering.
+pub(crate) struct LevelMeter {
+    active: AtomicBool,
+    sample_rate: AtomicU32,
+    rms: AtomicU32,
+    peak: AtomicU32,
+    samples_seen: AtomicU64,
+    /// Sample position just after the most recent clipped sample, or 0 if none.
+    clipped_until: AtomicU64,
+    bins: [AtomicU32; METER_WAVEFORM_BINS],
+    /// Index of the bin that will be written next; the oldest completed bin.
+    next_bin: AtomicUsize,
+    bin_peak: AtomicU32,
+    bin_count: AtomicUsize,
+}
+
+impl LevelMeter {
+    pub(crate) fn new() -> Self {
+        Self {
+            active: AtomicBool::new(false),
+            sample_rate: AtomicU32::new(0),
+            rms: AtomicU32::new(0),
+            peak: AtomicU32::new(0),
+            samples_seen: AtomicU64::new(0),
+            clipped_until: AtomicU64::new(0),
+            bins: [const { AtomicU32::new(0) }; METER_WAVEFORM_BINS],
+            next_bin: AtomicUsize::new(0),
+            bin_peak: AtomicU32::new(0),
+            bin_count: AtomicUsize::new(0),
+        }
+    }
+
+    /// Clear all statistics and start metering a stream at `sample_rate`.
+    pub(crate) fn start(&self, sample_rate: u32) {
+        self.clear();
+        self.sample_rate.store(sample_rate, Ordering::Relaxed);
+        self.active.store(true, Ordering::Relaxed);
+    }
+
+    /// Clear all statistics; levels read as silence until the next `start`.
+    pub(crate) fn stop(&self) {
+        self.active.store(false, Ordering::Relaxed);
+        self.clear();
+    }
+
+    fn clear(&self) {
+        for value in [&self.rms, &self.peak, &self.bin_peak] {
+            value.store(0, Ordering::Relaxed);
+        }
+        for bin in &self.bins {
+            bin.store(0, Ordering::Relaxed);
+        }
+        self.samples_seen.store(0, Ordering::Relaxed);
+        self.clipped_until.store(0, Ordering::Relaxed);
+        self.next_bin.store(0, Ordering::Relaxed);
+        self.bin_count.store(0, Ordering::Relaxed);
+    }
+
+    /// Update levels from one buffer of mono samples. Called from the capture callback.
+    pub(crate) fn record(&self, samples: &[f32]) {
+        if samples.is_empty() {
+            return;
+        }
+
+        let sample_rate = self.sample_rate.load(Ordering::Relaxed).max(1);
+        let samples_per_bin = (sample_rate as usize * METER_WAVEFORM_BIN_MS as usize / 1000).max(1);
+        let mut bin_peak = f32::from_bits(self.bin_peak.load(Ordering::Relaxed));
+        let mut bin_count = self.bin_count.load(Ordering::Relaxed);
+        let mut next_bin = self.next_bin.load(Ordering::Relaxed);
+        let position = self.samples_seen.load(Ordering::Relaxed);
+
+        let mut sum_squares = 0.0f64;
+        let mut peak = 0.0f32;
+        let mut last_clip = None;
+        for (offset, sample) in samples.iter().enumerate() {
+            let amplitude = sample.abs();
+            sum_squares += f64::from(amplitude * amplitude);
+            peak = peak.max(amplitude);
+            if amplitude >= METER_CLIP_THRESHOLD {
+                last_clip = Some(offset);
+            }
+
+            bin_peak = bin_peak.max(amplitude);
+            bin_count += 1;
+            if bin_count >= samples_per_bin {
+                self.bins[next_bin].store(bin_peak.to_bits(), Ordering::Relaxed);
+                next_bin = (next_bin + 1) % METER_WAVEFORM_BINS;
+                bin_peak = 0.0;
+                bin_count = 0;
+            }
+        }
+
+        let rms = (sum_squares / samples.len() as f64).sqrt() as f32;
+        self.rms.store(rms.to_bits(), Ordering::Relaxed);
+        self.peak.store(peak.to_bits(), Ordering::Relaxed);
+        self.bin_peak.store(bin_peak.to_bits(), Ordering::Relaxed);
+        self.bin_count.store(bin_count, Ordering::Relaxed);
+        self.next_bin.store(next_bin, Ordering::Relaxed);
+        if let Some(offset) = last_clip {
+            self.clipped_until
+                .store(position + offset as u64 + 1, Ordering::Relaxed);
+        }
+        self.samples_seen
+            .store(position + samples.len() as u64, Ordering::Relaxed);
+    }
+
+    /// Snapshot the current levels.
+    pub(crate) fn levels(&self) -> AudioLevels {
+        if !self.active.load(Ordering::Relaxed) {
+            return AudioLevels::silent();
+        }
+
+        let sample_rate = u64::from(self.sample_rate.load(Ordering::Relaxed));
+        let hold_samples = sample_rate * u64::from(METER_CLIP_HOLD_MS) / 1000;
+        let clipped_until = self.clipped_until.load(Ordering::Relaxed);
+        let samples_seen = self.samples_seen.load(Ordering::Relaxed);
+        let is_clipping =
+            clipped_until > 0 && samples_seen.saturating_sub(clipped_until) < hold_samples;
+
+        let next_bin = self.next_bin.load(Ordering::Relaxed);
+        let waveform = (0..METER_WAVEFORM_BINS)
+            .map(|i| {
+                let bin = &self.bins[(next_bin + i) % METER_WAVEFORM_BINS];
+                f32::from_bits(bin.load(Ordering::Relaxed))
+            })
+            .collect();
+
+        let rms = f32::from_bits(self.rms.load(Ordering::Relaxed));
+        let peak = f32::from_bits(self.peak.load(Ordering::Relaxed));
+        AudioLevels {
+            is_recording: true,
+            rms,
+            peak,
+            rms_db: amplitude_to_db(rms),
+            peak_db: amplitude_to_db(peak),
+            is_clipping,
+            waveform,
+        }
+    }
+}
+
+/// Amplitude in dBFS, floored at `METER_FLOOR_DB`.
+pub(crate) fn amplitude_to_db(amplitude: f32) -> f32 {
+    if amplitude <= 0.0 {
+        return METER_FLOOR_DB;
+    }
+    (20.0 * amplitude.log10()).max(METER_FLOOR_DB)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_should_read_as_silence_when_inactive() {
+        let meter = LevelMeter::new();
+        meter.record(&[0.5; 100]);
+        assert_eq!(meter.levels(), AudioLevels::silent());
+    }
+
+    #[test]
+    fn record_should_report_rms_and_peak_of_latest_buffer() {
+        let meter = LevelMeter::new();
+        meter.start(16_000);
+        meter.record(&[0.5, -0.5, 0.5, -0.5]);
+        meter.record(&[0.1, -0.2]);
+
+        let levels = meter.levels();
+        assert!(levels.is_recording);
+        assert!((levels.peak - 0.2).abs() < 1e-6);
+        assert!((levels.rms - (0.025f32).sqrt()).abs() < 1e-6);
+        assert!((levels.peak_db - 20.0 * 0.2f32.log10()).abs() < 1e-4);
+        assert!(!levels.is_clipping);
+    }
+
+    #[test]
+    fn clipping_flag_should_hold_for_clip_hold_window() {
+        let meter = LevelMeter::new();
+        meter.start(16_000);
+        meter.record(&[0.2, 1.0, 0.2]);
+        assert!(meter.levels().is_clipping);
+
+        // Still held 100 ms later, released after the hold window.
+        meter.record(&[0.1; 1_600]);
+        assert!(meter.levels().is_clipping);
+        meter.record(&[0.1; 8_000]);
+        assert!(!meter.levels().is_clipping);
+    }
+
+    #[test]
+    fn waveform_should_list_bin_peaks_oldest_first() {
+        let meter = LevelMeter::new();
+        meter.start(1_000);
+        let samples_per_bin = (1_000 * METER_WAVEFORM_BIN_MS / 1000) as usize;
+
+        // Fill every bin, then one more bin so the ring wraps around.
+        for bin in 0..=METER_WAVEFORM_BINS {
+            let amplitude = bin as f32 / 100.0;
+            meter.record(&vec![amplitude; samples_per_bin]);
+        }
+
+        let waveform = meter.levels().waveform;
+        assert_eq!(waveform.len(), METER_WAVEFORM_BINS);
+        assert!((waveform[0] - 0.01).abs() < 1e-6);
+        assert!(
+            (waveform[METER_WAVEFORM_BINS - 1] - METER_WAVEFORM_BINS as f32 / 100.0).abs() < 1e-6
+        );
+    }
+
+    #[test]
+    fn stop_should_reset_levels() {
+        let meter = LevelMeter::new();
+        meter.start(16_000);
+        meter.record(&[1.0; 100]);
+        meter.stop();
+        meter.start(16_000);
+
+        let levels = meter.levels();
+        assert_eq!(levels.peak, 0.0);
+        assert_eq!(levels.rms_db, METER_FLOOR_DB);
+        assert!(!levels.is_clipping);
+        assert!(levels.waveform.iter().all(|bin| *bin == 0.0));
+    }
+}
@@ -1,6 +1,271 @@
+use crate::cancellation::{cancellation_requested, CancellationToken};
+use crate::error::CoreError;
+use crate::transcribe::Transcript;
+use crate::{polish, transcribe, LlmProvider, TranscriptionProvider};
+use secrecy::SecretString;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, uniffi::Record)]
+/// Providers, credentials and prompt inputs for [`crate::run_pipeline`].
+pub struct PipelineOptions {
+    /// Speech-to-text provider.
+    pub transcription_provider: TranscriptionProvider,
+    /// API key for the transcription provider; may be empty for unauthenticated servers.
+    pub transcription_api_key: String,
+    /// Optional transcription language hint (ISO-639-1).
+    pub language: Option<String>,
+    /// LLM provider used for polishing.
+    pub llm_provider: LlmProvider,
+    /// API key for the LLM provider; ignored when `skip_polish` is set.
+    pub llm_api_key: String,
+    /// Optional description of where the text will be used.
+    pub context: Option<String>,
+    /// Return the raw transcript without calling the LLM.
+    #[uniffi(default = false)]
+    pub skip_polish: bool,
+}
+
 #[derive(Debug, uniffi::Record)]
 #[must_use]
-pub(crate) struct PipelineResult {
+/// Output of a transcription + polish pipeline run.
+pub struct PipelineResult {
+    /// Transcript returned by the transcription provider.
     pub raw_text: String,
+    /// Segment timings and metadata for `raw_text`.
+    pub transcript: Transcript,
+    /// Polished text, or the raw transcript when polish was skipped or failed.
     pub polished_text: String,
+    /// Wall time spent in transcription, in milliseconds.
+    pub transcribe_ms: u64,
+    /// Wall time spent in polish, in milliseconds (0 when skipped).
+    pub polish_ms: u64,
+    /// Polish failure that caused the fallback to raw text, if any.
+    pub polish_error: Option<String>,
+}
+
+fn elapsed_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+pub(crate) fn run_pipeline(
+    audio_bytes: &[u8],
+    options: &PipelineOptions,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<PipelineResult, CoreError> {
+    let transcription_api_key = SecretString::from(options.transcription_api_key.clone());
+    let llm_api_key = SecretString::from(options.llm_api_key.clone());
+
+    run_pipeline_with(
+        options,
+        cancellation_token,
+        || {
+            transcribe::transcribe_audio_detailed(
+                &options.transcription_provider,
+                &transcription_api_key,
+                audio_bytes,
+                options.language.as_deref(),
+                false,
+                cancellation_token,
+            )
+        },
+        |raw_text| {
+            polish::polish_text_with_cancellation(
+                &options.llm_provider,
+                &llm_api_key,
+                raw_text,
+                options.context.as_deref(),
+                cancellation_token,
+            )
+        },
+    )
+}
+
+/// Pipeline control flow with the provider calls injected, so fallback and
+/// cancellation behavior can be tested without network access.
+fn run_pipeline_with(
+    options: &PipelineOptions,
+    cancellation_token: Option<&CancellationToken>,
+    transcribe: impl FnOnce() -> Result<Transcript, CoreError>,
+    polish: impl FnOnce(&str) -> Result<String, CoreError>,
+) -> Result<PipelineResult, CoreError> {
+    if cancellation_requested(cancellation_token) {
+        return Err(CoreError::Cancelled);
+    }
+
+    let transcribe_start = Instant::now();
+    let transcript = transcribe()?;
+    let transcribe_ms = elapsed_ms(transcribe_start.elapsed());
+    let raw_text = transcript.text.clone();
+
+    if options.skip_polish {
+        return Ok(PipelineResult {
+            polished_text: raw_text.clone(),
+            raw_text,
+            transcript,
+            transcribe_ms,
+            polish_ms: 0,
+            polish_error: None,
+        });
+    }
+
+    if cancellation_requested(cancellation_token) {
+        return Err(CoreError::Cancelled);
+    }
+
+    let polish_start = Instant::now();
+    let polish_result = polish(&raw_text);
+    let polish_ms = elapsed_ms(polish_start.elapsed());
+
+    match polish_result {
+        Ok(polished_text) => Ok(PipelineResult {
+            raw_text,
+            transcript,
+            polished_text,
+            transcribe_ms,
+            polish_ms,
+            polish_error: None,
+        }),
+        Err(CoreError::Cancelled) => Err(CoreError::Cancelled),
+        Err(error) => {
+            log::warn!("Polish failed, falling back to raw transcript: {error}");
+            Ok(PipelineResult {
+                polished_text: raw_text.clone(),
+                raw_text,
+                transcript,
+                transcribe_ms,
+                polish_ms,
+                polish_error: Some(error.to_string()),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_pipeline, run_pipeline_with, PipelineOptions};
+    use crate::cancellation::CancellationToken;
+    use crate::error::CoreError;
+    use crate::transcribe::Transcript;
+    use crate::{LlmProvider, TranscriptionProvider};
+    use std::cell::Cell;
+
+    fn transcript(text: &str) -> Transcript {
+        Transcript {
+            text: text.to_string(),
+            language: None,
+            duration_seconds: None,
+            segments: Vec::new(),
+            words: Vec::new(),
+        }
+    }
+
+    fn options(skip_polish: bool) -> PipelineOptions {
+        PipelineOptions {
+            transcription_provider: TranscriptionProvider::Groq,
+            transcription_api_key: "groq".to_string(),
+            language: None,
+            llm_provider: LlmProvider::GoogleAiStudio,
+            llm_api_key: "gemini".to_string(),
+            context: None,
+            skip_polish,
+        }
+    }
+
+    #[test]
+    fn run_pipeline_with_should_return_polished_text_on_success() {
+        let result = run_pipeline_with(
+            &options(false),
+            None,
+            || Ok(transcript("um hello")),
+            |raw| {
+                assert_eq!(raw, "um hello");
+                Ok("Hello.".to_string())
+            },
+        )
+        .expect("pipeline should succeed");
+
+        assert_eq!(result.raw_text, "um hello");
+        assert_eq!(result.transcript.text, "um hello");
+        assert_eq!(result.polished_text, "Hello.");
+        assert!(result.polish_error.is_none());
+    }
+
+    #[test]
+    fn run_pipeline_with_should_fall_back_to_raw_text_when_polish_fails() {
+        let result = run_pipeline_with(
+            &options(false),
+            None,
+            || Ok(transcript("raw words")),
+            |_| {
+                Err(CoreError::Api(
+                    "Gemini API error: HTTP 400 Bad Request".to_string(),
+                ))
+            },
+        )
+        .expect("polish failure should not fail the pipeline");
+
+        assert_eq!(result.polished_text, "raw words");
+        assert_eq!(
+            result.polish_error.as_deref(),
+            Some("API error: Gemini API error: HTTP 400 Bad Request")
+        );
+    }
+
+    #[test]
+    fn run_pipeline_with_should_propagate_cancellation_during_polish() {
+        let result = run_pipeline_with(
+            &options(false),
+            None,
+            || Ok(transcript("raw")),
+            |_| Err(CoreError::Cancelled),
+        );
+
+        assert!(matches!(result, Err(CoreError::Cancelled)));
+    }
+
+    #[test]
+    fn run_pipeline_with_should_propagate_transcription_errors_without_polishing() {
+        let polish_called = Cell::new(false);
+        let result = run_pipeline_with(
+            &options(false),
+            None,
+            || Err(CoreError::EmptyResponse),
+            |_| {
+                polish_called.set(true);
+                Ok(String::new())
+            },
+        );
+
+        assert!(matches!(result, Err(CoreError::EmptyResponse)));
+        assert!(!polish_called.get());
+    }
+
+    #[test]
+    fn run_pipeline_with_should_skip_polish_when_requested() {
+        let polish_called = Cell::new(false);
+        let result = run_pipeline_with(
+            &options(true),
+            None,
+            || Ok(transcript("raw")),
+            |_| {
+                polish_called.set(true);
+                Ok(String::new())
+            },
+        )
+        .expect("pipeline should succeed");
+
+        assert_eq!(result.polished_text, "raw");
+        assert_eq!(result.polish_ms, 0);
+        assert!(!polish_called.get());
+    }
+
+    #[test]
+    fn run_pipeline_should_fail_fast_when_cancelled_before_start() {
+        let token = CancellationToken::new();
+        token.cancel();
+
+        let result = run_pipeline(b"fLaC", &options(false), Some(token.as_ref()));
+
+        assert!(matches!(result, Err(CoreError::Cancelled)));
+    }
 }
@@ -1,240 +1,12 @@
-use crate::cancellation::{
-    cancellation_requested, run_with_cancellation, worker_disconnected_message,
-    CancellableOperationError, CancellationToken,
-};
-use crate::config::{GEMINI_API_URL, GEMINI_MODEL, OPENAI_API_URL, OPENAI_MODEL};
+use crate::cancellation::CancellationToken;
 use crate::error::CoreError;
-use crate::http_client::get_http_client;
-use crate::retry::{is_retryable_status, with_retry, with_retry_cancellable, HttpResult};
+use crate::llm_client::{complete, complete_streaming, LlmRequest};
+use crate::streaming::LlmStreamListener;
 use crate::LlmProvider;
-use reqwest::StatusCode;
-use secrecy::{ExposeSecret, SecretString};
-use serde::Deserialize;
+use secrecy::SecretString;
 
-const EMPTY_RESPONSE_MESSAGE: &str = "Empty response";
-const CANCELLED_RESPONSE_MESSAGE: &str = "Operation cancelled";
-const POLISH_MAX_RETRY_ATTEMPTS: u32 = 3;
-
-#[derive(Deserialize)]
-struct GeminiResponse {
-    candidates: Vec<GeminiCandidate>,
-}
-
-#[derive(Deserialize)]
-struct GeminiCandidate {
-    content: GeminiContent,
-}
-
-#[derive(Deserialize)]
-struct GeminiContent {
-    parts: Vec<GeminiPart>,
-}
-
-#[derive(Deserialize)]
-struct GeminiPart {
-    text: Option<String>,
-}
-
-#[derive(Deserialize)]
-struct OpenAiResponse {
-    choices: Vec<OpenAiChoice>,
-}
-
-#[derive(Deserialize)]
-struct OpenAiChoice {
-    message: OpenAiMessage,
-}
-
-#[derive(Deserialize)]
-struct OpenAiMessage {
-    content: Option<String>,
-}
-
-fn provider_api_name(provider: LlmProvider) -> &'static str {
-    match provider {
-        LlmProvider::GoogleAiStudio => "Gemini API",
-        LlmProvider::Openai => "OpenAI API",
-    }
-}
-
-fn build_polish_request_body(provider: LlmProvider, prompt: &str) -> serde_json::Value {
-    match provider {
-        LlmProvider::GoogleAiStudio => serde_json::json!({
-            "contents": [
-                {
-                    "role": "user",
-                    "parts": [{"text": prompt}],
-                }
-            ]
-        }),
-        LlmProvider::Openai => serde_json::json!({
-            "model": OPENAI_MODEL,
-            "messages": [
-                {
-                    "role": "system",
-                    "content": "You are a professional text editor. Output only the polished text."
-                },
-                {
-                    "role": "user",
-                    "content": prompt
-                }
-            ]
-        }),
-    }
-}
-
-fn extract_gemini_text(payload: GeminiResponse) -> HttpResult<String> {
-    let text = payload
-        .candidates
-        .first()
-        .and_then(|c| c.content.parts.first())
-        .and_then(|p| p.text.clone());
-
-    match text {
-        Some(value) => {
-            let trimmed = value.trim();
-            if trimmed.is_empty() {
-                HttpResult::NonRetryable(EMPTY_RESPONSE_MESSAGE.to_string())
-            } else {
-                HttpResult::Success(trimmed.to_string())
-            }
-        }
-        None => HttpResult::NonRetryable(EMPTY_RESPONSE_MESSAGE.to_string()),
-    }
-}
-
-fn extract_openai_text(payload: OpenAiResponse) -> HttpResult<String> {
-    let text = payload
-        .choices
-        .first()
-        .and_then(|choice| choice.message.content.clone());
-
-    match text {
-        Some(value) => {
-            let trimmed = value.trim();
-            if trimmed.is_empty() {
-                HttpResult::NonRetryable(EMPTY_RESPONSE_MESSAGE.to_string())
-            } else {
-                HttpResult::Success(trimmed.to_string())
-            }
-        }
-        None => HttpResult::NonRetryable(EMPTY_RESPONSE_MESSAGE.to_string()),
-    }
-}
-
-fn classify_status(provider: LlmProvider, status: StatusCode) -> HttpResult<()> {
-    if status == StatusCode::OK {
-        HttpResult::Success(())
-    } else if is_retryable_status(status) {
-        HttpResult::Retryable
-    } else {
-        HttpResult::NonRetryable(format!("{} error: HTTP {status}", provider_api_name(provider)))
-    }
-}
-
-fn map_provider_error(provider: LlmProvider, msg: String) -> CoreError {
-    let api_error_prefix = format!("{} error: HTTP", provider_api_name(provider));
-    if msg == EMPTY_RESPONSE_MESSAGE {
-        CoreError::EmptyResponse
-    } else if msg == CANCELLED_RESPONSE_MESSAGE {
-        CoreError::Cancelled
-    } else if msg.starts_with(&api_error_prefix) {
-        CoreError::Api(msg)
-    } else {
-        CoreError::Http(msg)
-    }
-}
-
-fn run_polish_with_retry(
-    provider: LlmProvider,
-    operation: impl FnMut() -> HttpResult<String>,
-    cancellation_token: Option<&CancellationToken>,
-) -> Result<String, CoreError> {
-    let api_name = provider_api_name(provider);
-    if let Some(token) = cancellation_token {
-        with_retry_cancellable(POLISH_MAX_RETRY_ATTEMPTS, operation, api_name, || {
-            token.is_cancelled()
-        })
-        .map_err(|msg| map_provider_error(provider, msg))
-    } else {
-        with_retry(POLISH_MAX_RETRY_ATTEMPTS, operation, api_name)
-            .map_err(|msg| map_provider_error(provider, msg))
-    }
-}
-
-fn execute_polish_request(
-    provider: LlmProvider,
-    client: &reqwest::blocking::Client,
-    url: &str,
-    api_key: &str,
-    prompt: &str,
-) -> HttpResult<String> {
-    let body = build_polish_request_body(provider, prompt);
-    let request = match provider {
-        LlmProvider::GoogleAiStudio => client.post(url).header("x-goog-api-key", api_key),
-        LlmProvider::Openai => client.post(url).bearer_auth(api_key),
-    };
-
-    let response = request.json(&body).send();
-
-    match response {
-        Ok(resp) => match classify_status(provider, resp.status()) {
-            HttpResult::Success(()) => match provider {
-                LlmProvider::GoogleAiStudio => match resp.json::<GeminiResponse>() {
-                    Ok(payload) => extract_gemini_text(payload),
-                    Err(e) => HttpResult::NonRetryable(e.to_string()),
-                },
-                LlmProvider::Openai => match resp.json::<OpenAiResponse>() {
-                    Ok(payload) => extract_openai_text(payload),
-                    Err(e) => HttpResult::NonRetryable(e.to_string()),
-                },
-            },
-            HttpResult::Retryable => HttpResult::Retryable,
-            HttpResult::NonRetryable(msg) => HttpResult::NonRetryable(msg),
-        },
-        Err(_) => HttpResult::Retryable,
-    }
-}
-
-fn execute_polish_request_cancellable(
-    provider: LlmProvider,
-    client: &reqwest::blocking::Client,
-    url: &str,
-    api_key: &SecretString,
-    prompt: &str,
-    cancellation_token: Option<&CancellationToken>,
-) -> HttpResult<String> {
-    if cancellation_requested(cancellation_token) {
-        return HttpResult::NonRetryable(CANCELLED_RESPONSE_MESSAGE.to_string());
-    }
-
-    if cancellation_token.is_none() {
-        return execute_polish_request(provider, client, url, api_key.expose_secret(), prompt);
-    }
-
-    let worker_client = client.clone();
-    let worker_url = url.to_string();
-    let worker_api_key = api_key.expose_secret().to_string();
-    let worker_prompt = prompt.to_string();
-
-    match run_with_cancellation(cancellation_token, move || {
-        execute_polish_request(
-            provider,
-            &worker_client,
-            &worker_url,
-            &worker_api_key,
-            &worker_prompt,
-        )
-    }) {
-        Ok(result) => result,
-        Err(CancellableOperationError::Cancelled) => {
-            HttpResult::NonRetryable(CANCELLED_RESPONSE_MESSAGE.to_string())
-        }
-        Err(CancellableOperationError::WorkerDisconnected) => {
-            HttpResult::NonRetryable(worker_disconnected_message().to_string())
-        }
-    }
-}
+const POLISH_SYSTEM_INSTRUCTION: &str =
+    "You are a professional text editor. Output only the polished text.";
 
 /// Build the context section for the polishing prompt.
 ///
@@ -265,8 +37,17 @@ fn build_prompt(raw_text: &str, context: Option<&str>) -> String {
      )
 }
 
+/// Wrap a polishing prompt as a provider-independent LLM request.
+fn polish_request(prompt: &str) -> LlmRequest<'_> {
+    LlmRequest {
+        prompt,
+        system_instruction: Some(POLISH_SYSTEM_INSTRUCTION),
+        temperature: None,
+    }
+}
+
 pub(crate) fn polish_text(
-    provider: LlmProvider,
+    provider: &LlmProvider,
     api_key: &SecretString,
     raw_text: &str,
     context: Option<&str>,
@@ -275,36 +56,40 @@ pub(crate) fn polish_text(
 }
 
 pub(crate) fn polish_text_with_cancellation(
-    provider: LlmProvider,
+    provider: &LlmProvider,
     api_key: &SecretString,
     raw_text: &str,
     context: Option<&str>,
     cancellation_token: Option<&CancellationToken>,
 ) -> Result<String, CoreError> {
-    if cancellation_requested(cancellation_token) {
-        return Err(CoreError::Cancelled);
-    }
-
     let prompt = build_prompt(raw_text, context);
+    complete(
+        provider,
+        api_key,
+        &polish_request(&prompt),
+        cancellation_token,
+    )
+}
 
-    let client = get_http_client();
-    let url = match provider {
-        LlmProvider::GoogleAiStudio => format!("{GEMINI_API_URL}/{GEMINI_MODEL}:generateContent"),
-        LlmProvider::Openai => format!("{OPENAI_API_URL}/chat/completions"),
-    };
-
-    run_polish_with_retry(
+/// Polish text while streaming generated deltas to `listener`.
+///
+/// Returns the complete polished text once the stream ends. Retries only
+/// happen before the first delta arrives, so the listener never sees
+/// duplicated output.
+pub(crate) fn polish_text_streaming(
+    provider: &LlmProvider,
+    api_key: &SecretString,
+    raw_text: &str,
+    context: Option<&str>,
+    listener: &dyn LlmStreamListener,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<String, CoreError> {
+    let prompt = build_prompt(raw_text, context);
+    complete_streaming(
         provider,
-        || {
-            execute_polish_request_cancellable(
-                provider,
-                client,
-                &url,
-                api_key,
-                &prompt,
-                cancellation_token,
-            )
-        },
+        api_key,
+        &polish_request(&prompt),
+        listener,
         cancellation_token,
     )
 }
@@ -312,17 +97,18 @@ pub(crate) fn polish_text_with_cancellation(
 #[cfg(test)]
 mod tests {
     use super::{
-        build_context_section, build_polish_request_body, build_prompt, classify_status,
-        extract_gemini_text, extract_openai_text, map_provider_error, polish_text_with_cancellation,
-        run_polish_with_retry, GeminiResponse, OpenAiResponse,
+        build_context_section, build_prompt, polish_request, polish_text_with_cancellation,
     };
     use crate::cancellation::CancellationToken;
+    use crate::config::CoreConfig;
     use crate::error::CoreError;
-    use crate::retry::HttpResult;
+    use crate::llm_client::client_for;
     use crate::LlmProvider;
-    use reqwest::StatusCode;
     use secrecy::SecretString;
-    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn build_polish_request_body(provider: &LlmProvider, prompt: &str) -> serde_json::Value {
+        client_for(provider, &CoreConfig::default()).build_body(&polish_request(prompt), false)
+    }
 
     #[test]
     fn build_context_section_should_include_one_context_block_when_context_provided() {
@@ -411,15 +197,6 @@ mod tests {
         assert!(prompt.contains("Output ONLY the final polished text"));
     }
 
-    #[test]
-    fn map_provider_error_should_map_cancelled_variant() {
-        let result = map_provider_error(
-            LlmProvider::GoogleAiStudio,
-            "Operation cancelled".to_string(),
-        );
-        assert!(matches!(result, CoreError::Cancelled));
-    }
-
     #[test]
     fn build_prompt_should_keep_transcript_and_output_boundary_stable() {
         let raw_text = "Test content";
@@ -431,153 +208,54 @@ mod tests {
 
     #[test]
     fn build_polish_request_body_should_embed_prompt_as_user_text() {
-        let body = build_polish_request_body(LlmProvider::GoogleAiStudio, "hello");
+        let body = build_polish_request_body(&LlmProvider::GoogleAiStudio, "hello");
         assert_eq!(body["contents"][0]["role"], "user");
         assert_eq!(body["contents"][0]["parts"][0]["text"], "hello");
+        assert_eq!(
+            body["systemInstruction"]["parts"][0]["text"],
+            "You are a professional text editor. Output only the polished text."
+        );
     }
 
     #[test]
     fn build_polish_request_body_should_support_openai_provider() {
-        let body = build_polish_request_body(LlmProvider::Openai, "hello");
+        let body = build_polish_request_body(&LlmProvider::Openai, "hello");
 
         assert_eq!(body["model"], "gpt-5.4-nano");
         assert_eq!(body["messages"][0]["role"], "system");
-        assert_eq!(body["messages"][0]["content"], "You are a professional text editor. Output only the polished text.");
+        assert_eq!(
+            body["messages"][0]["content"],
+            "You are a professional text editor. Output only the polished text."
+        );
         assert_eq!(body["messages"][1]["role"], "user");
         assert_eq!(body["messages"][1]["content"], "hello");
     }
 
     #[test]
-    fn extract_gemini_text_should_return_success_when_payload_contains_text() {
-        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
-            "candidates": [{"content": {"parts": [{"text": "  polished output  "}]}}]
-        }))
-        .expect("valid payload");
-        let result = extract_gemini_text(payload);
-        assert!(matches!(result, HttpResult::Success(value) if value == "polished output"));
-    }
-
-    #[test]
-    fn extract_gemini_text_should_return_empty_response_when_candidates_are_missing() {
-        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
-            "candidates": []
-        }))
-        .expect("valid payload");
-        let result = extract_gemini_text(payload);
-        assert!(matches!(result, HttpResult::NonRetryable(msg) if msg == "Empty response"));
-    }
-
-    #[test]
-    fn extract_gemini_text_should_return_empty_response_when_text_is_blank() {
-        let payload: GeminiResponse = serde_json::from_value(serde_json::json!({
-            "candidates": [{"content": {"parts": [{"text": "   "} ]}}]
-        }))
-        .expect("valid payload");
-        let result = extract_gemini_text(payload);
-        assert!(matches!(result, HttpResult::NonRetryable(msg) if msg == "Empty response"));
-    }
-
-    #[test]
-    fn extract_openai_text_should_return_success_when_payload_contains_text() {
-        let payload: OpenAiResponse = serde_json::from_value(serde_json::json!({
-            "choices": [{"message": {"content": "  polished output  "}}]
-        }))
-        .expect("valid payload");
-        let result = extract_openai_text(payload);
-        assert!(matches!(result, HttpResult::Success(value) if value == "polished output"));
-    }
-
-    #[test]
-    fn extract_openai_text_should_return_empty_response_when_choices_are_missing() {
-        let payload: OpenAiResponse = serde_json::from_value(serde_json::json!({
-            "choices": []
-        }))
-        .expect("valid payload");
-        let result = extract_openai_text(payload);
-        assert!(matches!(result, HttpResult::NonRetryable(msg) if msg == "Empty response"));
-    }
-
-    #[test]
-    fn classify_status_should_retry_on_server_errors() {
-        let result = classify_status(LlmProvider::GoogleAiStudio, StatusCode::SERVICE_UNAVAILABLE);
-        assert!(matches!(result, HttpResult::Retryable));
-    }
-
-    #[test]
-    fn classify_status_should_map_client_error_to_non_retryable() {
-        let result = classify_status(LlmProvider::GoogleAiStudio, StatusCode::UNAUTHORIZED);
-        assert!(
-            matches!(result, HttpResult::NonRetryable(msg) if msg == "Gemini API error: HTTP 401 Unauthorized")
-        );
-    }
-
-    #[test]
-    fn classify_status_should_map_openai_client_error_to_non_retryable() {
-        let result = classify_status(LlmProvider::Openai, StatusCode::FORBIDDEN);
-        assert!(
-            matches!(result, HttpResult::NonRetryable(msg) if msg == "OpenAI API error: HTTP 403 Forbidden")
-        );
-    }
-
-    #[test]
-    fn map_provider_error_should_map_empty_response_variant() {
-        let result = map_provider_error(LlmProvider::GoogleAiStudio, "Empty response".to_string());
-        assert!(matches!(result, CoreError::EmptyResponse));
-    }
-
-    #[test]
-    fn map_provider_error_should_map_api_variant() {
-        let result = map_provider_error(
-            LlmProvider::GoogleAiStudio,
-            "Gemini API error: HTTP 403 Forbidden".to_string(),
-        );
-        assert!(matches!(result, CoreError::Api(_)));
-    }
-
-    #[test]
-    fn map_provider_error_should_map_http_variant() {
-        let result = map_provider_error(LlmProvider::GoogleAiStudio, "request timeout".to_string());
-        assert!(matches!(result, CoreError::Http(_)));
-    }
-
-    #[test]
-    fn run_polish_with_retry_should_retry_until_third_attempt_success() {
-        let attempts = AtomicU32::new(0);
-
-        let result = run_polish_with_retry(
-            LlmProvider::GoogleAiStudio,
-            || {
-                let attempt = attempts.fetch_add(1, Ordering::SeqCst);
-                if attempt < 2 {
-                    HttpResult::Retryable
-                } else {
-                    HttpResult::Success("ok".to_string())
-                }
-            },
-            None,
-        );
-
-        assert!(matches!(result, Ok(value) if value == "ok"));
-        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    fn build_polish_request_body_should_use_openai_shape_for_compatible_server() {
+        let provider = LlmProvider::OpenAiCompatible {
+            base_url: "http://localhost:11434/v1".to_string(),
+            model: "llama3.2".to_string(),
+        };
+        let body = build_polish_request_body(&provider, "hello");
+
+        assert_eq!(body["model"], "llama3.2");
+        assert_eq!(body["messages"][0]["role"], "system");
+        assert_eq!(body["messages"][1]["content"], "hello");
     }
 
     #[test]
-    fn run_polish_with_retry_should_fail_after_retry_budget_exhausted() {
-        let attempts = AtomicU32::new(0);
-
-        let result = run_polish_with_retry(
-            LlmProvider::GoogleAiStudio,
-            || {
-                attempts.fetch_add(1, Ordering::SeqCst);
-                HttpResult::Retryable
-            },
-            None,
-        );
-
-        assert!(
-            matches!(result, Err(CoreError::Http(message)) if message == "Gemini API: retries exceeded")
+    fn build_polish_request_body_should_support_anthropic_provider() {
+        let body = build_polish_request_body(&LlmProvider::Anthropic, "hello");
+
+        assert_eq!(body["model"], "claude-haiku-4-5");
+        assert_eq!(body["max_tokens"], 4096);
+        assert_eq!(
+            body["system"],
+            "You are a professional text editor. Output only the polished text."
         );
-        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+        assert_eq!(body["messages"][0]["role"], "user");
+        assert_eq!(body["messages"][0]["content"], "hello");
     }
 
     #[test]
@@ -586,7 +264,7 @@ mod tests {
         token.cancel();
 
         let result = polish_text_with_cancellation(
-            LlmProvider::GoogleAiStudio,
+            &LlmProvider::GoogleAiStudio,
             &SecretString::from("test-key".to_string()),
             "raw input",
             None,
@@ -1,9 +1,12 @@
+use crate::error::CoreError;
+use reqwest::blocking::Response;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use reqwest::StatusCode;
 use std::thread::sleep;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-const CANCELLED_MESSAGE: &str = "Operation cancelled";
 const RETRY_CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(50);
+const RETRY_AFTER_MS_HEADER: &str = "retry-after-ms";
 
 /// The result of an HTTP request that includes the response status information.
 /// This allows the retry logic to distinguish between success, retryable errors,
@@ -11,160 +14,320 @@ const RETRY_CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(50);
 pub(crate) enum HttpResult<T> {
     /// Successful response with the result value
     Success(T),
-    /// Retryable error - will retry with exponential backoff
-    Retryable,
+    /// Retryable error - will retry after the server-suggested `delay` if one was
+    /// given, otherwise after a jittered exponential backoff. `error` is reported
+    /// if no attempts remain.
+    Retryable {
+        error: CoreError,
+        delay: Option<Duration>,
+    },
     /// Non-retryable error - will fail immediately
-    NonRetryable(String),
+    NonRetryable(CoreError),
 }
 
-/// Executes an HTTP operation with exponential backoff retry logic.
+impl<T> HttpResult<T> {
+    /// Wrap a failure, retrying only when the error kind allows it.
+    pub(crate) fn failure(error: CoreError, delay: Option<Duration>) -> Self {
+        if error.is_retryable() {
+            HttpResult::Retryable { error, delay }
+        } else {
+            HttpResult::NonRetryable(error)
+        }
+    }
+
+    /// Continue with a successful value, passing failures through unchanged.
+    pub(crate) fn and_then<U>(self, next: impl FnOnce(T) -> HttpResult<U>) -> HttpResult<U> {
+        match self {
+            HttpResult::Success(value) => next(value),
+            HttpResult::Retryable { error, delay } => HttpResult::Retryable { error, delay },
+            HttpResult::NonRetryable(error) => HttpResult::NonRetryable(error),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, uniffi::Record)]
+/// Retry schedule for remote API calls.
 ///
-/// Retries up to `max_attempts` times with exponential backoff (2^attempt seconds)
-/// for retryable conditions (server errors, rate limiting, network errors).
+/// Backoff uses "full jitter": before retry `n` (0-based) the delay is drawn
+/// uniformly from `0..=min(max_delay_ms, base_delay_ms * 2^n)`. A server
+/// `Retry-After` delay replaces the computed backoff; when it is longer than
+/// `max_delay_ms` the call fails right away with the provider error instead of
+/// blocking.
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one (must be >= 1).
+    pub max_attempts: u32,
+    /// Backoff ceiling before the first retry, in milliseconds.
+    pub base_delay_ms: u64,
+    /// Upper bound for any wait between attempts, in milliseconds.
+    pub max_delay_ms: u64,
+    /// Give up instead of retrying when the next attempt would start after
+    /// this many milliseconds (0 disables the deadline).
+    pub total_deadline_ms: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 1_000,
+            max_delay_ms: 8_000,
+            total_deadline_ms: 60_000,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Upper bound of the jitter window before retry `retry_index` (0-based).
+    fn backoff_ceiling(&self, retry_index: u32) -> Duration {
+        let factor = 2u64.saturating_pow(retry_index);
+        let delay_ms = self
+            .base_delay_ms
+            .saturating_mul(factor)
+            .min(self.max_delay_ms);
+        Duration::from_millis(delay_ms)
+    }
+
+    fn deadline(&self) -> Option<Duration> {
+        (self.total_deadline_ms > 0).then(|| Duration::from_millis(self.total_deadline_ms))
+    }
+}
+
+/// Draw a uniformly distributed delay from `0..=ceiling`.
+fn full_jitter(ceiling: Duration) -> Duration {
+    let ceiling_ms = u64::try_from(ceiling.as_millis()).unwrap_or(u64::MAX);
+    Duration::from_millis(fastrand::u64(0..=ceiling_ms))
+}
+
+/// Executes an HTTP operation with jittered exponential backoff retry logic.
+///
+/// Retries up to `policy.max_attempts` times for retryable conditions (server
+/// errors, rate limiting, network errors), honoring server-suggested delays.
 ///
 /// # Arguments
-/// * `max_attempts` - Maximum number of attempts (must be >= 1)
+/// * `policy` - Attempt budget, backoff and deadline settings
 /// * `operation` - Function that performs the HTTP request and returns an HttpResult
-/// * `error_message` - Base error message for when all retries are exhausted
 ///
 /// # Returns
 /// * `Ok(T)` - The successful result from the operation
-/// * `Err(String)` - Error message if all retries are exhausted or a non-retryable error occurs
+/// * `Err(CoreError)` - The non-retryable error, or the last retryable one once
+///   attempts or the deadline run out
 ///
 /// # Example
 /// ```ignore
-/// use diy_typeless_core::retry::{with_retry, HttpResult};
+/// use diy_typeless_core::retry::{with_retry, HttpResult, RetryPolicy};
 ///
-/// let result = with_retry(3, || {
+/// let result = with_retry(&RetryPolicy::default(), || {
 ///     match make_http_request() {
-///         Ok(resp) if resp.status().is_success() => {
-///             HttpResult::Success(parse_response(resp))
-///         }
-///         Ok(resp) if is_retryable_status(resp.status()) => {
-///             HttpResult::Retryable
-///         }
-///         Ok(resp) => {
-///             HttpResult::NonRetryable(format!("HTTP error: {}", resp.status()))
-///         }
-///         Err(_) => HttpResult::Retryable,
+///         Ok(resp) => check_response("Groq", resp).and_then(parse_response),
+///         Err(e) => transport_error("Groq", &e),
 ///     }
-/// }, "API request failed");
+/// });
 /// ```
 pub(crate) fn with_retry<T>(
-    max_attempts: u32,
+    policy: &RetryPolicy,
     operation: impl FnMut() -> HttpResult<T>,
-    error_message: &str,
-) -> Result<T, String> {
-    with_retry_impl(max_attempts, operation, error_message, |seconds| {
-        sleep(Duration::from_secs(seconds));
-    })
+) -> Result<T, CoreError> {
+    with_retry_impl(policy, operation, sleep, full_jitter)
 }
 
 pub(crate) fn with_retry_cancellable<T>(
-    max_attempts: u32,
+    policy: &RetryPolicy,
     operation: impl FnMut() -> HttpResult<T>,
-    error_message: &str,
     is_cancelled: impl FnMut() -> bool,
-) -> Result<T, String> {
+) -> Result<T, CoreError> {
     with_retry_cancellable_impl(
-        max_attempts,
+        policy,
         operation,
-        error_message,
         is_cancelled,
         RETRY_CANCELLATION_POLL_INTERVAL,
         sleep,
+        full_jitter,
     )
 }
 
 fn with_retry_impl<T>(
-    max_attempts: u32,
-    mut operation: impl FnMut() -> HttpResult<T>,
-    error_message: &str,
-    mut sleep_fn: impl FnMut(u64),
-) -> Result<T, String> {
-    if max_attempts == 0 {
-        return Err("max_attempts must be at least 1".to_string());
-    }
-
-    for attempt in 0..max_attempts {
-        match operation() {
-            HttpResult::Success(value) => return Ok(value),
-            HttpResult::NonRetryable(msg) => return Err(msg),
-            HttpResult::Retryable => {
-                // Only sleep if we're going to retry
-                if attempt < max_attempts - 1 {
-                    let backoff = 2u64.pow(attempt);
-                    sleep_fn(backoff);
-                }
-            }
-        }
-    }
-
-    Err(format!("{}: retries exceeded", error_message))
+    policy: &RetryPolicy,
+    operation: impl FnMut() -> HttpResult<T>,
+    mut sleep_fn: impl FnMut(Duration),
+    jitter_fn: impl FnMut(Duration) -> Duration,
+) -> Result<T, CoreError> {
+    retry_loop(policy, operation, jitter_fn, |delay| {
+        sleep_fn(delay);
+        Ok(())
+    })
 }
 
 fn with_retry_cancellable_impl<T>(
-    max_attempts: u32,
-    mut operation: impl FnMut() -> HttpResult<T>,
-    error_message: &str,
+    policy: &RetryPolicy,
+    operation: impl FnMut() -> HttpResult<T>,
     mut is_cancelled: impl FnMut() -> bool,
     poll_interval: Duration,
     mut sleep_fn: impl FnMut(Duration),
-) -> Result<T, String> {
-    if max_attempts == 0 {
-        return Err("max_attempts must be at least 1".to_string());
+    jitter_fn: impl FnMut(Duration) -> Duration,
+) -> Result<T, CoreError> {
+    if is_cancelled() {
+        return Err(CoreError::Cancelled);
     }
 
-    for attempt in 0..max_attempts {
+    retry_loop(policy, operation, jitter_fn, |delay| {
+        let mut remaining = delay;
+        while remaining > Duration::ZERO {
+            if is_cancelled() {
+                return Err(CoreError::Cancelled);
+            }
+
+            let sleep_for = remaining.min(poll_interval);
+            sleep_fn(sleep_for);
+            remaining -= sleep_for;
+        }
+
         if is_cancelled() {
-            return Err(CANCELLED_MESSAGE.to_string());
+            return Err(CoreError::Cancelled);
         }
+        Ok(())
+    })
+}
+
+/// Shared attempt loop; `wait` performs the backoff and may abort it.
+fn retry_loop<T>(
+    policy: &RetryPolicy,
+    mut operation: impl FnMut() -> HttpResult<T>,
+    mut jitter_fn: impl FnMut(Duration) -> Duration,
+    mut wait: impl FnMut(Duration) -> Result<(), CoreError>,
+) -> Result<T, CoreError> {
+    if policy.max_attempts == 0 {
+        return Err(CoreError::Config(
+            "max_attempts must be at least 1".to_string(),
+        ));
+    }
 
-        match operation() {
+    let start = Instant::now();
+    let mut attempt = 0;
+    loop {
+        let (error, server_delay) = match operation() {
             HttpResult::Success(value) => return Ok(value),
-            HttpResult::NonRetryable(msg) => return Err(msg),
-            HttpResult::Retryable => {
-                if attempt < max_attempts - 1 {
-                    let mut remaining = Duration::from_secs(2u64.pow(attempt));
-
-                    while remaining > Duration::ZERO {
-                        if is_cancelled() {
-                            return Err(CANCELLED_MESSAGE.to_string());
-                        }
-
-                        let sleep_for = remaining.min(poll_interval);
-                        sleep_fn(sleep_for);
-                        remaining -= sleep_for;
-                    }
-                }
+            HttpResult::NonRetryable(error) => return Err(error),
+            HttpResult::Retryable { error, delay } => (error, delay),
+        };
+
+        // Only wait if we're going to retry
+        if attempt + 1 >= policy.max_attempts {
+            log::warn!("Retries exhausted after {} attempts: {error}", attempt + 1);
+            return Err(error);
+        }
+
+        if let Some(server_delay) = server_delay {
+            if server_delay > Duration::from_millis(policy.max_delay_ms) {
+                log::warn!(
+                    "Server asked to wait {}ms, over the {}ms limit: {error}",
+                    server_delay.as_millis(),
+                    policy.max_delay_ms
+                );
+                return Err(error);
             }
         }
+        let delay = server_delay.unwrap_or_else(|| jitter_fn(policy.backoff_ceiling(attempt)));
+        if policy
+            .deadline()
+            .is_some_and(|deadline| start.elapsed() + delay > deadline)
+        {
+            log::warn!(
+                "Retry deadline reached after {} attempts: {error}",
+                attempt + 1
+            );
+            return Err(error);
+        }
+        wait(delay)?;
+        attempt += 1;
     }
-
-    Err(format!("{}: retries exceeded", error_message))
 }
 
-/// Checks if an HTTP status code indicates a retryable error.
+/// Reads the server-suggested retry delay from response headers.
 ///
-/// Retryable status codes:
-/// - 429 (Too Many Requests) - Rate limiting
-/// - 5xx (Server Errors) - Temporary server issues
-pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
-    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+/// Supports OpenAI's `retry-after-ms` and the standard `Retry-After` header in
+/// its delay-seconds form. HTTP-date values are ignored and fall back to the
+/// computed backoff.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let header_value = |name| headers.get(name).and_then(|value| value.to_str().ok());
+
+    if let Some(millis) = header_value(RETRY_AFTER_MS_HEADER)
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .filter(|millis| millis.is_finite() && *millis >= 0.0)
+    {
+        return Some(Duration::from_secs_f64(millis / 1_000.0));
+    }
+
+    header_value(RETRY_AFTER.as_str())
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .filter(|seconds| seconds.is_finite() && *seconds >= 0.0)
+        .map(Duration::from_secs_f64)
+}
+
+/// Pass a 200 response through, or turn any other status into a structured
+/// failure carrying the provider error body and `Retry-After` hint.
+pub(crate) fn check_response(provider: &str, response: Response) -> HttpResult<Response> {
+    check_response_with(response, |status, delay, body| {
+        CoreError::from_status(provider, status, delay, body)
+    })
+}
+
+/// Like [`check_response`], but with a caller-supplied status classifier.
+pub(crate) fn check_response_with(
+    response: Response,
+    classify: impl FnOnce(StatusCode, Option<Duration>, &str) -> CoreError,
+) -> HttpResult<Response> {
+    let status = response.status();
+    if status == StatusCode::OK {
+        return HttpResult::Success(response);
+    }
+
+    let delay = retry_after(response.headers());
+    let body = response.text().unwrap_or_default();
+    HttpResult::failure(classify(status, delay, &body), delay)
+}
+
+/// Classify a request that failed before any response arrived.
+pub(crate) fn transport_error<T>(provider: &str, err: &reqwest::Error) -> HttpResult<T> {
+    HttpResult::failure(CoreError::from_transport(provider, err), None)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use reqwest::header::HeaderValue;
     use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
     use std::sync::Arc;
     use std::thread;
-    use std::time::Instant;
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay_ms: 1_000,
+            max_delay_ms: 8_000,
+            total_deadline_ms: 0,
+        }
+    }
+
+    /// Deterministic jitter that always picks the top of the window.
+    fn no_jitter(ceiling: Duration) -> Duration {
+        ceiling
+    }
+
+    fn retryable<T>(delay: Option<Duration>) -> HttpResult<T> {
+        HttpResult::Retryable {
+            error: CoreError::Http("connection reset".to_string()),
+            delay,
+        }
+    }
+
+    fn secs(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_secs).collect()
+    }
 
     #[test]
     fn test_success_on_first_attempt() {
-        let result = with_retry(3, || HttpResult::Success::<i32>(42), "test");
-        assert_eq!(result, Ok(42));
+        let result = with_retry(&policy(3), || HttpResult::Success::<i32>(42));
+        assert!(matches!(result, Ok(42)));
     }
 
     #[test]
@@ -172,21 +335,21 @@ mod tests {
         let attempts = AtomicU32::new(0);
         let mut backoff_calls = Vec::new();
         let result = with_retry_impl(
-            3,
+            &policy(3),
             || {
                 let current = attempts.fetch_add(1, Ordering::SeqCst);
                 if current < 2 {
-                    HttpResult::Retryable::<u32>
+                    retryable::<u32>(None)
                 } else {
                     HttpResult::Success(current)
                 }
             },
-            "test",
-            |seconds| backoff_calls.push(seconds),
+            |delay| backoff_calls.push(delay),
+            no_jitter,
         );
-        assert_eq!(result, Ok(2));
+        assert!(matches!(result, Ok(2)));
         assert_eq!(attempts.load(Ordering::SeqCst), 3);
-        assert_eq!(backoff_calls, vec![1, 2]);
+        assert_eq!(backoff_calls, secs(&[1, 2]));
     }
 
     #[test]
@@ -194,16 +357,15 @@ mod tests {
         let attempts = AtomicU32::new(0);
         let mut sleeper_called = false;
         let result = with_retry_impl(
-            3,
+            &policy(3),
             || {
                 attempts.fetch_add(1, Ordering::SeqCst);
-                HttpResult::NonRetryable::<u32>("bad request".to_string())
+                HttpResult::NonRetryable::<u32>(CoreError::EmptyResponse)
             },
-            "test",
             |_| sleeper_called = true,
+            no_jitter,
         );
-        assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), "bad request");
+        assert!(matches!(result, Err(CoreError::EmptyResponse)));
         assert_eq!(attempts.load(Ordering::SeqCst), 1);
         assert!(!sleeper_called);
     }
@@ -213,60 +375,158 @@ mod tests {
         let attempts = AtomicU32::new(0);
         let mut backoff_calls = Vec::new();
         let result = with_retry_impl(
-            3,
+            &policy(3),
             || {
                 attempts.fetch_add(1, Ordering::SeqCst);
-                HttpResult::Retryable::<u32>
+                retryable::<u32>(None)
             },
-            "API call",
-            |seconds| backoff_calls.push(seconds),
+            |delay| backoff_calls.push(delay),
+            no_jitter,
         );
-        assert!(result.is_err());
-        assert!(result.unwrap_err().contains("API call: retries exceeded"));
+        assert!(matches!(result, Err(CoreError::Http(message)) if message == "connection reset"));
         assert_eq!(attempts.load(Ordering::SeqCst), 3);
-        assert_eq!(backoff_calls, vec![1, 2]);
+        assert_eq!(backoff_calls, secs(&[1, 2]));
     }
 
     #[test]
-    fn test_is_retryable_status() {
-        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
-        assert!(is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
-        assert!(is_retryable_status(StatusCode::BAD_GATEWAY));
-        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
-        assert!(is_retryable_status(StatusCode::GATEWAY_TIMEOUT));
+    fn test_max_attempts_zero_returns_validation_error() {
+        let result = with_retry(&policy(0), || HttpResult::Success::<i32>(42));
+        assert!(matches!(result, Err(CoreError::Config(_))));
+    }
 
-        assert!(!is_retryable_status(StatusCode::OK));
-        assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
-        assert!(!is_retryable_status(StatusCode::UNAUTHORIZED));
-        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+    #[test]
+    fn test_exponential_backoff_sequence_for_four_attempts() {
+        let mut backoff_calls = Vec::new();
+        let _ = with_retry_impl(
+            &policy(4),
+            || retryable::<u32>(None),
+            |delay| backoff_calls.push(delay),
+            no_jitter,
+        );
+        assert_eq!(backoff_calls, secs(&[1, 2, 4]));
     }
 
     #[test]
-    fn test_max_attempts_zero_returns_validation_error() {
-        let result = with_retry(0, || HttpResult::Success::<i32>(42), "test");
-        assert_eq!(result, Err("max_attempts must be at least 1".to_string()));
+    fn test_backoff_is_capped_at_max_delay() {
+        let mut backoff_calls = Vec::new();
+        let _ = with_retry_impl(
+            &RetryPolicy {
+                max_delay_ms: 3_000,
+                ..policy(5)
+            },
+            || retryable::<u32>(None),
+            |delay| backoff_calls.push(delay),
+            no_jitter,
+        );
+        assert_eq!(backoff_calls, secs(&[1, 2, 3, 3]));
     }
 
     #[test]
-    fn test_exponential_backoff_sequence_for_four_attempts() {
+    fn test_jitter_receives_backoff_ceiling() {
+        let mut ceilings = Vec::new();
+        let mut backoff_calls = Vec::new();
+        let _ = with_retry_impl(
+            &policy(3),
+            || retryable::<u32>(None),
+            |delay| backoff_calls.push(delay),
+            |ceiling| {
+                ceilings.push(ceiling);
+                ceiling / 2
+            },
+        );
+        assert_eq!(ceilings, secs(&[1, 2]));
+        assert_eq!(
+            backoff_calls,
+            vec![Duration::from_millis(500), Duration::from_secs(1)]
+        );
+    }
+
+    #[test]
+    fn test_full_jitter_stays_within_ceiling() {
+        let ceiling = Duration::from_millis(250);
+        for _ in 0..100 {
+            assert!(full_jitter(ceiling) <= ceiling);
+        }
+        assert_eq!(full_jitter(Duration::ZERO), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_server_delay_overrides_backoff() {
         let mut backoff_calls = Vec::new();
+        let mut jitter_called = false;
         let _ = with_retry_impl(
-            4,
-            || HttpResult::Retryable::<u32>,
-            "API call",
-            |seconds| backoff_calls.push(seconds),
+            &policy(2),
+            || retryable::<u32>(Some(Duration::from_millis(1_500))),
+            |delay| backoff_calls.push(delay),
+            |ceiling| {
+                jitter_called = true;
+                ceiling
+            },
+        );
+        assert_eq!(backoff_calls, vec![Duration::from_millis(1_500)]);
+        assert!(!jitter_called);
+    }
+
+    #[test]
+    fn test_server_delay_over_max_delay_fails_without_waiting() {
+        let attempts = AtomicU32::new(0);
+        let mut backoff_calls = Vec::new();
+        let result = with_retry_impl(
+            &policy(3),
+            || {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                HttpResult::<u32>::Retryable {
+                    error: CoreError::RateLimited {
+                        provider: "Groq".to_string(),
+                        retry_after_secs: Some(3_600),
+                        body: String::new(),
+                    },
+                    delay: Some(Duration::from_secs(3_600)),
+                }
+            },
+            |delay| backoff_calls.push(delay),
+            no_jitter,
+        );
+        assert!(matches!(
+            result,
+            Err(CoreError::RateLimited {
+                retry_after_secs: Some(3_600),
+                ..
+            })
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+        assert!(backoff_calls.is_empty());
+    }
+
+    #[test]
+    fn test_deadline_stops_retries_that_would_start_too_late() {
+        let attempts = AtomicU32::new(0);
+        let mut backoff_calls = Vec::new();
+        let result = with_retry_impl(
+            &RetryPolicy {
+                total_deadline_ms: 1_500,
+                ..policy(5)
+            },
+            || {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                retryable::<u32>(None)
+            },
+            |delay| backoff_calls.push(delay),
+            no_jitter,
         );
-        assert_eq!(backoff_calls, vec![1, 2, 4]);
+        assert!(matches!(result, Err(CoreError::Http(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(backoff_calls, secs(&[1]));
     }
 
     #[test]
     fn test_no_sleep_after_final_attempt() {
         let mut backoff_calls = Vec::new();
         let _ = with_retry_impl(
-            1,
-            || HttpResult::Retryable::<u32>,
-            "API call",
-            |seconds| backoff_calls.push(seconds),
+            &policy(1),
+            || retryable::<u32>(None),
+            |delay| backoff_calls.push(delay),
+            no_jitter,
         );
         assert!(backoff_calls.is_empty());
     }
@@ -276,21 +536,21 @@ mod tests {
         let attempts = AtomicU32::new(0);
         let mut backoff_calls = Vec::new();
         let result = with_retry_impl(
-            4,
+            &policy(4),
             || {
                 let current = attempts.fetch_add(1, Ordering::SeqCst);
                 if current == 3 {
                     HttpResult::Success("ok")
                 } else {
-                    HttpResult::Retryable
+                    retryable(None)
                 }
             },
-            "API call",
-            |seconds| backoff_calls.push(seconds),
+            |delay| backoff_calls.push(delay),
+            no_jitter,
         );
-        assert_eq!(result, Ok("ok"));
+        assert!(matches!(result, Ok("ok")));
         assert_eq!(attempts.load(Ordering::SeqCst), 4);
-        assert_eq!(backoff_calls, vec![1, 2, 4]);
+        assert_eq!(backoff_calls, secs(&[1, 2, 4]));
     }
 
     #[test]
@@ -298,16 +558,15 @@ mod tests {
         let cancelled = AtomicBool::new(true);
         let attempts = AtomicU32::new(0);
         let result = with_retry_cancellable(
-            3,
+            &policy(3),
             || {
                 attempts.fetch_add(1, Ordering::SeqCst);
                 HttpResult::Success::<u32>(1)
             },
-            "API call",
             || cancelled.load(Ordering::SeqCst),
         );
 
-        assert_eq!(result, Err("Operation cancelled".to_string()));
+        assert!(matches!(result, Err(CoreError::Cancelled)));
         assert_eq!(attempts.load(Ordering::SeqCst), 0);
     }
 
@@ -323,12 +582,11 @@ mod tests {
 
         let start = Instant::now();
         let result = with_retry_cancellable(
-            3,
+            &policy(3),
             || {
                 attempts.fetch_add(1, Ordering::SeqCst);
-                HttpResult::Retryable::<u32>
+                retryable::<u32>(Some(Duration::from_secs(1)))
             },
-            "API call",
             || cancelled.load(Ordering::SeqCst),
         );
         let elapsed = start.elapsed();
@@ -336,7 +594,7 @@ mod tests {
             .join()
             .expect("canceller thread should join cleanly");
 
-        assert_eq!(result, Err("Operation cancelled".to_string()));
+        assert!(matches!(result, Err(CoreError::Cancelled)));
         assert_eq!(attempts.load(Ordering::SeqCst), 1);
         assert!(elapsed < Duration::from_millis(500));
     }
@@ -348,25 +606,108 @@ mod tests {
         let mut sleeps = 0_u32;
 
         let result = with_retry_cancellable_impl(
-            3,
+            &policy(3),
             || {
                 let current = attempts.fetch_add(1, Ordering::SeqCst);
                 if current == 1 {
                     HttpResult::Success("ok")
                 } else {
-                    HttpResult::Retryable
+                    retryable(None)
                 }
             },
-            "API call",
             || cancelled.load(Ordering::SeqCst),
             Duration::from_millis(10),
             |_| {
                 sleeps += 1;
             },
+            no_jitter,
         );
 
-        assert_eq!(result, Ok("ok"));
+        assert!(matches!(result, Ok("ok")));
         assert_eq!(attempts.load(Ordering::SeqCst), 2);
         assert!(sleeps > 0);
     }
+
+    #[test]
+    fn test_retry_after_parses_delay_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn test_retry_after_prefers_millisecond_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("2"));
+        headers.insert(RETRY_AFTER_MS_HEADER, HeaderValue::from_static("350"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_millis(350)));
+    }
+
+    #[test]
+    fn test_retry_after_ignores_http_dates_and_garbage() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("-3"));
+        assert_eq!(retry_after(&headers), None);
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_failure_wraps_by_error_retryability() {
+        let retry = HttpResult::<()>::failure(
+            CoreError::Timeout {
+                provider: "Groq".to_string(),
+                status: None,
+                body: String::new(),
+            },
+            Some(Duration::from_secs(2)),
+        );
+        assert!(matches!(
+            retry,
+            HttpResult::Retryable { delay: Some(d), .. } if d == Duration::from_secs(2)
+        ));
+
+        let fail = HttpResult::<()>::failure(CoreError::EmptyResponse, None);
+        assert!(matches!(
+            fail,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    #[test]
+    fn test_and_then_passes_failures_through() {
+        let mut next_called = false;
+        let chained = retryable::<u32>(Some(Duration::from_secs(1))).and_then(|_| {
+            next_called = true;
+            HttpResult::Success(())
+        });
+        assert!(matches!(chained, HttpResult::Retryable { .. }));
+        assert!(!next_called);
+
+        let mapped = HttpResult::Success(2).and_then(|v| HttpResult::Success(v * 2));
+        assert!(matches!(mapped, HttpResult::Success(4)));
+    }
+
+    #[test]
+    fn test_retries_report_the_last_error() {
+        let attempts = AtomicU32::new(0);
+        let result = with_retry_impl(
+            &policy(2),
+            || {
+                let current = attempts.fetch_add(1, Ordering::SeqCst);
+                HttpResult::<u32>::Retryable {
+                    error: CoreError::Http(format!("attempt {current}")),
+                    delay: None,
+                }
+            },
+            |_| {},
+            no_jitter,
+        );
+        assert!(matches!(result, Err(CoreError::Http(message)) if message == "attempt 1"));
+    }
 }
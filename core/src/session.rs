@@ -0,0 +1,789 @@
+use crate::audio::{self, AudioData, AudioProcessingOptions, CaptureBuffer};
+use crate::config::{MAX_RECORDING_BUFFER_BYTES, MAX_RECORDING_SECONDS, PRE_ROLL_MS};
+use crate::error::CoreError;
+use crate::meter::AudioLevels;
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::sync::{Arc, LazyLock, Mutex, MutexGuard, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Record)]
+/// Upper bounds on a single recording. A value of 0 disables that limit.
+///
+/// When either limit is reached the capture callback stops buffering, the microphone
+/// is released (unless the session is armed), and
+/// [`RecordingEventListener::on_recording_limit_reached`] fires. The host still calls
+/// `stop` to collect the audio, which is marked as `truncated`. Pre-roll audio kept
+/// by an armed session does not count toward either limit.
+pub struct RecordingLimits {
+    /// Longest recording kept, in seconds of captured audio.
+    #[uniffi(default = 1800)]
+    pub max_duration_seconds: u32,
+    /// Most memory the capture buffer may use, in bytes.
+    #[uniffi(default = 536870912)]
+    pub max_buffer_bytes: u64,
+}
+
+impl Default for RecordingLimits {
+    fn default() -> Self {
+        Self {
+            max_duration_seconds: MAX_RECORDING_SECONDS,
+            max_buffer_bytes: MAX_RECORDING_BUFFER_BYTES,
+        }
+    }
+}
+
+impl RecordingLimits {
+    /// Mono samples a recording at `sample_rate` may buffer; 0 means unlimited.
+    fn max_samples(&self, sample_rate: u32) -> usize {
+        let by_duration = u64::from(self.max_duration_seconds) * u64::from(sample_rate);
+        let by_bytes = self.max_buffer_bytes / std::mem::size_of::<f32>() as u64;
+        let max = match (by_duration, by_bytes) {
+            (0, 0) => 0,
+            (0, limit) | (limit, 0) => limit,
+            (duration, bytes) => duration.min(bytes),
+        };
+        usize::try_from(max).unwrap_or(usize::MAX)
+    }
+}
+
+/// Receives recording lifecycle events.
+#[uniffi::export(with_foreign)]
+pub trait RecordingEventListener: Send + Sync {
+    /// Called on a background thread once a recording hits its [`RecordingLimits`].
+    ///
+    /// Capture has already stopped; call `stop` to get the truncated audio.
+    fn on_recording_limit_reached(&self);
+}
+
+/// Wall-clock bookkeeping for one recording, excluding paused intervals.
+#[derive(Clone, Copy, Debug)]
+struct Timeline {
+    started_at: Instant,
+    paused_at: Option<Instant>,
+    paused_total: Duration,
+    /// Set once capture stopped at the recording limit; later time is not counted.
+    frozen: Option<Duration>,
+}
+
+impl Timeline {
+    fn new(now: Instant) -> Self {
+        Self {
+            started_at: now,
+            paused_at: None,
+            paused_total: Duration::ZERO,
+            frozen: None,
+        }
+    }
+
+    fn pause(&mut self, now: Instant) -> Result<(), CoreError> {
+        if self.paused_at.is_some() {
+            return Err(CoreError::RecordingAlreadyPaused);
+        }
+        self.paused_at = Some(now);
+        Ok(())
+    }
+
+    fn resume(&mut self, now: Instant) -> Result<(), CoreError> {
+        let paused_at = self.paused_at.take().ok_or(CoreError::RecordingNotPaused)?;
+        self.paused_total += now.saturating_duration_since(paused_at);
+        Ok(())
+    }
+
+    /// Stop the clock at `now`; later calls keep the first freeze.
+    fn freeze(&mut self, now: Instant) {
+        if self.frozen.is_none() {
+            self.frozen = Some(self.elapsed(now));
+        }
+    }
+
+    fn elapsed(&self, now: Instant) -> Duration {
+        if let Some(frozen) = self.frozen {
+            return frozen;
+        }
+        let end = self.paused_at.unwrap_or(now);
+        end.saturating_duration_since(self.started_at)
+            .saturating_sub(self.paused_total)
+    }
+}
+
+/// Open input stream feeding the session's capture buffer.
+struct InputStream {
+    /// Held only to keep capturing; the stream stops when dropped.
+    _stream: cpal::Stream,
+    sample_rate: u32,
+    device_id: Option<String>,
+}
+
+struct ActiveRecording {
+    /// Distinguishes this recording from later ones for the limit watcher.
+    id: u64,
+    timeline: Timeline,
+    sample_rate: u32,
+    pre_roll_samples: usize,
+}
+
+#[derive(Default)]
+struct SessionState {
+    input: Option<InputStream>,
+    /// Keep the input stream open between recordings to buffer pre-roll audio.
+    armed: bool,
+    recording: Option<ActiveRecording>,
+    recordings_started: u64,
+    limits: RecordingLimits,
+    listener: Option<Arc<dyn RecordingEventListener>>,
+}
+
+/// A microphone capture with its own stream and sample buffer.
+///
+/// Sessions share no state, so several can exist in one process and tests can
+/// exercise them in parallel. The `start_recording`/`stop_recording` exports drive
+/// a process-wide default session, see [`default_session`].
+#[derive(uniffi::Object)]
+pub struct RecordingSession {
+    /// Shared with the limit watcher thread, which only holds a weak reference.
+    state: Arc<Mutex<SessionState>>,
+    capture: Arc<CaptureBuffer>,
+}
+
+fn pre_roll_limit(sample_rate: u32) -> usize {
+    (u64::from(sample_rate) * u64::from(PRE_ROLL_MS) / 1000) as usize
+}
+
+fn lock_state(state: &Mutex<SessionState>) -> Result<MutexGuard<'_, SessionState>, CoreError> {
+    state
+        .lock()
+        .map_err(|_| CoreError::AudioCapture("Recording lock poisoned".to_string()))
+}
+
+/// Wait for recording `id` to hit its limit, then release the microphone and notify
+/// the listener.
+///
+/// Holds only weak references so a dropped session still closes its stream; the
+/// thread exits when the capture buffer drops its sender on the next reset.
+fn watch_limit(
+    state: Weak<Mutex<SessionState>>,
+    capture: Weak<CaptureBuffer>,
+    id: u64,
+    limit_hit: Receiver<()>,
+) {
+    if limit_hit.recv().is_err() {
+        return;
+    }
+    let (Some(state), Some(capture)) = (state.upgrade(), capture.upgrade()) else {
+        return;
+    };
+    let Ok(mut state) = lock_state(&state) else {
+        return;
+    };
+    let Some(recording) = state
+        .recording
+        .as_mut()
+        .filter(|recording| recording.id == id)
+    else {
+        return;
+    };
+    recording.timeline.freeze(Instant::now());
+    log::warn!("Recording limit reached; capture stopped");
+    if !state.armed {
+        // Only the stream is dropped; the samples stay buffered for `stop`.
+        state.input = None;
+        capture.meter().stop();
+    }
+    let listener = state.listener.clone();
+    drop(state);
+    if let Some(listener) = listener {
+        listener.on_recording_limit_reached();
+    }
+}
+
+impl RecordingSession {
+    fn lock(&self) -> Result<MutexGuard<'_, SessionState>, CoreError> {
+        lock_state(&self.state)
+    }
+
+    /// Apply the session limits to the recording that is about to start.
+    fn enforce_limits(&self, state: &mut SessionState, sample_rate: u32) {
+        let max_samples = state.limits.max_samples(sample_rate);
+        if max_samples == 0 {
+            return;
+        }
+        let (signal, limit_hit) = sync_channel(1);
+        self.capture.set_limit(max_samples, Some(signal));
+        let id = state.recordings_started;
+        let session_state = Arc::downgrade(&self.state);
+        let capture = Arc::downgrade(&self.capture);
+        let spawned = thread::Builder::new()
+            .name("recording-limit".to_string())
+            .spawn(move || watch_limit(session_state, capture, id, limit_hit));
+        if let Err(error) = spawned {
+            log::warn!("Failed to start recording limit watcher: {error}");
+        }
+    }
+
+    /// Close the input stream, if any, and clear the capture buffer.
+    fn close_input(&self, state: &mut SessionState) -> Result<(), CoreError> {
+        // SAFETY: The stream must be dropped before locking the samples to avoid deadlock.
+        // The audio callback holds the samples lock while writing; if we lock the samples
+        // first, the callback would block on them while we block on stream teardown.
+        state.input = None;
+        self.capture.meter().stop();
+        self.capture.reset(0)?;
+        Ok(())
+    }
+
+    fn open_input(
+        &self,
+        state: &mut SessionState,
+        device_id: Option<&str>,
+        pre_roll_limit_for: impl Fn(u32) -> usize,
+    ) -> Result<(), CoreError> {
+        self.close_input(state)?;
+        let (stream, sample_rate) = audio::open_input_stream(device_id, &self.capture)?;
+        // The callback may already have buffered a few samples; start from a clean slate.
+        self.capture.reset(pre_roll_limit_for(sample_rate))?;
+        state.input = Some(InputStream {
+            _stream: stream,
+            sample_rate,
+            device_id: device_id.map(str::to_string),
+        });
+        Ok(())
+    }
+
+    pub(crate) fn start_on(&self, device_id: Option<&str>) -> Result<(), CoreError> {
+        let mut state = self.lock()?;
+        if state.recording.is_some() {
+            return Err(CoreError::RecordingAlreadyActive);
+        }
+
+        self.capture.set_paused(false);
+        let reuse_armed = state
+            .input
+            .as_ref()
+            .is_some_and(|input| state.armed && input.device_id.as_deref() == device_id);
+        let pre_roll_samples = if reuse_armed {
+            self.capture.begin_from_pre_roll()?
+        } else {
+            self.open_input(&mut state, device_id, |_| 0)?;
+            0
+        };
+        let sample_rate = state.input.as_ref().map_or(0, |input| input.sample_rate);
+
+        state.recordings_started += 1;
+        self.enforce_limits(&mut state, sample_rate);
+        state.recording = Some(ActiveRecording {
+            id: state.recordings_started,
+            timeline: Timeline::new(Instant::now()),
+            sample_rate,
+            pre_roll_samples,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn stop_with(
+        &self,
+        options: &AudioProcessingOptions,
+    ) -> Result<AudioData, CoreError> {
+        let mut state = self.lock()?;
+        let recording = state
+            .recording
+            .take()
+            .ok_or(CoreError::RecordingNotActive)?;
+        let sample_rate = recording.sample_rate;
+        // The input is already closed when the recording stopped at its limit.
+        if let Some(input) = state.input.as_ref() {
+            log::debug!(
+                "Stopping recording on {}",
+                input.device_id.as_deref().unwrap_or("default input device")
+            );
+        }
+
+        self.capture.set_paused(false);
+        let truncated = self.capture.limit_reached();
+        let (samples, clipped_samples) = if state.armed && state.input.is_some() {
+            // Keep the stream running and start collecting the next pre-roll.
+            self.capture.take_recording(pre_roll_limit(sample_rate))?
+        } else {
+            // Drop the stream before taking the samples, as in `close_input`.
+            state.input = None;
+            self.capture.meter().stop();
+            self.capture.take_recording(0)?
+        };
+        drop(state);
+
+        if samples.is_empty() {
+            return Err(CoreError::AudioCapture("No audio captured".to_string()));
+        }
+
+        let captured_samples = samples.len();
+        let mut audio = audio::encode_for_transcription(samples, sample_rate, options)?;
+        audio.pre_roll_seconds = recording.pre_roll_samples as f32 / sample_rate as f32;
+        audio.truncated = truncated;
+        // Counted by the capture callback as the samples arrived.
+        audio.clip_stats.input_clipped_samples = clipped_samples;
+        audio.clip_stats.input_clipped_ratio = clipped_samples as f32 / captured_samples as f32;
+        Ok(audio)
+    }
+}
+
+#[uniffi::export]
+impl RecordingSession {
+    /// Create an idle session.
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self {
+            state: Arc::new(Mutex::new(SessionState::default())),
+            capture: Arc::new(CaptureBuffer::new()),
+        })
+    }
+
+    /// Start capturing from `device_id`, or the system default input device when `None`.
+    ///
+    /// `device_id` is an identifier (or exact name) reported by `list_input_devices`.
+    /// When the session is armed on the same device, the buffered pre-roll becomes the
+    /// start of the recording.
+    pub fn start(&self, device_id: Option<String>) -> Result<(), CoreError> {
+        self.start_on(device_id.as_deref())
+    }
+
+    /// Stop capturing and return FLAC-encoded audio with default post-processing.
+    pub fn stop(&self) -> Result<AudioData, CoreError> {
+        self.stop_with(&AudioProcessingOptions::default())
+    }
+
+    /// Stop capturing and return FLAC-encoded audio using custom post-processing options.
+    pub fn stop_with_options(
+        &self,
+        options: AudioProcessingOptions,
+    ) -> Result<AudioData, CoreError> {
+        self.stop_with(&options)
+    }
+
+    /// Stop buffering audio while keeping the input stream open.
+    ///
+    /// Paused audio is left out of the recording, so `AudioData::duration_seconds`
+    /// only counts time spent recording. Fails with [`CoreError::RecordingAlreadyPaused`]
+    /// when already paused.
+    pub fn pause(&self) -> Result<(), CoreError> {
+        let mut state = self.lock()?;
+        let recording = state
+            .recording
+            .as_mut()
+            .ok_or(CoreError::RecordingNotActive)?;
+        recording.timeline.pause(Instant::now())?;
+        self.capture.set_paused(true);
+        Ok(())
+    }
+
+    /// Resume buffering audio after [`RecordingSession::pause`].
+    ///
+    /// Fails with [`CoreError::RecordingNotPaused`] when the session is not paused.
+    pub fn resume(&self) -> Result<(), CoreError> {
+        let mut state = self.lock()?;
+        let recording = state
+            .recording
+            .as_mut()
+            .ok_or(CoreError::RecordingNotActive)?;
+        recording.timeline.resume(Instant::now())?;
+        self.capture.set_paused(false);
+        Ok(())
+    }
+
+    /// Keep an input stream open on `device_id` (or the default device) and buffer the
+    /// last 500 ms of audio, so a recording started later includes speech that began
+    /// just before `start` was called.
+    ///
+    /// The microphone stays in use until [`RecordingSession::disarm`]. Arming a session
+    /// that is recording keeps its current input open once the recording stops.
+    pub fn arm(&self, device_id: Option<String>) -> Result<(), CoreError> {
+        let mut state = self.lock()?;
+        state.armed = true;
+        if state.recording.is_some() {
+            return Ok(());
+        }
+        let already_open = state
+            .input
+            .as_ref()
+            .is_some_and(|input| input.device_id == device_id);
+        if !already_open {
+            if let Err(error) = self.open_input(&mut state, device_id.as_deref(), pre_roll_limit) {
+                state.armed = false;
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Stop buffering pre-roll and release the microphone when not recording.
+    pub fn disarm(&self) -> Result<(), CoreError> {
+        let mut state = self.lock()?;
+        state.armed = false;
+        if state.recording.is_none() {
+            self.close_input(&mut state)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the session keeps the microphone open between recordings.
+    pub fn is_armed(&self) -> bool {
+        self.lock().is_ok_and(|state| state.armed)
+    }
+
+    /// Time spent recording, excluding paused intervals and any time after the
+    /// recording limit stopped capture. Zero when idle.
+    pub fn elapsed(&self) -> Duration {
+        match self.lock() {
+            Ok(state) => state
+                .recording
+                .as_ref()
+                .map_or(Duration::ZERO, |recording| {
+                    recording.timeline.elapsed(Instant::now())
+                }),
+            Err(_) => Duration::ZERO,
+        }
+    }
+
+    /// Whether the session is capturing, including while paused.
+    pub fn is_recording(&self) -> bool {
+        self.lock().is_ok_and(|state| state.recording.is_some())
+    }
+
+    /// Whether the session is capturing but paused.
+    pub fn is_paused(&self) -> bool {
+        self.lock().is_ok_and(|state| {
+            state
+                .recording
+                .as_ref()
+                .is_some_and(|recording| recording.timeline.paused_at.is_some())
+        })
+    }
+
+    /// Limits applied to recordings started after this call.
+    pub fn set_limits(&self, limits: RecordingLimits) -> Result<(), CoreError> {
+        self.lock()?.limits = limits;
+        Ok(())
+    }
+
+    /// Limits applied to new recordings.
+    pub fn limits(&self) -> RecordingLimits {
+        self.lock()
+            .map(|state| state.limits.clone())
+            .unwrap_or_default()
+    }
+
+    /// Register the listener notified of recording events, replacing any previous one.
+    pub fn set_event_listener(
+        &self,
+        listener: Option<Arc<dyn RecordingEventListener>>,
+    ) -> Result<(), CoreError> {
+        self.lock()?.listener = listener;
+        Ok(())
+    }
+
+    /// Whether the current recording stopped capturing at its [`RecordingLimits`].
+    pub fn is_limit_reached(&self) -> bool {
+        self.is_recording() && self.capture.limit_reached()
+    }
+
+    /// Live levels of this session's input, including while armed; silent when idle.
+    pub fn levels(&self) -> AudioLevels {
+        self.capture.meter().levels()
+    }
+}
+
+static DEFAULT_SESSION: LazyLock<Arc<RecordingSession>> = LazyLock::new(RecordingSession::new);
+
+/// Session driven by the free recording functions.
+pub(crate) fn default_session() -> Arc<RecordingSession> {
+    DEFAULT_SESSION.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn timeline_should_exclude_paused_intervals() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut timeline = Timeline::new(start);
+
+        timeline.pause(at(1_000)).expect("pause should succeed");
+        assert_eq!(timeline.elapsed(at(5_000)), Duration::from_millis(1_000));
+        timeline.resume(at(3_000)).expect("resume should succeed");
+        assert_eq!(timeline.elapsed(at(3_500)), Duration::from_millis(1_500));
+        timeline.pause(at(4_000)).expect("pause should succeed");
+        timeline.resume(at(4_500)).expect("resume should succeed");
+        assert_eq!(timeline.elapsed(at(5_000)), Duration::from_millis(2_500));
+    }
+
+    #[test]
+    fn frozen_timeline_should_stop_counting() {
+        let start = Instant::now();
+        let at = |ms| start + Duration::from_millis(ms);
+        let mut timeline = Timeline::new(start);
+
+        timeline.freeze(at(2_000));
+        assert_eq!(timeline.elapsed(at(5_000)), Duration::from_millis(2_000));
+        // A pause after the freeze must not shorten the frozen time.
+        timeline.pause(at(6_000)).expect("pause should succeed");
+        timeline.resume(at(9_000)).expect("resume should succeed");
+        timeline.freeze(at(10_000));
+        assert_eq!(timeline.elapsed(at(10_000)), Duration::from_millis(2_000));
+    }
+
+    #[test]
+    fn timeline_should_reject_invalid_pause_transitions() {
+        let start = Instant::now();
+        let mut timeline = Timeline::new(start);
+
+        assert!(matches!(
+            timeline.resume(start),
+            Err(CoreError::RecordingNotPaused)
+        ));
+        timeline.pause(start).expect("pause should succeed");
+        assert!(matches!(
+            timeline.pause(start + Duration::from_secs(1)),
+            Err(CoreError::RecordingAlreadyPaused)
+        ));
+        // The rejected pause must not move the pause start.
+        timeline
+            .resume(start + Duration::from_secs(2))
+            .expect("resume should succeed");
+        assert_eq!(
+            timeline.elapsed(start + Duration::from_secs(2)),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn idle_session_should_reject_transitions() {
+        let session = RecordingSession::new();
+        assert!(matches!(session.stop(), Err(CoreError::RecordingNotActive)));
+        assert!(matches!(
+            session.pause(),
+            Err(CoreError::RecordingNotActive)
+        ));
+        assert!(matches!(
+            session.resume(),
+            Err(CoreError::RecordingNotActive)
+        ));
+        assert_eq!(session.elapsed(), Duration::ZERO);
+        assert!(!session.is_recording());
+        assert!(!session.is_paused());
+        assert_eq!(session.levels(), AudioLevels::silent());
+    }
+
+    #[test]
+    fn failed_start_should_leave_session_idle() {
+        let session = RecordingSession::new();
+        let result = session.start(Some("no-such-input-device".to_string()));
+        assert!(matches!(
+            result,
+            Err(CoreError::AudioDeviceNotFound(_) | CoreError::AudioCapture(_))
+        ));
+        assert!(!session.is_recording());
+        assert!(!default_session().is_recording());
+    }
+
+    #[test]
+    fn failed_arm_should_leave_session_disarmed() {
+        let session = RecordingSession::new();
+        assert!(session
+            .arm(Some("no-such-input-device".to_string()))
+            .is_err());
+        assert!(!session.is_armed());
+        session
+            .disarm()
+            .expect("disarming an idle session should succeed");
+        assert!(!session.is_armed());
+    }
+
+    #[test]
+    fn pre_roll_limit_should_cover_pre_roll_duration() {
+        assert_eq!(pre_roll_limit(48_000), 24_000);
+        assert_eq!(pre_roll_limit(16_000), 8_000);
+    }
+
+    #[test]
+    fn max_samples_should_take_the_tighter_limit() {
+        let limits = |max_duration_seconds, max_buffer_bytes| RecordingLimits {
+            max_duration_seconds,
+            max_buffer_bytes,
+        };
+        assert_eq!(limits(60, 0).max_samples(16_000), 960_000);
+        assert_eq!(limits(0, 4_000).max_samples(16_000), 1_000);
+        assert_eq!(limits(60, 4_000).max_samples(16_000), 1_000);
+        assert_eq!(limits(1, 1 << 30).max_samples(48_000), 48_000);
+        assert_eq!(limits(0, 0).max_samples(48_000), 0);
+        assert_eq!(
+            RecordingLimits::default().max_samples(48_000),
+            30 * 60 * 48_000
+        );
+    }
+
+    struct CountingListener(AtomicUsize);
+
+    impl RecordingEventListener for CountingListener {
+        fn on_recording_limit_reached(&self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    /// A session recording at 1 kHz without a real input device.
+    fn recording_session(
+        limits: RecordingLimits,
+    ) -> (Arc<RecordingSession>, Arc<CountingListener>) {
+        let session = RecordingSession::new();
+        let listener = Arc::new(CountingListener(AtomicUsize::new(0)));
+        session.set_limits(limits).expect("limits should be set");
+        session
+            .set_event_listener(Some(listener.clone()))
+            .expect("listener should be set");
+
+        let mut state = session.lock().expect("state should lock");
+        state.recordings_started = 1;
+        session.enforce_limits(&mut state, 1_000);
+        state.recording = Some(ActiveRecording {
+            id: 1,
+            timeline: Timeline::new(Instant::now()),
+            sample_rate: 1_000,
+            pre_roll_samples: 0,
+        });
+        drop(state);
+        (session, listener)
+    }
+
+    fn tone(len: usize) -> Vec<f32> {
+        (0..len).map(|i| (i as f32 * 0.3).sin() * 0.5).collect()
+    }
+
+    fn wait_for(condition: impl Fn() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !condition() {
+            assert!(Instant::now() < deadline, "condition not met in time");
+            thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn limit_should_notify_listener_and_mark_audio_truncated() {
+        let (session, listener) = recording_session(RecordingLimits {
+            max_duration_seconds: 2,
+            max_buffer_bytes: 0,
+        });
+        let options = AudioProcessingOptions {
+            trim_silence: false,
+            ..AudioProcessingOptions::default()
+        };
+
+        audio::capture_f32(&tone(1_500), 1, &session.capture);
+        assert!(!session.is_limit_reached());
+        audio::capture_f32(&tone(1_500), 1, &session.capture);
+        assert!(session.is_limit_reached());
+        wait_for(|| listener.0.load(Ordering::SeqCst) == 1);
+        assert!(session.is_recording());
+        let elapsed = session.elapsed();
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(session.elapsed(), elapsed);
+
+        let audio = session
+            .stop_with(&options)
+            .expect("truncated audio should be returned");
+        assert!(audio.truncated);
+        assert!((audio.duration_seconds - 2.0).abs() < 0.01);
+        assert!(!session.is_recording());
+        assert!(!session.is_limit_reached());
+        assert_eq!(listener.0.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn pre_roll_should_not_count_against_the_limit() {
+        let session = RecordingSession::new();
+        session
+            .set_limits(RecordingLimits {
+                max_duration_seconds: 2,
+                max_buffer_bytes: 0,
+            })
+            .expect("limits should be set");
+        session.capture.reset(500).expect("buffer should arm");
+        audio::capture_f32(&[1.0; 100], 1, &session.capture);
+        audio::capture_f32(&tone(400), 1, &session.capture);
+
+        let mut state = session.lock().expect("state should lock");
+        let pre_roll_samples = session
+            .capture
+            .begin_from_pre_roll()
+            .expect("recording should begin");
+        state.recordings_started = 1;
+        session.enforce_limits(&mut state, 1_000);
+        state.recording = Some(ActiveRecording {
+            id: 1,
+            timeline: Timeline::new(Instant::now()),
+            sample_rate: 1_000,
+            pre_roll_samples,
+        });
+        drop(state);
+
+        audio::capture_f32(&tone(1_900), 1, &session.capture);
+        assert!(!session.is_limit_reached());
+        audio::capture_f32(&[1.0; 200], 1, &session.capture);
+        assert!(session.is_limit_reached());
+
+        let options = AudioProcessingOptions {
+            trim_silence: false,
+            ..AudioProcessingOptions::default()
+        };
+        let audio = session
+            .stop_with(&options)
+            .expect("truncated audio should be returned");
+        assert!(audio.truncated);
+        assert!((audio.pre_roll_seconds - 0.5).abs() < 0.01);
+        assert!((audio.duration_seconds - 2.5).abs() < 0.01);
+        // Clipped pre-roll and the 100 kept samples before the limit; not the 100 after.
+        assert_eq!(audio.clip_stats.input_clipped_samples, 200);
+        assert!((audio.clip_stats.input_clipped_ratio - 200.0 / 2_500.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn recording_under_limit_should_not_be_truncated() {
+        let (session, listener) = recording_session(RecordingLimits {
+            max_duration_seconds: 0,
+            max_buffer_bytes: 8_000,
+        });
+        let options = AudioProcessingOptions {
+            trim_silence: false,
+            ..AudioProcessingOptions::default()
+        };
+
+        audio::capture_f32(&tone(1_000), 1, &session.capture);
+        let audio = session
+            .stop_with(&options)
+            .expect("audio should be returned");
+        assert!(!audio.truncated);
+        assert_eq!(listener.0.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn watcher_should_ignore_a_limit_from_an_earlier_recording() {
+        let (session, listener) = recording_session(RecordingLimits::default());
+        let (signal, limit_hit) = sync_channel(1);
+        signal.send(()).expect("signal should send");
+
+        watch_limit(
+            Arc::downgrade(&session.state),
+            Arc::downgrade(&session.capture),
+            0,
+            limit_hit,
+        );
+        assert_eq!(listener.0.load(Ordering::SeqCst), 0);
+        assert!(session.is_recording());
+    }
+
+    #[test]
+    fn default_session_should_be_shared() {
+        assert!(Arc::ptr_eq(&default_session(), &default_session()));
+        assert!(!Arc::ptr_eq(&default_session(), &RecordingSession::new()));
+    }
+}
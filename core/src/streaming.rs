@@ -0,0 +1,264 @@
+use crate::cancellation::{cancellation_requested, worker_disconnected_message, CancellationToken};
+use crate::error::CoreError;
+use crate::llm_client::LlmClient;
+use crate::retry::{check_response_with, transport_error, HttpResult};
+use std::io::{BufRead, BufReader};
+use std::sync::mpsc::{channel, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+const STREAM_POLL_INTERVAL: Duration = Duration::from_millis(50);
+const OPENAI_DONE_SENTINEL: &str = "[DONE]";
+
+/// Receives incremental text while an LLM response is streamed.
+#[uniffi::export(with_foreign)]
+pub trait LlmStreamListener: Send + Sync {
+    /// Called on the calling thread with each new chunk of generated text, in order.
+    fn on_delta(&self, delta: String);
+}
+
+/// Incremental Server-Sent Events decoder.
+///
+/// Only `data:` fields are relevant for LLM streams; comments, `event:`, `id:`
+/// and `retry:` fields are ignored.
+#[derive(Default)]
+struct SseDecoder {
+    data_lines: Vec<String>,
+}
+
+impl SseDecoder {
+    /// Feed one line without its terminator; returns the event payload when a
+    /// blank line completes an event.
+    fn push_line(&mut self, line: &str) -> Option<String> {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        if line.is_empty() {
+            return self.finish();
+        }
+
+        if let Some(value) = line.strip_prefix("data:") {
+            self.data_lines
+                .push(value.strip_prefix(' ').unwrap_or(value).to_string());
+        }
+        None
+    }
+
+    /// Flush a pending event at end of stream.
+    fn finish(&mut self) -> Option<String> {
+        if self.data_lines.is_empty() {
+            return None;
+        }
+        let data = self.data_lines.join("\n");
+        self.data_lines.clear();
+        Some(data)
+    }
+}
+
+enum StreamMessage {
+    Delta(String),
+    Finished(HttpResult<()>),
+}
+
+/// Extract the non-empty text delta carried by one SSE event payload.
+fn parse_stream_delta(client: &dyn LlmClient, data: &str) -> Result<Option<String>, CoreError> {
+    Ok(client
+        .parse_stream_event(data)?
+        .filter(|text| !text.is_empty()))
+}
+
+fn read_stream(
+    client: &dyn LlmClient,
+    reader: impl BufRead,
+    sender: &Sender<StreamMessage>,
+) -> HttpResult<()> {
+    let mut decoder = SseDecoder::default();
+    let mut lines = reader.lines();
+
+    loop {
+        let event = match lines.next() {
+            Some(Ok(line)) => decoder.push_line(&line),
+            Some(Err(e)) => return HttpResult::NonRetryable(CoreError::Http(e.to_string())),
+            None => match decoder.finish() {
+                Some(data) => Some(data),
+                None => return HttpResult::Success(()),
+            },
+        };
+
+        let Some(data) = event else {
+            continue;
+        };
+        if data == OPENAI_DONE_SENTINEL {
+            return HttpResult::Success(());
+        }
+
+        match parse_stream_delta(client, &data) {
+            Ok(Some(delta)) => {
+                if sender.send(StreamMessage::Delta(delta)).is_err() {
+                    // Receiver gave up (cancelled); stop reading.
+                    return HttpResult::Success(());
+                }
+            }
+            Ok(None) => {}
+            Err(e) => return HttpResult::NonRetryable(e),
+        }
+    }
+}
+
+/// Execute one streaming attempt and forward deltas to `listener`.
+///
+/// The request runs on a worker thread so cancellation is honored while the
+/// response body is still arriving. Only failures before the body starts are
+/// reported as retryable, so a retry never replays deltas the listener has
+/// already seen.
+pub(crate) fn execute_streaming_request(
+    client: &Arc<dyn LlmClient>,
+    request: reqwest::blocking::RequestBuilder,
+    listener: &dyn LlmStreamListener,
+    cancellation_token: Option<&CancellationToken>,
+) -> HttpResult<String> {
+    if cancellation_requested(cancellation_token) {
+        return HttpResult::NonRetryable(CoreError::Cancelled);
+    }
+
+    let (sender, receiver) = channel();
+    let client = Arc::clone(client);
+    thread::spawn(move || {
+        let result = match request.send() {
+            Ok(resp) => check_response_with(resp, |status, delay, body| {
+                client.classify_status(status, delay, body)
+            })
+            .and_then(|resp| read_stream(client.as_ref(), BufReader::new(resp), &sender)),
+            Err(e) => transport_error(client.name(), &e),
+        };
+        let _ = sender.send(StreamMessage::Finished(result));
+    });
+
+    let mut text = String::new();
+    loop {
+        if cancellation_requested(cancellation_token) {
+            return HttpResult::NonRetryable(CoreError::Cancelled);
+        }
+
+        match receiver.recv_timeout(STREAM_POLL_INTERVAL) {
+            Ok(StreamMessage::Delta(delta)) => {
+                text.push_str(&delta);
+                listener.on_delta(delta);
+            }
+            Ok(StreamMessage::Finished(HttpResult::Success(()))) => {
+                let trimmed = text.trim();
+                return if trimmed.is_empty() {
+                    HttpResult::NonRetryable(CoreError::EmptyResponse)
+                } else {
+                    HttpResult::Success(trimmed.to_string())
+                };
+            }
+            Ok(StreamMessage::Finished(HttpResult::Retryable { error, delay })) => {
+                return HttpResult::Retryable { error, delay };
+            }
+            Ok(StreamMessage::Finished(HttpResult::NonRetryable(error))) => {
+                return HttpResult::NonRetryable(error);
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                return HttpResult::NonRetryable(CoreError::Http(
+                    worker_disconnected_message().to_string(),
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_stream_delta, read_stream, SseDecoder, StreamMessage};
+    use crate::config::CoreConfig;
+    use crate::llm_client::{client_for, LlmClient};
+    use crate::retry::HttpResult;
+    use crate::LlmProvider;
+    use std::io::Cursor;
+    use std::sync::mpsc::channel;
+    use std::sync::Arc;
+
+    fn client(provider: &LlmProvider) -> Arc<dyn LlmClient> {
+        client_for(provider, &CoreConfig::default())
+    }
+
+    fn collect_deltas(provider: &LlmProvider, body: &str) -> (Vec<String>, HttpResult<()>) {
+        let (sender, receiver) = channel();
+        let result = read_stream(
+            client(provider).as_ref(),
+            Cursor::new(body.as_bytes()),
+            &sender,
+        );
+        drop(sender);
+        let deltas = receiver
+            .iter()
+            .filter_map(|message| match message {
+                StreamMessage::Delta(delta) => Some(delta),
+                StreamMessage::Finished(_) => None,
+            })
+            .collect();
+        (deltas, result)
+    }
+
+    #[test]
+    fn sse_decoder_should_emit_event_on_blank_line() {
+        let mut decoder = SseDecoder::default();
+        assert_eq!(decoder.push_line("data: {\"a\":1}"), None);
+        assert_eq!(decoder.push_line(""), Some("{\"a\":1}".to_string()));
+        assert_eq!(decoder.push_line(""), None);
+    }
+
+    #[test]
+    fn sse_decoder_should_join_multiline_data_and_ignore_other_fields() {
+        let mut decoder = SseDecoder::default();
+        assert_eq!(decoder.push_line(": keep-alive"), None);
+        assert_eq!(decoder.push_line("event: message"), None);
+        assert_eq!(decoder.push_line("data: first"), None);
+        assert_eq!(decoder.push_line("data:second\r"), None);
+        assert_eq!(decoder.push_line("\r"), Some("first\nsecond".to_string()));
+    }
+
+    #[test]
+    fn sse_decoder_should_flush_trailing_event_on_finish() {
+        let mut decoder = SseDecoder::default();
+        decoder.push_line("data: tail");
+        assert_eq!(decoder.finish(), Some("tail".to_string()));
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[test]
+    fn parse_stream_delta_should_skip_openai_role_and_finish_chunks() {
+        let role = r#"{"choices":[{"delta":{"role":"assistant","content":""}}]}"#;
+        let finish = r#"{"choices":[{"delta":{},"finish_reason":"stop"}]}"#;
+        assert!(matches!(
+            parse_stream_delta(client(&LlmProvider::Openai).as_ref(), role),
+            Ok(None)
+        ));
+        assert!(matches!(
+            parse_stream_delta(client(&LlmProvider::Openai).as_ref(), finish),
+            Ok(None)
+        ));
+    }
+
+    #[test]
+    fn read_stream_should_stop_at_openai_done_sentinel() {
+        let body = "data: {\"choices\":[{\"delta\":{\"content\":\"A\"}}]}\n\n\
+                    data: {\"choices\":[{\"delta\":{\"content\":\"B\"}}]}\n\n\
+                    data: [DONE]\n\n\
+                    data: {\"choices\":[{\"delta\":{\"content\":\"ignored\"}}]}\n\n";
+        let (deltas, result) = collect_deltas(&LlmProvider::Openai, body);
+        assert_eq!(deltas, vec!["A", "B"]);
+        assert!(matches!(result, HttpResult::Success(())));
+    }
+
+    #[test]
+    fn read_stream_should_deliver_final_gemini_event_without_trailing_blank_line() {
+        let body =
+            "data: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\"One \"}]}}]}\r\n\r\n\
+                    data: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\"two\"}]}}]}";
+        let (deltas, result) = collect_deltas(&LlmProvider::GoogleAiStudio, body);
+        assert_eq!(deltas, vec!["One ", "two"]);
+        assert!(matches!(result, HttpResult::Success(())));
+    }
+}
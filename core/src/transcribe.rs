@@ -2,16 +2,140 @@ use crate::cancellation::{
     cancellation_requested, run_with_cancellation, worker_disconnected_message,
     CancellableOperationError, CancellationToken,
 };
-use crate::config::{GROQ_TRANSCRIBE_URL, GROQ_WHISPER_MODEL};
+use crate::config::{
+    base_url, core_config, CoreConfig, LOW_CONFIDENCE_LOGPROB, MAX_COMPRESSION_RATIO,
+    MAX_REPEATED_PHRASE_WORDS, NO_SPEECH_PROB_THRESHOLD, REPETITION_LIMIT,
+};
+use crate::dictionary;
 use crate::error::CoreError;
 use crate::http_client::get_http_client;
-use crate::retry::{is_retryable_status, with_retry, with_retry_cancellable, HttpResult};
-use reqwest::StatusCode;
+use crate::retry::{
+    check_response, transport_error, with_retry, with_retry_cancellable, HttpResult, RetryPolicy,
+};
+use crate::TranscriptionProvider;
 use secrecy::{ExposeSecret, SecretString};
+use serde::Deserialize;
+
+/// Text Whisper is known to produce for silence or background noise, learned
+/// from subtitled video. Entries are compared after [`comparable_text`], so
+/// write them lowercase without punctuation.
+const KNOWN_HALLUCINATIONS: &[&str] = &[
+    "thank you for watching",
+    "thanks for watching",
+    "thank you for watching please subscribe",
+    "thanks for watching and see you next time",
+    "please subscribe to my channel",
+    "like and subscribe",
+    "see you in the next video",
+    "subtitles by the amaraorg community",
+    "transcribed by otterai",
+    "ご視聴ありがとうございました",
+    "字幕由amaraorg社区提供",
+    "请不吝点赞订阅转发打赏支持明镜与点点栏目",
+    "продолжение следует",
+    "untertitel der amaraorg community",
+];
+
+#[derive(Clone, Debug, PartialEq, uniffi::Record)]
+/// Transcription with timing and confidence details.
+pub struct Transcript {
+    /// Full transcript text.
+    pub text: String,
+    /// Language reported by the provider, if any.
+    pub language: Option<String>,
+    /// Audio duration reported by the provider, in seconds.
+    pub duration_seconds: Option<f64>,
+    /// Segment timings in order.
+    pub segments: Vec<TranscriptSegment>,
+    /// Word timings in order; empty unless word timestamps were requested.
+    pub words: Vec<TranscriptWord>,
+}
+
+#[derive(Clone, Debug, PartialEq, uniffi::Record)]
+/// One transcribed segment, usually a sentence or phrase.
+pub struct TranscriptSegment {
+    /// Segment start, in seconds from the beginning of the audio.
+    pub start_seconds: f64,
+    /// Segment end, in seconds from the beginning of the audio.
+    pub end_seconds: f64,
+    /// Segment text.
+    pub text: String,
+    /// Average token log probability; values far below zero suggest low confidence.
+    pub avg_logprob: Option<f64>,
+    /// Probability that the segment contains no speech.
+    pub no_speech_prob: Option<f64>,
+}
+
+#[derive(Clone, Debug, PartialEq, uniffi::Record)]
+/// One transcribed word with its timing.
+pub struct TranscriptWord {
+    /// The word as transcribed.
+    pub word: String,
+    /// Word start, in seconds from the beginning of the audio.
+    pub start_seconds: f64,
+    /// Word end, in seconds from the beginning of the audio.
+    pub end_seconds: f64,
+}
+
+/// `verbose_json` response of the `audio/transcriptions` endpoint.
+#[derive(Deserialize)]
+struct VerboseTranscription {
+    text: String,
+    language: Option<String>,
+    duration: Option<f64>,
+    segments: Option<Vec<VerboseSegment>>,
+    words: Option<Vec<VerboseWord>>,
+}
 
-const EMPTY_RESPONSE_MESSAGE: &str = "Empty response";
-const CANCELLED_RESPONSE_MESSAGE: &str = "Operation cancelled";
-const TRANSCRIBE_MAX_RETRY_ATTEMPTS: u32 = 3;
+#[derive(Deserialize)]
+struct VerboseSegment {
+    start: f64,
+    end: f64,
+    text: String,
+    avg_logprob: Option<f64>,
+    no_speech_prob: Option<f64>,
+    compression_ratio: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct VerboseWord {
+    word: String,
+    start: f64,
+    end: f64,
+}
+
+/// Optional form fields sent with a transcription request.
+#[derive(Clone, Debug, Default)]
+struct TranscribeFields {
+    language: Option<String>,
+    /// Spelling hint passed as Whisper's `prompt`.
+    prompt: Option<String>,
+    word_timestamps: bool,
+}
+
+fn provider_name(provider: &TranscriptionProvider) -> &'static str {
+    match provider {
+        TranscriptionProvider::Groq => "Groq",
+        TranscriptionProvider::Openai => "OpenAI",
+        TranscriptionProvider::OpenAiCompatible { .. } => "Transcription server",
+    }
+}
+
+/// Resolve the `audio/transcriptions` endpoint URL and model name for a provider.
+fn transcription_endpoint(
+    provider: &TranscriptionProvider,
+    config: &CoreConfig,
+) -> (String, String) {
+    let (api_url, model) = match provider {
+        TranscriptionProvider::Groq => (&config.groq_api_url, &config.groq_whisper_model),
+        TranscriptionProvider::Openai => (&config.openai_api_url, &config.openai_whisper_model),
+        TranscriptionProvider::OpenAiCompatible { base_url, model } => (base_url, model),
+    };
+    (
+        format!("{}/audio/transcriptions", base_url(api_url)),
+        model.clone(),
+    )
+}
 
 fn normalize_language(language: Option<&str>) -> Option<String> {
     language.and_then(|value| {
@@ -23,61 +147,189 @@ fn normalize_language(language: Option<&str>) -> Option<String> {
 fn normalize_transcription_text(text: String) -> HttpResult<String> {
     let trimmed = text.trim();
     if trimmed.is_empty() {
-        HttpResult::NonRetryable(EMPTY_RESPONSE_MESSAGE.to_string())
+        HttpResult::NonRetryable(CoreError::EmptyResponse)
     } else {
         HttpResult::Success(trimmed.to_string())
     }
 }
 
-fn classify_transcribe_status(status: StatusCode) -> HttpResult<()> {
-    if status == StatusCode::OK {
-        HttpResult::Success(())
-    } else if is_retryable_status(status) {
-        HttpResult::Retryable
+/// Lowercased alphanumeric form of a word, so "Hello," matches "hello".
+///
+/// Words without any letters or digits are returned unchanged.
+pub(crate) fn comparable_word(word: &str) -> String {
+    let normalized: String = word
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if normalized.is_empty() {
+        word.to_string()
     } else {
-        HttpResult::NonRetryable(format!("Groq API error: HTTP {status}"))
+        normalized
     }
 }
 
-fn map_transcribe_error(msg: String) -> CoreError {
-    if msg == EMPTY_RESPONSE_MESSAGE {
-        CoreError::EmptyResponse
-    } else if msg == CANCELLED_RESPONSE_MESSAGE {
-        CoreError::Cancelled
-    } else if msg.starts_with("Groq API error") {
-        CoreError::Api(msg)
+fn comparable_text(text: &str) -> String {
+    text.split_whitespace()
+        .map(comparable_word)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn is_known_hallucination(text: &str) -> bool {
+    let text = comparable_text(text);
+    KNOWN_HALLUCINATIONS.contains(&text.as_str())
+}
+
+/// Whether a segment looks like Whisper filling silence or noise rather than speech.
+///
+/// A known hallucination phrase only counts with some sign of silence, so a user who
+/// really says "thanks for watching" keeps the sentence.
+fn is_hallucinated_segment(segment: &VerboseSegment) -> bool {
+    let no_speech = segment
+        .no_speech_prob
+        .is_some_and(|prob| prob > NO_SPEECH_PROB_THRESHOLD);
+    let low_confidence = segment
+        .avg_logprob
+        .is_some_and(|logprob| logprob < LOW_CONFIDENCE_LOGPROB);
+    let looping = segment
+        .compression_ratio
+        .is_some_and(|ratio| ratio > MAX_COMPRESSION_RATIO);
+    (no_speech && low_confidence)
+        || looping
+        || ((no_speech || low_confidence) && is_known_hallucination(&segment.text))
+}
+
+/// Collapse any phrase repeated [`REPETITION_LIMIT`] or more times in a row to a
+/// single occurrence. Returns `None` when the text has no such loop.
+fn collapse_repetitions(text: &str) -> Option<String> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let comparable: Vec<String> = words.iter().map(|word| comparable_word(word)).collect();
+
+    let mut kept: Vec<&str> = Vec::with_capacity(words.len());
+    let mut collapsed = false;
+    let mut index = 0;
+    'words: while index < words.len() {
+        for len in 1..=MAX_REPEATED_PHRASE_WORDS {
+            let phrase = &comparable[index..(index + len).min(words.len())];
+            let repeats = comparable[index..]
+                .chunks_exact(len)
+                .take_while(|chunk| *chunk == phrase)
+                .count();
+            if repeats >= REPETITION_LIMIT {
+                kept.extend_from_slice(&words[index..index + len]);
+                index += repeats * len;
+                collapsed = true;
+                continue 'words;
+            }
+        }
+        kept.push(words[index]);
+        index += 1;
+    }
+
+    collapsed.then(|| kept.join(" "))
+}
+
+fn normalize_transcript(payload: VerboseTranscription) -> HttpResult<Transcript> {
+    let raw_segments = payload.segments.unwrap_or_default();
+    let segment_count = raw_segments.len();
+    let kept_segments: Vec<VerboseSegment> = raw_segments
+        .into_iter()
+        .filter(|segment| !is_hallucinated_segment(segment))
+        .collect();
+    let dropped_segments = kept_segments.len() < segment_count;
+
+    // Rebuild the text from what is left; segment text keeps the provider's own
+    // leading spaces, so concatenation is right for both spaced and CJK text.
+    let text = if dropped_segments {
+        kept_segments
+            .iter()
+            .map(|segment| segment.text.as_str())
+            .collect::<String>()
     } else {
-        CoreError::Http(msg)
+        payload.text
+    };
+    let text = collapse_repetitions(&text).unwrap_or(text);
+
+    if (dropped_segments && text.trim().is_empty()) || is_known_hallucination(&text) {
+        log::debug!("Discarding transcript that looks like a Whisper hallucination");
+        return HttpResult::NonRetryable(CoreError::NoSpeechDetected);
     }
+
+    normalize_transcription_text(text).and_then(|text| {
+        let words = payload
+            .words
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|word| {
+                let middle = (word.start + word.end) / 2.0;
+                !dropped_segments
+                    || kept_segments
+                        .iter()
+                        .any(|segment| (segment.start..=segment.end).contains(&middle))
+            })
+            .map(|word| TranscriptWord {
+                word: word.word.trim().to_string(),
+                start_seconds: word.start,
+                end_seconds: word.end,
+            })
+            .collect();
+
+        HttpResult::Success(Transcript {
+            text,
+            language: payload.language.filter(|language| !language.is_empty()),
+            duration_seconds: payload.duration,
+            segments: kept_segments
+                .into_iter()
+                .map(|segment| TranscriptSegment {
+                    start_seconds: segment.start,
+                    end_seconds: segment.end,
+                    text: segment.text.trim().to_string(),
+                    avg_logprob: segment.avg_logprob,
+                    no_speech_prob: segment.no_speech_prob,
+                })
+                .collect(),
+            words,
+        })
+    })
 }
 
-fn run_transcribe_with_retry(
-    operation: impl FnMut() -> HttpResult<String>,
+fn run_transcribe_with_retry<T>(
+    policy: &RetryPolicy,
+    operation: impl FnMut() -> HttpResult<T>,
     cancellation_token: Option<&CancellationToken>,
-) -> Result<String, CoreError> {
+) -> Result<T, CoreError> {
     if let Some(token) = cancellation_token {
-        with_retry_cancellable(TRANSCRIBE_MAX_RETRY_ATTEMPTS, operation, "Groq API", || {
-            token.is_cancelled()
-        })
-        .map_err(map_transcribe_error)
+        with_retry_cancellable(policy, operation, || token.is_cancelled())
     } else {
-        with_retry(TRANSCRIBE_MAX_RETRY_ATTEMPTS, operation, "Groq API")
-            .map_err(map_transcribe_error)
+        with_retry(policy, operation)
     }
 }
 
 fn execute_transcribe_request(
+    provider: &TranscriptionProvider,
     client: &reqwest::blocking::Client,
     api_key: &str,
     audio_bytes: &[u8],
-    language: Option<&str>,
-) -> HttpResult<String> {
+    fields: &TranscribeFields,
+) -> HttpResult<Transcript> {
+    let (url, model) = transcription_endpoint(provider, &core_config());
     let mut form = reqwest::blocking::multipart::Form::new()
-        .text("model", GROQ_WHISPER_MODEL)
-        .text("response_format", "text");
+        .text("model", model)
+        .text("response_format", "verbose_json")
+        .text("timestamp_granularities[]", "segment");
 
-    if let Some(language) = language {
-        form = form.text("language", language.to_string());
+    // Asking for words alone would drop segments from the response.
+    if fields.word_timestamps {
+        form = form.text("timestamp_granularities[]", "word");
+    }
+
+    if let Some(language) = &fields.language {
+        form = form.text("language", language.clone());
+    }
+
+    if let Some(prompt) = &fields.prompt {
+        form = form.text("prompt", prompt.clone());
     }
 
     // Audio bytes are FLAC format (compressed, ~50-70% smaller)
@@ -86,117 +338,169 @@ fn execute_transcribe_request(
         .mime_str("audio/flac")
     {
         Ok(p) => p,
-        Err(e) => return HttpResult::NonRetryable(e.to_string()),
+        Err(e) => return HttpResult::NonRetryable(CoreError::Http(e.to_string())),
     };
 
     form = form.part("file", part);
 
-    let response = client
-        .post(GROQ_TRANSCRIBE_URL)
-        .bearer_auth(api_key)
-        .multipart(form)
-        .send();
+    let mut request = client.post(url);
+    // Self-hosted OpenAI-compatible servers commonly run without authentication.
+    if !api_key.is_empty() {
+        request = request.bearer_auth(api_key);
+    }
+    let response = request.multipart(form).send();
 
+    let name = provider_name(provider);
     match response {
-        Ok(resp) => match classify_transcribe_status(resp.status()) {
-            HttpResult::Success(()) => match resp.text() {
-                Ok(text) => normalize_transcription_text(text),
-                Err(e) => HttpResult::NonRetryable(e.to_string()),
+        Ok(resp) => check_response(name, resp).and_then(|resp| match resp.bytes() {
+            Ok(body) => match serde_json::from_slice::<VerboseTranscription>(&body) {
+                Ok(payload) => normalize_transcript(payload),
+                Err(e) => HttpResult::NonRetryable(e.into()),
             },
-            HttpResult::Retryable => HttpResult::Retryable,
-            HttpResult::NonRetryable(msg) => HttpResult::NonRetryable(msg),
-        },
-        Err(_) => HttpResult::Retryable,
+            Err(e) => HttpResult::NonRetryable(CoreError::from_transport(name, &e)),
+        }),
+        Err(e) => transport_error(name, &e),
     }
 }
 
 fn execute_transcribe_request_cancellable(
+    provider: &TranscriptionProvider,
     client: &reqwest::blocking::Client,
     api_key: &SecretString,
     audio_bytes: &[u8],
-    language: Option<&str>,
+    fields: &TranscribeFields,
     cancellation_token: Option<&CancellationToken>,
-) -> HttpResult<String> {
+) -> HttpResult<Transcript> {
     if cancellation_requested(cancellation_token) {
-        return HttpResult::NonRetryable(CANCELLED_RESPONSE_MESSAGE.to_string());
+        return HttpResult::NonRetryable(CoreError::Cancelled);
     }
 
     if cancellation_token.is_none() {
-        return execute_transcribe_request(client, api_key.expose_secret(), audio_bytes, language);
+        return execute_transcribe_request(
+            provider,
+            client,
+            api_key.expose_secret(),
+            audio_bytes,
+            fields,
+        );
     }
 
+    let worker_provider = provider.clone();
     let worker_client = client.clone();
     let worker_api_key = api_key.expose_secret().to_string();
     let worker_audio_bytes = audio_bytes.to_vec();
-    let worker_language = language.map(str::to_string);
+    let worker_fields = fields.clone();
 
     match run_with_cancellation(cancellation_token, move || {
         execute_transcribe_request(
+            &worker_provider,
             &worker_client,
             &worker_api_key,
             &worker_audio_bytes,
-            worker_language.as_deref(),
+            &worker_fields,
         )
     }) {
         Ok(result) => result,
-        Err(CancellableOperationError::Cancelled) => {
-            HttpResult::NonRetryable(CANCELLED_RESPONSE_MESSAGE.to_string())
-        }
+        Err(CancellableOperationError::Cancelled) => HttpResult::NonRetryable(CoreError::Cancelled),
         Err(CancellableOperationError::WorkerDisconnected) => {
-            HttpResult::NonRetryable(worker_disconnected_message().to_string())
+            HttpResult::NonRetryable(CoreError::Http(worker_disconnected_message().to_string()))
         }
     }
 }
 
 pub(crate) fn transcribe_audio_bytes(
+    provider: &TranscriptionProvider,
     api_key: &SecretString,
     audio_bytes: &[u8],
     language: Option<&str>,
 ) -> Result<String, CoreError> {
-    transcribe_audio_bytes_with_cancellation(api_key, audio_bytes, language, None)
+    transcribe_audio_bytes_with_cancellation(provider, api_key, audio_bytes, language, None)
 }
 
 pub(crate) fn transcribe_audio_bytes_with_cancellation(
+    provider: &TranscriptionProvider,
     api_key: &SecretString,
     audio_bytes: &[u8],
     language: Option<&str>,
     cancellation_token: Option<&CancellationToken>,
 ) -> Result<String, CoreError> {
+    transcribe_audio_detailed(
+        provider,
+        api_key,
+        audio_bytes,
+        language,
+        false,
+        cancellation_token,
+    )
+    .map(|transcript| transcript.text)
+}
+
+/// Transcribe audio and keep the provider's segment (and optionally word) timings.
+///
+/// The active dictionary's vocabulary is sent as the transcription prompt and its
+/// replacements are applied to the result.
+pub(crate) fn transcribe_audio_detailed(
+    provider: &TranscriptionProvider,
+    api_key: &SecretString,
+    audio_bytes: &[u8],
+    language: Option<&str>,
+    word_timestamps: bool,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<Transcript, CoreError> {
     if cancellation_requested(cancellation_token) {
         return Err(CoreError::Cancelled);
     }
 
     let client = get_http_client();
-    let normalized_language = normalize_language(language);
+    let dictionary = dictionary::dictionary();
+    let fields = TranscribeFields {
+        language: normalize_language(language),
+        prompt: dictionary.transcription_prompt(),
+        word_timestamps,
+    };
 
+    let policy = core_config().retry_policy;
     run_transcribe_with_retry(
+        &policy,
         || {
             execute_transcribe_request_cancellable(
+                provider,
                 client,
                 api_key,
                 audio_bytes,
-                normalized_language.as_deref(),
+                &fields,
                 cancellation_token,
             )
         },
         cancellation_token,
     )
+    .map(|transcript| dictionary.apply_to_transcript(transcript))
 }
 
 #[cfg(test)]
 mod tests {
     use super::{
-        classify_transcribe_status, map_transcribe_error, normalize_language,
-        normalize_transcription_text, run_transcribe_with_retry,
-        transcribe_audio_bytes_with_cancellation,
+        collapse_repetitions, is_known_hallucination, normalize_language, normalize_transcript,
+        normalize_transcription_text, provider_name, run_transcribe_with_retry,
+        transcribe_audio_bytes_with_cancellation, transcription_endpoint, TranscriptSegment,
+        TranscriptWord, VerboseTranscription,
     };
     use crate::cancellation::CancellationToken;
+    use crate::config::CoreConfig;
     use crate::error::CoreError;
-    use crate::retry::HttpResult;
-    use reqwest::StatusCode;
+    use crate::retry::{HttpResult, RetryPolicy};
+    use crate::TranscriptionProvider;
     use secrecy::SecretString;
     use std::sync::atomic::{AtomicU32, Ordering};
 
+    fn fast_retry_policy() -> RetryPolicy {
+        RetryPolicy {
+            base_delay_ms: 1,
+            max_delay_ms: 1,
+            ..RetryPolicy::default()
+        }
+    }
+
     #[test]
     fn normalize_language_should_return_none_when_absent() {
         assert_eq!(normalize_language(None), None);
@@ -215,7 +519,10 @@ mod tests {
     #[test]
     fn normalize_transcription_text_should_reject_blank_body() {
         let result = normalize_transcription_text("   \n\t".to_string());
-        assert!(matches!(result, HttpResult::NonRetryable(msg) if msg == "Empty response"));
+        assert!(matches!(
+            result,
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
     }
 
     #[test]
@@ -225,47 +532,224 @@ mod tests {
     }
 
     #[test]
-    fn classify_transcribe_status_should_mark_retryable_statuses() {
-        let result = classify_transcribe_status(StatusCode::TOO_MANY_REQUESTS);
-        assert!(matches!(result, HttpResult::Retryable));
+    fn normalize_transcript_should_map_segments_words_and_metadata() {
+        let payload: VerboseTranscription = serde_json::from_value(serde_json::json!({
+            "task": "transcribe",
+            "language": "english",
+            "duration": 2.5,
+            "text": " Hello there. ",
+            "segments": [{
+                "id": 0, "start": 0.0, "end": 2.5, "text": " Hello there.",
+                "avg_logprob": -0.25, "no_speech_prob": 0.01
+            }],
+            "words": [
+                {"word": "Hello", "start": 0.1, "end": 0.6},
+                {"word": " there.", "start": 0.7, "end": 1.2}
+            ]
+        }))
+        .expect("valid payload");
+
+        let HttpResult::Success(transcript) = normalize_transcript(payload) else {
+            panic!("expected transcript");
+        };
+        assert_eq!(transcript.text, "Hello there.");
+        assert_eq!(transcript.language.as_deref(), Some("english"));
+        assert_eq!(transcript.duration_seconds, Some(2.5));
+        assert_eq!(
+            transcript.segments,
+            vec![TranscriptSegment {
+                start_seconds: 0.0,
+                end_seconds: 2.5,
+                text: "Hello there.".to_string(),
+                avg_logprob: Some(-0.25),
+                no_speech_prob: Some(0.01),
+            }]
+        );
+        assert_eq!(
+            transcript.words[1],
+            TranscriptWord {
+                word: "there.".to_string(),
+                start_seconds: 0.7,
+                end_seconds: 1.2,
+            }
+        );
     }
 
     #[test]
-    fn classify_transcribe_status_should_retry_on_server_errors() {
-        let result = classify_transcribe_status(StatusCode::SERVICE_UNAVAILABLE);
-        assert!(matches!(result, HttpResult::Retryable));
+    fn normalize_transcript_should_accept_missing_timings_and_reject_blank_text() {
+        let bare: VerboseTranscription =
+            serde_json::from_value(serde_json::json!({"text": "hi", "segments": null}))
+                .expect("valid payload");
+        let HttpResult::Success(transcript) = normalize_transcript(bare) else {
+            panic!("expected transcript");
+        };
+        assert!(transcript.segments.is_empty());
+        assert!(transcript.words.is_empty());
+        assert_eq!(transcript.language, None);
+
+        let blank: VerboseTranscription =
+            serde_json::from_value(serde_json::json!({"text": "  "})).expect("valid payload");
+        assert!(matches!(
+            normalize_transcript(blank),
+            HttpResult::NonRetryable(CoreError::EmptyResponse)
+        ));
+    }
+
+    fn verbose(value: serde_json::Value) -> VerboseTranscription {
+        serde_json::from_value(value).expect("valid payload")
     }
 
     #[test]
-    fn classify_transcribe_status_should_mark_api_errors_as_non_retryable() {
-        let result = classify_transcribe_status(StatusCode::BAD_REQUEST);
-        assert!(
-            matches!(result, HttpResult::NonRetryable(msg) if msg == "Groq API error: HTTP 400 Bad Request")
+    fn normalize_transcript_should_reject_known_hallucination_without_segments() {
+        let payload = verbose(serde_json::json!({"text": " Thank you for watching! "}));
+        assert!(matches!(
+            normalize_transcript(payload),
+            HttpResult::NonRetryable(CoreError::NoSpeechDetected)
+        ));
+    }
+
+    #[test]
+    fn normalize_transcript_should_drop_silent_and_looping_segments() {
+        let payload = verbose(serde_json::json!({
+            "text": " Send the report. Thanks for watching. la la la la la",
+            "segments": [
+                {"start": 0.0, "end": 2.0, "text": " Send the report.",
+                 "avg_logprob": -0.2, "no_speech_prob": 0.05, "compression_ratio": 1.1},
+                {"start": 2.0, "end": 4.0, "text": " Thanks for watching.",
+                 "avg_logprob": -1.4, "no_speech_prob": 0.8, "compression_ratio": 1.0},
+                {"start": 4.0, "end": 9.0, "text": " la la la la la",
+                 "avg_logprob": -0.3, "no_speech_prob": 0.1, "compression_ratio": 3.2}
+            ],
+            "words": [
+                {"word": "Send", "start": 0.1, "end": 0.4},
+                {"word": "Thanks", "start": 2.1, "end": 2.5}
+            ]
+        }));
+
+        let HttpResult::Success(transcript) = normalize_transcript(payload) else {
+            panic!("expected transcript");
+        };
+        assert_eq!(transcript.text, "Send the report.");
+        assert_eq!(transcript.segments.len(), 1);
+        assert_eq!(transcript.words.len(), 1);
+        assert_eq!(transcript.words[0].word, "Send");
+    }
+
+    #[test]
+    fn normalize_transcript_should_keep_uncertain_segment_when_confident_in_words() {
+        let payload = verbose(serde_json::json!({
+            "text": " Okay.",
+            "segments": [{"start": 0.0, "end": 1.0, "text": " Okay.",
+                          "avg_logprob": -0.4, "no_speech_prob": 0.7}]
+        }));
+        assert!(matches!(
+            normalize_transcript(payload),
+            HttpResult::Success(transcript) if transcript.text == "Okay."
+        ));
+    }
+
+    #[test]
+    fn normalize_transcript_should_keep_confident_segment_matching_a_known_phrase() {
+        let payload = verbose(serde_json::json!({
+            "text": " Thanks for watching. See you on Monday.",
+            "segments": [
+                {"start": 0.0, "end": 1.5, "text": " Thanks for watching.",
+                 "avg_logprob": -0.2, "no_speech_prob": 0.02},
+                {"start": 1.5, "end": 3.0, "text": " See you on Monday.",
+                 "avg_logprob": -0.3, "no_speech_prob": 0.03}
+            ]
+        }));
+        assert!(matches!(
+            normalize_transcript(payload),
+            HttpResult::Success(transcript)
+                if transcript.text == "Thanks for watching. See you on Monday."
+                    && transcript.segments.len() == 2
+        ));
+    }
+
+    #[test]
+    fn normalize_transcript_should_report_no_speech_when_every_segment_is_dropped() {
+        let payload = verbose(serde_json::json!({
+            "text": " ご視聴ありがとうございました",
+            "segments": [{"start": 0.0, "end": 3.0, "text": "ご視聴ありがとうございました",
+                          "avg_logprob": -0.5, "no_speech_prob": 0.7}]
+        }));
+        assert!(matches!(
+            normalize_transcript(payload),
+            HttpResult::NonRetryable(CoreError::NoSpeechDetected)
+        ));
+    }
+
+    #[test]
+    fn collapse_repetitions_should_reduce_looping_phrases_to_one_occurrence() {
+        assert_eq!(
+            collapse_repetitions("I will go. I will go. I will go. I will go. Then stop."),
+            Some("I will go. Then stop.".to_string())
+        );
+        assert_eq!(
+            collapse_repetitions("no no no, that is fine"),
+            None,
+            "short emphatic repeats are left alone"
         );
     }
 
     #[test]
-    fn map_transcribe_error_should_map_empty_response_variant() {
-        let result = map_transcribe_error("Empty response".to_string());
-        assert!(matches!(result, CoreError::EmptyResponse));
+    fn is_known_hallucination_should_ignore_case_and_punctuation() {
+        assert!(is_known_hallucination("Thanks for watching!"));
+        assert!(is_known_hallucination("字幕由Amara.org社区提供"));
+        assert!(!is_known_hallucination(
+            "Thanks for watching the kids tonight."
+        ));
     }
 
     #[test]
-    fn map_transcribe_error_should_map_cancelled_variant() {
-        let result = map_transcribe_error("Operation cancelled".to_string());
-        assert!(matches!(result, CoreError::Cancelled));
+    fn provider_name_should_label_each_provider_for_errors() {
+        let compatible = TranscriptionProvider::OpenAiCompatible {
+            base_url: "http://localhost:8000/v1".to_string(),
+            model: "whisper".to_string(),
+        };
+        assert_eq!(provider_name(&TranscriptionProvider::Groq), "Groq");
+        assert_eq!(provider_name(&TranscriptionProvider::Openai), "OpenAI");
+        assert_eq!(provider_name(&compatible), "Transcription server");
     }
 
     #[test]
-    fn map_transcribe_error_should_map_api_variant() {
-        let result = map_transcribe_error("Groq API error: HTTP 401 Unauthorized".to_string());
-        assert!(matches!(result, CoreError::Api(_)));
+    fn transcription_endpoint_should_use_groq_defaults() {
+        let (url, model) =
+            transcription_endpoint(&TranscriptionProvider::Groq, &CoreConfig::default());
+        assert_eq!(url, "https://api.groq.com/openai/v1/audio/transcriptions");
+        assert_eq!(model, "whisper-large-v3-turbo");
     }
 
     #[test]
-    fn map_transcribe_error_should_map_http_variant() {
-        let result = map_transcribe_error("transport failed".to_string());
-        assert!(matches!(result, CoreError::Http(_)));
+    fn transcription_endpoint_should_use_openai_transcriptions_endpoint() {
+        let (url, model) =
+            transcription_endpoint(&TranscriptionProvider::Openai, &CoreConfig::default());
+        assert_eq!(url, "https://api.openai.com/v1/audio/transcriptions");
+        assert_eq!(model, "whisper-1");
+    }
+
+    #[test]
+    fn transcription_endpoint_should_use_configured_groq_url_and_model() {
+        let config = CoreConfig {
+            groq_api_url: "http://127.0.0.1:8080/openai/v1".to_string(),
+            groq_whisper_model: "whisper-large-v3".to_string(),
+            ..CoreConfig::default()
+        };
+        let (url, model) = transcription_endpoint(&TranscriptionProvider::Groq, &config);
+        assert_eq!(url, "http://127.0.0.1:8080/openai/v1/audio/transcriptions");
+        assert_eq!(model, "whisper-large-v3");
+    }
+
+    #[test]
+    fn transcription_endpoint_should_join_compatible_base_url_without_double_slash() {
+        let provider = TranscriptionProvider::OpenAiCompatible {
+            base_url: "http://localhost:8000/v1/".to_string(),
+            model: "Systran/faster-whisper-small".to_string(),
+        };
+        let (url, model) = transcription_endpoint(&provider, &CoreConfig::default());
+        assert_eq!(url, "http://localhost:8000/v1/audio/transcriptions");
+        assert_eq!(model, "Systran/faster-whisper-small");
     }
 
     #[test]
@@ -273,10 +757,14 @@ mod tests {
         let attempts = AtomicU32::new(0);
 
         let result = run_transcribe_with_retry(
+            &fast_retry_policy(),
             || {
                 let attempt = attempts.fetch_add(1, Ordering::SeqCst);
                 if attempt < 2 {
-                    HttpResult::Retryable
+                    HttpResult::Retryable {
+                        error: CoreError::Http("connection reset".to_string()),
+                        delay: None,
+                    }
                 } else {
                     HttpResult::Success("ok".to_string())
                 }
@@ -293,15 +781,23 @@ mod tests {
         let attempts = AtomicU32::new(0);
 
         let result = run_transcribe_with_retry(
+            &fast_retry_policy(),
             || {
                 attempts.fetch_add(1, Ordering::SeqCst);
-                HttpResult::Retryable
+                HttpResult::<String>::Retryable {
+                    error: CoreError::RateLimited {
+                        provider: "Groq".to_string(),
+                        retry_after_secs: None,
+                        body: String::new(),
+                    },
+                    delay: None,
+                }
             },
             None,
         );
 
         assert!(
-            matches!(result, Err(CoreError::Http(message)) if message == "Groq API: retries exceeded")
+            matches!(result, Err(CoreError::RateLimited { provider, .. }) if provider == "Groq")
         );
         assert_eq!(attempts.load(Ordering::SeqCst), 3);
     }
@@ -312,6 +808,7 @@ mod tests {
         token.cancel();
 
         let result = transcribe_audio_bytes_with_cancellation(
+            &TranscriptionProvider::Groq,
             &SecretString::from("test-key".to_string()),
             b"fake-audio",
             None,
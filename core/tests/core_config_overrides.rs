@@ -0,0 +1,196 @@
+//! Integration tests for runtime provider configuration.
+//!
+//! Requests are routed to a loopback stand-in server, so no network access is needed.
+
+mod support;
+
+use diy_typeless_core::{
+    default_core_config, get_core_config, polish_text, set_core_config, transcribe_audio_bytes,
+    transcribe_audio_detailed, CoreConfig, CoreError, LlmProvider, TranscriptionProvider,
+};
+use support::{lock_config, override_config, CannedResponse, StandInServer};
+
+#[test]
+fn polish_text_should_use_configured_openai_url_and_model() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"choices":[{"message":{"content":"Polished text."}}]}"#,
+    )]);
+    let _guard = override_config(CoreConfig {
+        openai_api_url: server.url("/v1"),
+        openai_model: "gpt-stand-in".to_string(),
+        ..default_core_config()
+    });
+
+    let result = polish_text(
+        LlmProvider::Openai,
+        "test-key".to_string(),
+        "polished text".to_string(),
+        None,
+    );
+    let requests = server.finish();
+
+    assert_eq!(result.expect("polish should succeed"), "Polished text.");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/v1/chat/completions");
+    assert_eq!(requests[0].header("authorization"), Some("Bearer test-key"));
+    let body: serde_json::Value =
+        serde_json::from_slice(&requests[0].body).expect("request body should be JSON");
+    assert_eq!(body["model"], "gpt-stand-in");
+}
+
+#[test]
+fn polish_text_should_use_configured_gemini_model_in_path() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"candidates":[{"content":{"parts":[{"text":"Hello there."}]}}]}"#,
+    )]);
+    let _guard = override_config(CoreConfig {
+        gemini_api_url: server.url("/v1beta/models"),
+        gemini_model: "gemini-stand-in".to_string(),
+        ..default_core_config()
+    });
+
+    let result = polish_text(
+        LlmProvider::GoogleAiStudio,
+        "test-key".to_string(),
+        "hello there".to_string(),
+        None,
+    );
+    let requests = server.finish();
+
+    assert_eq!(result.expect("polish should succeed"), "Hello there.");
+    assert_eq!(
+        requests[0].path,
+        "/v1beta/models/gemini-stand-in:generateContent"
+    );
+}
+
+#[test]
+fn transcribe_audio_bytes_should_use_configured_groq_url_and_model() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"text":"hello world\n","segments":[]}"#,
+    )]);
+    let _guard = override_config(CoreConfig {
+        groq_api_url: server.url("/openai/v1"),
+        groq_whisper_model: "whisper-stand-in".to_string(),
+        ..default_core_config()
+    });
+
+    let result = transcribe_audio_bytes("test-key".to_string(), b"fLaC".to_vec(), None);
+    let requests = server.finish();
+
+    assert_eq!(result.expect("transcription should succeed"), "hello world");
+    assert_eq!(requests[0].path, "/openai/v1/audio/transcriptions");
+    assert!(requests[0].body_text().contains("whisper-stand-in"));
+    assert!(requests[0].body_text().contains("verbose_json"));
+}
+
+#[test]
+fn transcribe_audio_detailed_should_return_segments_and_request_word_timings() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"task":"transcribe","language":"english","duration":1.5,"text":" Hi there.",
+            "segments":[{"id":0,"start":0.0,"end":1.5,"text":" Hi there.","avg_logprob":-0.2,"no_speech_prob":0.01}],
+            "words":[{"word":"Hi","start":0.1,"end":0.4},{"word":"there.","start":0.5,"end":1.1}]}"#,
+    )]);
+    let _guard = override_config(CoreConfig {
+        groq_api_url: server.url("/openai/v1"),
+        ..default_core_config()
+    });
+
+    let result = transcribe_audio_detailed(
+        TranscriptionProvider::Groq,
+        "test-key".to_string(),
+        b"fLaC".to_vec(),
+        None,
+        true,
+    );
+    let requests = server.finish();
+
+    let transcript = result.expect("transcription should succeed");
+    assert_eq!(transcript.text, "Hi there.");
+    assert_eq!(transcript.language.as_deref(), Some("english"));
+    assert_eq!(transcript.segments.len(), 1);
+    assert_eq!(transcript.words.len(), 2);
+    let body = requests[0].body_text();
+    assert_eq!(body.matches("timestamp_granularities[]").count(), 2);
+    assert!(body.contains("word"));
+}
+
+#[test]
+fn set_core_config_should_reject_invalid_values_and_keep_previous_config() {
+    let _lock = lock_config();
+    let before = get_core_config();
+
+    let result = set_core_config(CoreConfig {
+        groq_api_url: "ftp://example.com".to_string(),
+        ..default_core_config()
+    });
+
+    assert!(matches!(result, Err(CoreError::Config(_))));
+    assert_eq!(get_core_config(), before);
+}
+
+#[test]
+fn polish_text_should_call_anthropic_messages_api() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"content":[{"type":"text","text":"Polished by Claude."}],"stop_reason":"end_turn"}"#,
+    )]);
+    let _guard = override_config(CoreConfig {
+        anthropic_api_url: server.url("/v1"),
+        anthropic_model: "claude-stand-in".to_string(),
+        ..default_core_config()
+    });
+
+    let result = polish_text(
+        LlmProvider::Anthropic,
+        "test-key".to_string(),
+        "polished by claude".to_string(),
+        None,
+    );
+    let requests = server.finish();
+
+    assert_eq!(
+        result.expect("polish should succeed"),
+        "Polished by Claude."
+    );
+    assert_eq!(requests[0].path, "/v1/messages");
+    assert_eq!(requests[0].header("x-api-key"), Some("test-key"));
+    assert_eq!(requests[0].header("anthropic-version"), Some("2023-06-01"));
+    let body: serde_json::Value =
+        serde_json::from_slice(&requests[0].body).expect("request body should be JSON");
+    assert_eq!(body["model"], "claude-stand-in");
+    assert_eq!(body["max_tokens"], 4096);
+}
+
+#[test]
+fn polish_text_should_call_keyless_openai_compatible_server() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"choices":[{"message":{"content":"Polished locally."}}]}"#,
+    )]);
+    let provider = LlmProvider::OpenAiCompatible {
+        base_url: server.url("/v1"),
+        model: "llama3.2".to_string(),
+    };
+
+    let result = polish_text(
+        provider,
+        String::new(),
+        "polished locally".to_string(),
+        None,
+    );
+    let requests = server.finish();
+
+    assert_eq!(result.expect("polish should succeed"), "Polished locally.");
+    assert_eq!(requests[0].path, "/v1/chat/completions");
+    assert_eq!(requests[0].header("authorization"), None);
+    let body: serde_json::Value =
+        serde_json::from_slice(&requests[0].body).expect("request body should be JSON");
+    assert_eq!(body["model"], "llama3.2");
+    assert_eq!(body["messages"][0]["role"], "system");
+}
@@ -1,8 +1,15 @@
 //! Integration tests for stable public error/display contracts.
 //!
-//! These tests intentionally avoid network and audio device side effects.
+//! These tests intentionally avoid network and audio device side effects; provider
+//! calls go to a loopback stand-in server.
 
-use diy_typeless_core::{stop_recording, CoreError};
+mod support;
+
+use diy_typeless_core::{
+    stop_recording, transcribe_long_audio, CancellationToken, CoreError, LongAudioOptions,
+    RecordingSession, TranscriptionProvider,
+};
+use support::{CannedResponse, StandInServer};
 
 #[test]
 fn core_error_display_messages_are_stable_for_common_variants() {
@@ -24,6 +31,16 @@ fn stop_recording_should_fail_with_not_active_when_no_session_started() {
     assert!(matches!(result, Err(CoreError::RecordingNotActive)));
 }
 
+#[test]
+fn recording_session_should_fail_with_not_active_when_not_started() {
+    let session = RecordingSession::new();
+    assert!(matches!(session.stop(), Err(CoreError::RecordingNotActive)));
+    assert!(matches!(
+        session.pause(),
+        Err(CoreError::RecordingNotActive)
+    ));
+}
+
 #[test]
 fn serde_json_errors_should_map_to_serialization_core_error() {
     let parse_result = serde_json::from_str::<serde_json::Value>("{invalid json}");
@@ -31,3 +48,91 @@ fn serde_json_errors_should_map_to_serialization_core_error() {
     let core_error: CoreError = parse_error.into();
     assert!(matches!(core_error, CoreError::Serialization(_)));
 }
+
+#[test]
+fn transcribe_long_audio_should_reject_invalid_options_before_decoding() {
+    let result = transcribe_long_audio(
+        TranscriptionProvider::Groq,
+        "test-key".to_string(),
+        Vec::new(),
+        None,
+        LongAudioOptions {
+            chunk_seconds: 0,
+            ..LongAudioOptions::default()
+        },
+        CancellationToken::new(),
+    );
+    assert!(matches!(result, Err(CoreError::Config(_))));
+}
+
+#[test]
+fn transcribe_long_audio_should_fail_with_audio_processing_for_undecodable_bytes() {
+    let result = transcribe_long_audio(
+        TranscriptionProvider::Groq,
+        "test-key".to_string(),
+        b"RIFF....WAVE".to_vec(),
+        None,
+        LongAudioOptions::default(),
+        CancellationToken::new(),
+    );
+    assert!(matches!(result, Err(CoreError::AudioProcessing(_))));
+}
+
+/// Half a second of a 440 Hz tone as a 16 kHz, 16-bit mono WAV file.
+fn wav_tone() -> Vec<u8> {
+    let samples: Vec<i16> = (0..8_000)
+        .map(|i| ((i as f32 * 440.0 * std::f32::consts::TAU / 16_000.0).sin() * 8_000.0) as i16)
+        .collect();
+    let data_len = (samples.len() * 2) as u32;
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes());
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // mono
+    wav.extend_from_slice(&16_000u32.to_le_bytes());
+    wav.extend_from_slice(&32_000u32.to_le_bytes()); // byte rate
+    wav.extend_from_slice(&2u16.to_le_bytes()); // block align
+    wav.extend_from_slice(&16u16.to_le_bytes());
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        wav.extend_from_slice(&sample.to_le_bytes());
+    }
+    wav
+}
+
+#[test]
+fn transcribe_long_audio_should_decode_wav_before_calling_the_provider() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"text":" Hello from a WAV file."}"#,
+    )]);
+    let provider = TranscriptionProvider::OpenAiCompatible {
+        base_url: server.url("/v1"),
+        model: "whisper".to_string(),
+    };
+
+    let result = transcribe_long_audio(
+        provider,
+        "test-key".to_string(),
+        wav_tone(),
+        None,
+        LongAudioOptions::default(),
+        CancellationToken::new(),
+    );
+    let requests = server.finish();
+
+    assert_eq!(
+        result.expect("decoded WAV should be transcribed"),
+        "Hello from a WAV file."
+    );
+    assert_eq!(requests.len(), 1);
+    let upload = &requests[0].body;
+    assert!(
+        upload.windows(4).any(|window| window == b"fLaC"),
+        "the WAV should be re-encoded as FLAC before upload"
+    );
+    assert!(!upload.windows(4).any(|window| window == b"RIFF"));
+}
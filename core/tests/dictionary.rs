@@ -0,0 +1,88 @@
+//! Integration tests for the user dictionary.
+//!
+//! The dictionary is process-global, so every test holds the shared config lock
+//! and restores an empty dictionary before returning.
+
+mod support;
+
+use diy_typeless_core::{
+    add_dictionary_entry, add_vocabulary_term, apply_dictionary, default_core_config,
+    get_dictionary, remove_dictionary_entry, remove_vocabulary_term, set_dictionary,
+    transcribe_audio_detailed, CoreConfig, CoreError, Dictionary, DictionaryEntry,
+    TranscriptionProvider,
+};
+use support::{lock_config, override_config, CannedResponse, StandInServer};
+
+fn entry(pattern: &str, replacement: &str) -> DictionaryEntry {
+    DictionaryEntry {
+        pattern: pattern.to_string(),
+        replacement: replacement.to_string(),
+        case_sensitive: false,
+        is_regex: false,
+    }
+}
+
+#[test]
+fn dictionary_crud_should_update_terms_and_entries() {
+    let _lock = lock_config();
+    set_dictionary(Dictionary::default()).expect("empty dictionary should be valid");
+
+    add_vocabulary_term("Anya".to_string()).expect("term should be added");
+    add_vocabulary_term("anya".to_string()).expect("duplicate term should be ignored");
+    add_dictionary_entry(entry("cooper netties", "Kubernetes")).expect("entry should be added");
+    add_dictionary_entry(entry("cooper netties", "Kubernetes!")).expect("entry should be updated");
+    assert_eq!(get_dictionary().vocabulary, vec!["Anya"]);
+    assert_eq!(get_dictionary().entries.len(), 1);
+    assert_eq!(
+        apply_dictionary("deploy to cooper netties".to_string()),
+        "deploy to Kubernetes!"
+    );
+
+    let invalid = add_dictionary_entry(DictionaryEntry {
+        is_regex: true,
+        ..entry("[", "x")
+    });
+    assert!(matches!(invalid, Err(CoreError::Config(_))));
+    assert_eq!(get_dictionary().entries.len(), 1);
+
+    assert!(remove_vocabulary_term("ANYA".to_string()).expect("removal should succeed"));
+    assert!(remove_dictionary_entry("cooper netties".to_string()).expect("removal should succeed"));
+    assert!(!remove_dictionary_entry("cooper netties".to_string()).expect("removal should succeed"));
+    assert_eq!(get_dictionary(), Dictionary::default());
+}
+
+#[test]
+fn transcribe_should_send_vocabulary_prompt_and_apply_replacements() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"text":" Ask and ya about cooper netties.","segments":[]}"#,
+    )]);
+    let _guard = override_config(CoreConfig {
+        groq_api_url: server.url("/openai/v1"),
+        ..default_core_config()
+    });
+    set_dictionary(Dictionary {
+        vocabulary: vec!["Anya".to_string(), "Kubernetes".to_string()],
+        entries: vec![
+            entry("and ya", "Anya"),
+            entry("cooper netties", "Kubernetes"),
+        ],
+    })
+    .expect("dictionary should be valid");
+
+    let result = transcribe_audio_detailed(
+        TranscriptionProvider::Groq,
+        "test-key".to_string(),
+        b"fLaC".to_vec(),
+        None,
+        false,
+    );
+    set_dictionary(Dictionary::default()).expect("empty dictionary should be valid");
+    let requests = server.finish();
+
+    let transcript = result.expect("transcription should succeed");
+    assert_eq!(transcript.text, "Ask Anya about Kubernetes.");
+    let body = requests[0].body_text();
+    assert!(body.contains("name=\"prompt\""));
+    assert!(body.contains("Anya, Kubernetes"));
+}
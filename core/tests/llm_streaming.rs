@@ -0,0 +1,202 @@
+//! Integration tests for streaming LLM responses over Server-Sent Events.
+//!
+//! Requests are routed to a loopback stand-in server, so no network access is needed.
+
+mod support;
+
+use diy_typeless_core::{
+    default_core_config, polish_text_streaming, process_text_with_llm_streaming, CancellationToken,
+    CoreConfig, CoreError, LlmProvider, LlmStreamListener,
+};
+use std::sync::{Arc, Mutex};
+use support::{override_config, CannedResponse, StandInServer};
+
+#[derive(Default)]
+struct RecordingListener {
+    deltas: Mutex<Vec<String>>,
+    cancel_on_first_delta: Option<Arc<CancellationToken>>,
+}
+
+impl LlmStreamListener for RecordingListener {
+    fn on_delta(&self, delta: String) {
+        self.deltas
+            .lock()
+            .expect("delta lock should be acquired")
+            .push(delta);
+        if let Some(token) = &self.cancel_on_first_delta {
+            token.cancel();
+        }
+    }
+}
+
+impl RecordingListener {
+    fn deltas(&self) -> Vec<String> {
+        self.deltas
+            .lock()
+            .expect("delta lock should be acquired")
+            .clone()
+    }
+}
+
+#[test]
+fn polish_text_streaming_should_deliver_openai_deltas_in_order() {
+    let server = StandInServer::start(vec![CannedResponse::event_stream(&[
+        r#"{"choices":[{"delta":{"role":"assistant","content":""}}]}"#,
+        r#"{"choices":[{"delta":{"content":"Hello"}}]}"#,
+        r#"{"choices":[{"delta":{"content":", world."}}]}"#,
+        r#"{"choices":[{"delta":{},"finish_reason":"stop"}]}"#,
+        "[DONE]",
+    ])]);
+    let _config = override_config(CoreConfig {
+        openai_api_url: server.url("/v1"),
+        ..default_core_config()
+    });
+    let listener = Arc::new(RecordingListener::default());
+
+    let result = polish_text_streaming(
+        LlmProvider::Openai,
+        "test-key".to_string(),
+        "hello world".to_string(),
+        None,
+        listener.clone(),
+        CancellationToken::new(),
+    );
+    let requests = server.finish();
+
+    assert_eq!(result.expect("stream should succeed"), "Hello, world.");
+    assert_eq!(listener.deltas(), vec!["Hello", ", world."]);
+    assert_eq!(requests[0].path, "/v1/chat/completions");
+    let body: serde_json::Value =
+        serde_json::from_slice(&requests[0].body).expect("request body should be JSON");
+    assert_eq!(body["stream"], true);
+}
+
+#[test]
+fn process_text_with_llm_streaming_should_use_gemini_sse_endpoint() {
+    let server = StandInServer::start(vec![CannedResponse::event_stream(&[
+        r#"{"candidates":[{"content":{"parts":[{"text":"Short "}],"role":"model"}}]}"#,
+        r#"{"candidates":[{"content":{"parts":[{"text":"answer"}],"role":"model"},"finishReason":"STOP"}]}"#,
+    ])]);
+    let _config = override_config(CoreConfig {
+        gemini_api_url: server.url("/v1beta/models"),
+        gemini_model: "gemini-stand-in".to_string(),
+        ..default_core_config()
+    });
+    let listener = Arc::new(RecordingListener::default());
+
+    let result = process_text_with_llm_streaming(
+        LlmProvider::GoogleAiStudio,
+        "test-key".to_string(),
+        "question".to_string(),
+        None,
+        None,
+        listener.clone(),
+        CancellationToken::new(),
+    );
+    let requests = server.finish();
+
+    assert_eq!(result.expect("stream should succeed"), "Short answer");
+    assert_eq!(listener.deltas(), vec!["Short ", "answer"]);
+    assert_eq!(
+        requests[0].path,
+        "/v1beta/models/gemini-stand-in:streamGenerateContent?alt=sse"
+    );
+    assert_eq!(requests[0].header("x-goog-api-key"), Some("test-key"));
+}
+
+#[test]
+fn polish_text_streaming_should_stop_when_cancelled_mid_stream() {
+    let server = StandInServer::start(vec![CannedResponse::event_stream(&[
+        r#"{"choices":[{"delta":{"content":"first"}}]}"#,
+        r#"{"choices":[{"delta":{"content":"second"}}]}"#,
+        "[DONE]",
+    ])]);
+    let _config = override_config(CoreConfig {
+        openai_api_url: server.url("/v1"),
+        ..default_core_config()
+    });
+    let token = CancellationToken::new();
+    let listener = Arc::new(RecordingListener {
+        cancel_on_first_delta: Some(Arc::clone(&token)),
+        ..RecordingListener::default()
+    });
+
+    let result = polish_text_streaming(
+        LlmProvider::Openai,
+        "test-key".to_string(),
+        "text".to_string(),
+        None,
+        listener.clone(),
+        token,
+    );
+    server.finish();
+
+    assert!(matches!(result, Err(CoreError::Cancelled)));
+    assert_eq!(listener.deltas(), vec!["first"]);
+}
+
+#[test]
+fn polish_text_streaming_should_map_rejected_key_to_unauthorized() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        401,
+        r#"{"error":{"message":"bad key"}}"#,
+    )]);
+    let _config = override_config(CoreConfig {
+        openai_api_url: server.url("/v1"),
+        ..default_core_config()
+    });
+    let listener = Arc::new(RecordingListener::default());
+
+    let result = polish_text_streaming(
+        LlmProvider::Openai,
+        "bad-key".to_string(),
+        "text".to_string(),
+        None,
+        listener.clone(),
+        CancellationToken::new(),
+    );
+    server.finish();
+
+    assert!(matches!(
+        result,
+        Err(CoreError::Unauthorized { provider, status: 401, body })
+            if provider == "OpenAI" && body.contains("bad key")
+    ));
+    assert!(listener.deltas().is_empty());
+}
+
+#[test]
+fn polish_text_streaming_should_deliver_anthropic_text_deltas() {
+    let server = StandInServer::start(vec![CannedResponse::event_stream(&[
+        r#"{"type":"message_start","message":{"id":"msg_1","content":[]}}"#,
+        r#"{"type":"content_block_start","index":0,"content_block":{"type":"text","text":""}}"#,
+        r#"{"type":"ping"}"#,
+        r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"Hi "}}"#,
+        r#"{"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"there."}}"#,
+        r#"{"type":"content_block_stop","index":0}"#,
+        r#"{"type":"message_delta","delta":{"stop_reason":"end_turn"}}"#,
+        r#"{"type":"message_stop"}"#,
+    ])]);
+    let _config = override_config(CoreConfig {
+        anthropic_api_url: server.url("/v1"),
+        ..default_core_config()
+    });
+    let listener = Arc::new(RecordingListener::default());
+
+    let result = polish_text_streaming(
+        LlmProvider::Anthropic,
+        "test-key".to_string(),
+        "hi there".to_string(),
+        None,
+        listener.clone(),
+        CancellationToken::new(),
+    );
+    let requests = server.finish();
+
+    assert_eq!(result.expect("stream should succeed"), "Hi there.");
+    assert_eq!(listener.deltas(), vec!["Hi ", "there."]);
+    assert_eq!(requests[0].path, "/v1/messages");
+    let body: serde_json::Value =
+        serde_json::from_slice(&requests[0].body).expect("request body should be JSON");
+    assert_eq!(body["stream"], true);
+}
@@ -0,0 +1,128 @@
+//! Integration tests for structured provider errors.
+//!
+//! Requests are routed to a loopback stand-in server, so no network access is needed.
+
+mod support;
+
+use diy_typeless_core::{
+    default_core_config, polish_text, transcribe_audio_bytes, CoreConfig, CoreError, LlmProvider,
+    RetryPolicy,
+};
+use support::{override_config, CannedResponse, StandInServer};
+
+fn single_attempt() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 1,
+        ..RetryPolicy::default()
+    }
+}
+
+#[test]
+fn transcription_should_report_rejected_key_with_provider_and_body() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        401,
+        r#"{"error":{"message":"Invalid API Key","code":"invalid_api_key"}}"#,
+    )]);
+    let _guard = override_config(CoreConfig {
+        groq_api_url: server.url("/openai/v1"),
+        ..default_core_config()
+    });
+
+    let result = transcribe_audio_bytes("bad-key".to_string(), b"fLaC".to_vec(), None);
+    let requests = server.finish();
+
+    assert_eq!(requests.len(), 1);
+    let error = result.expect_err("401 should fail");
+    assert!(!error.is_retryable());
+    assert_eq!(error.status_code(), Some(401));
+    assert_eq!(error.to_string(), "Groq rejected the API key (HTTP 401)");
+    assert!(
+        matches!(error, CoreError::Unauthorized { body, .. } if body.contains("invalid_api_key"))
+    );
+}
+
+#[test]
+fn polish_should_report_rate_limit_with_retry_after() {
+    let mut throttled = CannedResponse::json(
+        429,
+        r#"{"error":{"message":"Rate limit reached","code":"rate_limit_exceeded"}}"#,
+    );
+    throttled
+        .headers
+        .push(("Retry-After".to_string(), "20".to_string()));
+    let server = StandInServer::start(vec![throttled]);
+    let _guard = override_config(CoreConfig {
+        openai_api_url: server.url("/v1"),
+        retry_policy: single_attempt(),
+        ..default_core_config()
+    });
+
+    let result = polish_text(
+        LlmProvider::Openai,
+        "test-key".to_string(),
+        "text".to_string(),
+        None,
+    );
+    server.finish();
+
+    assert!(matches!(
+        result,
+        Err(CoreError::RateLimited { provider, retry_after_secs: Some(20), .. }) if provider == "OpenAI"
+    ));
+}
+
+#[test]
+fn polish_should_retry_server_errors_and_then_succeed() {
+    let mut unavailable = CannedResponse::json(503, r#"{"error":{"message":"overloaded"}}"#);
+    unavailable
+        .headers
+        .push(("Retry-After".to_string(), "0".to_string()));
+    let server = StandInServer::start(vec![
+        unavailable,
+        CannedResponse::json(
+            200,
+            r#"{"candidates":[{"content":{"parts":[{"text":"Recovered."}]}}]}"#,
+        ),
+    ]);
+    let _guard = override_config(CoreConfig {
+        gemini_api_url: server.url("/v1beta/models"),
+        ..default_core_config()
+    });
+
+    let result = polish_text(
+        LlmProvider::GoogleAiStudio,
+        "test-key".to_string(),
+        "text".to_string(),
+        None,
+    );
+    let requests = server.finish();
+
+    assert_eq!(result.expect("second attempt should succeed"), "Recovered.");
+    assert_eq!(requests.len(), 2);
+}
+
+#[test]
+fn polish_should_report_blocked_gemini_prompt() {
+    let server = StandInServer::start(vec![CannedResponse::json(
+        200,
+        r#"{"promptFeedback":{"blockReason":"PROHIBITED_CONTENT"}}"#,
+    )]);
+    let _guard = override_config(CoreConfig {
+        gemini_api_url: server.url("/v1beta/models"),
+        ..default_core_config()
+    });
+
+    let result = polish_text(
+        LlmProvider::GoogleAiStudio,
+        "test-key".to_string(),
+        "text".to_string(),
+        None,
+    );
+    server.finish();
+
+    assert!(matches!(
+        result,
+        Err(CoreError::ContentBlocked { provider, reason })
+            if provider == "Gemini" && reason == "PROHIBITED_CONTENT"
+    ));
+}
@@ -0,0 +1,231 @@
+//! Minimal stand-in HTTP server for integration tests.
+//!
+//! Serves a fixed sequence of canned responses on a loopback port and records
+//! every request it receives, so tests can exercise real HTTP paths without
+//! reaching remote providers.
+
+// Each integration test binary compiles this module and uses a different subset of it.
+#![allow(dead_code)]
+
+use diy_typeless_core::{default_core_config, set_core_config, CoreConfig};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{LazyLock, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+
+/// The core configuration is process-global, so tests that change it run one at a time.
+static CONFIG_TEST_LOCK: LazyLock<Mutex<()>> = LazyLock::new(|| Mutex::new(()));
+
+/// Restores the default core configuration when dropped.
+pub(crate) struct ConfigOverride {
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Drop for ConfigOverride {
+    fn drop(&mut self) {
+        set_core_config(default_core_config()).expect("default config should be valid");
+    }
+}
+
+/// Serialize against other config-changing tests and install `config`.
+pub(crate) fn override_config(config: CoreConfig) -> ConfigOverride {
+    let lock = CONFIG_TEST_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    set_core_config(config).expect("override config should be valid");
+    ConfigOverride { _lock: lock }
+}
+
+/// Serialize against config-changing tests without modifying the configuration.
+pub(crate) fn lock_config() -> MutexGuard<'static, ()> {
+    CONFIG_TEST_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// A canned HTTP response.
+pub(crate) struct CannedResponse {
+    pub(crate) status: u16,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: String,
+}
+
+impl CannedResponse {
+    pub(crate) fn json(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            body: body.to_string(),
+        }
+    }
+
+    pub(crate) fn event_stream(events: &[&str]) -> Self {
+        let body: String = events
+            .iter()
+            .map(|event| format!("data: {event}\n\n"))
+            .collect();
+        Self {
+            status: 200,
+            headers: vec![("Content-Type".to_string(), "text/event-stream".to_string())],
+            body,
+        }
+    }
+
+    pub(crate) fn text(status: u16, body: &str) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type".to_string(), "text/plain".to_string())],
+            body: body.to_string(),
+        }
+    }
+}
+
+/// A request captured by the stand-in server.
+pub(crate) struct RecordedRequest {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    pub(crate) fn body_text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// Loopback server answering one connection per canned response.
+pub(crate) struct StandInServer {
+    base_url: String,
+    handle: JoinHandle<Vec<RecordedRequest>>,
+}
+
+impl StandInServer {
+    pub(crate) fn start(responses: Vec<CannedResponse>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("stand-in server should bind");
+        let port = listener
+            .local_addr()
+            .expect("stand-in server should have an address")
+            .port();
+
+        let handle = thread::spawn(move || {
+            let mut recorded = Vec::new();
+            for response in responses {
+                let (mut stream, _) = listener.accept().expect("connection should be accepted");
+                recorded.push(read_request(&mut stream));
+                write_response(&mut stream, &response);
+            }
+            recorded
+        });
+
+        Self {
+            base_url: format!("http://127.0.0.1:{port}"),
+            handle,
+        }
+    }
+
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url)
+    }
+
+    /// Wait for all canned responses to be served and return the captured requests.
+    pub(crate) fn finish(self) -> Vec<RecordedRequest> {
+        self.handle
+            .join()
+            .expect("stand-in server thread should join cleanly")
+    }
+}
+
+fn read_request(stream: &mut TcpStream) -> RecordedRequest {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader
+        .read_line(&mut request_line)
+        .expect("request line should be readable");
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let path = parts.next().unwrap_or_default().to_string();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader
+            .read_line(&mut line)
+            .expect("header line should be readable");
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            headers.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    let header = |name: &str| {
+        headers
+            .iter()
+            .find(|(key, _): &&(String, String)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.clone())
+    };
+
+    let body = if let Some(length) = header("content-length") {
+        let length: usize = length.parse().expect("content-length should be numeric");
+        let mut body = vec![0; length];
+        reader
+            .read_exact(&mut body)
+            .expect("request body should be readable");
+        body
+    } else if header("transfer-encoding").is_some_and(|value| value.contains("chunked")) {
+        read_chunked_body(&mut reader)
+    } else {
+        Vec::new()
+    };
+
+    RecordedRequest {
+        method,
+        path,
+        headers,
+        body,
+    }
+}
+
+fn read_chunked_body(reader: &mut impl BufRead) -> Vec<u8> {
+    let mut body = Vec::new();
+    loop {
+        let mut size_line = String::new();
+        reader
+            .read_line(&mut size_line)
+            .expect("chunk size should be readable");
+        let size = usize::from_str_radix(size_line.trim(), 16).expect("chunk size should be hex");
+        let mut chunk = vec![0; size + 2];
+        reader
+            .read_exact(&mut chunk)
+            .expect("chunk should be readable");
+        if size == 0 {
+            return body;
+        }
+        body.extend_from_slice(&chunk[..size]);
+    }
+}
+
+fn write_response(stream: &mut TcpStream, response: &CannedResponse) {
+    let mut head = format!("HTTP/1.1 {} Stand-In\r\n", response.status);
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{name}: {value}\r\n"));
+    }
+    head.push_str(&format!(
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        response.body.len()
+    ));
+    stream
+        .write_all(head.as_bytes())
+        .and_then(|()| stream.write_all(response.body.as_bytes()))
+        .expect("response should be writable");
+}